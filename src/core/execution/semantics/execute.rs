@@ -19,11 +19,13 @@ limitations under the License.
 use std::collections::{BTreeSet, HashSet};
 use crate::core::execution::trace::from_model::from_model::InteractionInterpretableAsTraceAction;
 use crate::core::execution::trace::trace::TraceAction;
+use crate::core::language::avoid::avoids::AvoidsLifelines;
 use crate::core::language::involve::involves::InvolvesLifelines;
 use crate::core::language::position::position::Position;
 use crate::core::language::prune::prunable::LifelinePrunable;
 use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction, EmissionTargetRef, ReceptionAction};
 use crate::core::language::syntax::interaction::{Interaction, LoopKind};
+use crate::core::language::syntax::util::fold_recursive_frags::fold_recursive_alt_frags;
 
 
 fn execute_emission(em_act : &EmissionAction) -> Interaction {
@@ -42,7 +44,7 @@ fn execute_emission(em_act : &EmissionAction) -> Interaction {
                 }
             }
             if target_lf_ids.len() > 0 {
-                let rc_act = ReceptionAction::new(None,em_act.ms_id,CommunicationSynchronicity::Asynchronous,target_lf_ids);
+                let rc_act = ReceptionAction::new(None,em_act.ms_id,CommunicationSynchronicity::Asynchronous,target_lf_ids,em_act.args.clone());
                 return Interaction::Reception(rc_act);
             } else {
                 return Interaction::Empty;
@@ -65,7 +67,7 @@ fn execute_reception(rc_act : &ReceptionAction, sub_pos : &Option<usize>) -> Int
                     let mut target_lf_ids = rc_act.recipients.clone();
                     target_lf_ids.remove(*sub_pos_idx);
                     if target_lf_ids.len() > 0 {
-                        let new_rc_act = ReceptionAction::new(rc_act.origin_gt_id,rc_act.ms_id,CommunicationSynchronicity::Asynchronous,target_lf_ids);
+                        let new_rc_act = ReceptionAction::new(rc_act.origin_gt_id,rc_act.ms_id,CommunicationSynchronicity::Asynchronous,target_lf_ids,rc_act.args.clone());
                         return Interaction::Reception(new_rc_act);
                     } else {
                         return Interaction::Empty;
@@ -148,6 +150,77 @@ fn make_follow_up_loop(old_i1 : &Interaction,
     }
 }
 
+fn make_remaining_bounded_loop(i1 : &Interaction,
+                               lkind : &LoopKind,
+                               min : u32,
+                               max : u32) -> Interaction {
+    if max == 0 {
+        return Interaction::Empty;
+    } else {
+        return Interaction::BoundedLoop(lkind.clone(), min, max, Box::new(i1.clone()));
+    }
+}
+
+fn make_follow_up_bounded_loop(old_i1 : &Interaction,
+                               new_i1 : Interaction,
+                               lkind : &LoopKind,
+                               min : u32,
+                               max : u32,
+                               tar_lf_ids : &BTreeSet<usize>) -> Interaction {
+    // the executed action belongs to a new iteration which therefore consumes one unit of both bounds
+    let rem_i = make_remaining_bounded_loop(old_i1, lkind, min.saturating_sub(1), max - 1);
+    if new_i1 == Interaction::Empty {
+        return rem_i;
+    }
+    if rem_i == Interaction::Empty {
+        return new_i1;
+    }
+    match &lkind {
+        LoopKind::SStrictSeq => {
+            return Interaction::Strict( Box::new(new_i1), Box::new(rem_i) );
+        },
+        LoopKind::HHeadFirstWS => {
+            return Interaction::Seq( Box::new(new_i1), Box::new(rem_i) );
+        },
+        LoopKind::PInterleaving => {
+            return Interaction::Par( Box::new(new_i1), Box::new(rem_i) );
+        },
+        LoopKind::WWeakSeq => {
+            let pruned_i1 = if old_i1.avoids_all_of(tar_lf_ids) {
+                old_i1.prune(tar_lf_ids)
+            } else {
+                Interaction::Empty
+            };
+            if pruned_i1 == Interaction::Empty {
+                return Interaction::Seq( Box::new(new_i1), Box::new(rem_i) );
+            }
+            // ***
+            // with weak sequencing, the executed action may belong to the (k+1)-th iteration
+            // provided the k previous iterations avoid the targeted lifelines
+            // those k iterations are taken from the same budget of iterations
+            let mut alternatives : Vec<Interaction> = vec![];
+            for k in 0..max {
+                let after_i = make_remaining_bounded_loop(old_i1,
+                                                          lkind,
+                                                          min.saturating_sub(1+k),
+                                                          max - 1 - k);
+                let mut new_i = if after_i == Interaction::Empty {
+                    new_i1.clone()
+                } else {
+                    Interaction::Seq( Box::new(new_i1.clone()), Box::new(after_i) )
+                };
+                if k > 0 {
+                    let before_i = Interaction::BoundedLoop(lkind.clone(), k, k, Box::new(pruned_i1.clone()));
+                    new_i = Interaction::Seq( Box::new(before_i), Box::new(new_i) );
+                }
+                alternatives.push(new_i);
+            }
+            let mut alternatives_refs : Vec<&Interaction> = alternatives.iter().collect();
+            return fold_recursive_alt_frags(&mut alternatives_refs);
+        }
+    }
+}
+
 
 fn execute_interaction_left(my_int : &Interaction,
                             sub_p1 : &Position,
@@ -175,6 +248,55 @@ fn execute_interaction_left(my_int : &Interaction,
             let new_i = make_follow_up_loop(i1,new_i1,lkind,tar_lf_ids);
             return ExecutionResult::new(new_i,affected);
         },
+        Interaction::BoundedLoop(lkind, min, max, i1) => {
+            let exres1 = execute_interaction(i1,sub_p1,tar_lf_ids,false);
+            let new_i1 = exres1.interaction;
+            let affected = if get_affected {
+                i1.involved_lifelines()
+            } else {
+                btreeset!{}
+            };
+            let new_i = make_follow_up_bounded_loop(i1,new_i1,lkind,*min,*max,tar_lf_ids);
+            return ExecutionResult::new(new_i,affected);
+        },
+        Interaction::Neg(i1) => {
+            // executing an action of a negative fragment commits to the forbidden scenario
+            // so that what remains is the rest of it, which is no longer optional
+            let exres1 = execute_interaction(i1,sub_p1,tar_lf_ids,false);
+            let affected = if get_affected {
+                i1.involved_lifelines()
+            } else {
+                btreeset!{}
+            };
+            return ExecutionResult::new(exres1.interaction,affected);
+        },
+        Interaction::Assert(i1) => {
+            let exres1 = execute_interaction(i1,sub_p1,tar_lf_ids,get_affected);
+            let new_i1 = exres1.interaction;
+            let affected = exres1.affected_lifelines;
+            // ***
+            let new_i : Interaction;
+            if new_i1 == Interaction::Empty {
+                new_i = Interaction::Empty;
+            } else {
+                new_i = Interaction::Assert( Box::new(new_i1) );
+            }
+            return ExecutionResult::new(new_i,affected);
+        },
+        Interaction::Disrupt(i1,i2) => {
+            let exres1 = execute_interaction(i1,sub_p1,tar_lf_ids,get_affected);
+            let new_i1 = exres1.interaction;
+            let affected = exres1.affected_lifelines;
+            // ***
+            // once the interrupted sub-interaction has terminated it can no longer be interrupted
+            let new_i : Interaction;
+            if new_i1 == Interaction::Empty {
+                new_i = Interaction::Empty;
+            } else {
+                new_i = Interaction::Disrupt( Box::new(new_i1), i2.clone());
+            }
+            return ExecutionResult::new(new_i,affected);
+        },
         Interaction::Strict(i1,i2) => {
             let exres1 = execute_interaction(i1,sub_p1,tar_lf_ids,get_affected);
             let new_i1 = exres1.interaction;
@@ -278,6 +400,17 @@ fn execute_interaction_right(my_int : &Interaction,
                 return execute_interaction( i2,sub_p2, tar_lf_ids,false);
             }
         },
+        Interaction::Disrupt(i1,i2) => {
+            // the interruption discards what remains of the interrupted sub-interaction
+            if get_affected {
+                let mut affected = i1.involved_lifelines();
+                let exres2 = execute_interaction( i2,sub_p2, tar_lf_ids,true);
+                affected.extend(exres2.affected_lifelines);
+                return ExecutionResult::new(exres2.interaction,affected);
+            } else {
+                return execute_interaction( i2,sub_p2, tar_lf_ids,false);
+            }
+        },
         Interaction::Par(i1,i2) => {
             let exres2 = execute_interaction(i2,sub_p2, tar_lf_ids,get_affected);
             let new_i2 = exres2.interaction;
@@ -481,4 +614,75 @@ pub fn execute_interaction(my_int : &Interaction,
             return execute_interaction_both(my_int,p1,p2,tar_lf_ids,get_affected);
         }
     }
-}
\ No newline at end of file
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test_fixtures::emission;
+    use super::*;
+
+    fn execute_on(int : &Interaction, pos : Position, lf_id : usize) -> Interaction {
+        return execute_interaction(int,&pos,&btreeset!{lf_id},false).interaction;
+    }
+
+    fn first_of_loop_body(pos : Position) -> Position {
+        return Position::Left(Box::new(pos));
+    }
+
+    #[test]
+    fn executing_a_bounded_loop_consumes_one_iteration() {
+        // loopS[2,3](a -- m1 ->|)
+        let int = Interaction::BoundedLoop(LoopKind::SStrictSeq,2,3,Box::new(emission(0,0)));
+        let got = execute_on(&int,first_of_loop_body(Position::Epsilon(None)),0);
+        assert_eq!(got, Interaction::BoundedLoop(LoopKind::SStrictSeq,1,2,Box::new(emission(0,0))));
+        // the last iteration leaves nothing to execute
+        let int = Interaction::BoundedLoop(LoopKind::SStrictSeq,0,1,Box::new(emission(0,0)));
+        let got = execute_on(&int,first_of_loop_body(Position::Epsilon(None)),0);
+        assert_eq!(got, Interaction::Empty);
+    }
+
+    #[test]
+    fn remaining_iterations_follow_the_started_one() {
+        // loopS[1,2](strict(a -- m1 ->|, a -- m2 ->|))
+        let body = Interaction::Strict(Box::new(emission(0,0)),Box::new(emission(0,1)));
+        let int = Interaction::BoundedLoop(LoopKind::SStrictSeq,1,2,Box::new(body.clone()));
+        let got = execute_on(&int,first_of_loop_body(Position::Left(Box::new(Position::Epsilon(None)))),0);
+        let expected = Interaction::Strict(Box::new(emission(0,1)),
+                                           Box::new(Interaction::BoundedLoop(LoopKind::SStrictSeq,0,1,Box::new(body))));
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn weakly_sequenced_iterations_may_be_skipped_over() {
+        // loopW[0,2](alt(strict(a -- m1 ->|, a -- m2 ->|), b -- m3 ->|))
+        // "a!m1" may occur in the first iteration or in the second one provided the first one is "b!m3"
+        let body = Interaction::Alt(Box::new(Interaction::Strict(Box::new(emission(0,0)),Box::new(emission(0,1)))),
+                                    Box::new(emission(1,2)));
+        let int = Interaction::BoundedLoop(LoopKind::WWeakSeq,0,2,Box::new(body.clone()));
+        let pos = first_of_loop_body(Position::Left(Box::new(Position::Left(Box::new(Position::Epsilon(None))))));
+        let got = execute_on(&int,pos,0);
+        let in_first = Interaction::Seq(Box::new(emission(0,1)),
+                                        Box::new(Interaction::BoundedLoop(LoopKind::WWeakSeq,0,1,Box::new(body))));
+        let in_second = Interaction::Seq(Box::new(Interaction::BoundedLoop(LoopKind::WWeakSeq,1,1,Box::new(emission(1,2)))),
+                                         Box::new(emission(0,1)));
+        assert_eq!(got, Interaction::Alt(Box::new(in_first),Box::new(in_second)));
+    }
+
+    #[test]
+    fn the_interruption_discards_the_rest_of_the_disrupted_interaction() {
+        // disrupt(strict(a -- m1 ->|, a -- m2 ->|), b -- m3 ->|)
+        let int = Interaction::Disrupt(Box::new(Interaction::Strict(Box::new(emission(0,0)),Box::new(emission(0,1)))),
+                                       Box::new(emission(1,2)));
+        let in_disrupted = Position::Left(Box::new(Position::Left(Box::new(Position::Epsilon(None)))));
+        let got = execute_on(&int,in_disrupted,0);
+        let expected = Interaction::Disrupt(Box::new(emission(0,1)),Box::new(emission(1,2)));
+        assert_eq!(got, expected);
+        // once terminated, the disrupted interaction can no longer be interrupted
+        assert_eq!(execute_on(&got,Position::Left(Box::new(Position::Epsilon(None))),0), Interaction::Empty);
+        // the interruption affects the lifelines of the disrupted interaction
+        let exres = execute_interaction(&int,&Position::Right(Box::new(Position::Epsilon(None))),&btreeset!{1},true);
+        assert_eq!(exres.interaction, Interaction::Empty);
+        assert_eq!(exres.affected_lifelines, btreeset!{0,1});
+    }
+}
@@ -263,6 +263,24 @@ fn global_frontier_rec(delayed_alt : bool, interaction : &Interaction, loop_dept
         Interaction::Loop(_, ref i1) => {
             return push_frontier_left( &mut global_frontier_rec(delayed_alt,i1,loop_depth+1) );
         },
+        Interaction::BoundedLoop(_, _, max, ref i1) => {
+            if *max == 0 {
+                return vec![];
+            }
+            // bounded loops cannot be unfolded indefinitely and hence do not count as loop instances
+            return push_frontier_left( &mut global_frontier_rec(delayed_alt,i1,loop_depth) );
+        },
+        Interaction::Neg(ref i1) => {
+            return push_frontier_left( &mut global_frontier_rec(delayed_alt,i1,loop_depth) );
+        },
+        Interaction::Assert(ref i1) => {
+            return push_frontier_left( &mut global_frontier_rec(delayed_alt,i1,loop_depth) );
+        },
+        Interaction::Disrupt(ref i1, ref i2) => {
+            let mut front = push_frontier_left( &mut global_frontier_rec(delayed_alt,i1,loop_depth) );
+            front.append( &mut push_frontier_right( &mut global_frontier_rec(delayed_alt,i2,loop_depth)) );
+            return front;
+        },
         _ => {
             panic!("non-conform interaction");
         }
@@ -17,3 +17,4 @@ limitations under the License.
 
 pub mod frontier;
 pub mod execute;
+pub mod negative;
@@ -0,0 +1,153 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::execution::semantics::frontier::global_frontier;
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+// executing the action at the given position means entering (and hence committing to) a negative fragment
+// when the position targets both branches of an alternative (delayed alt) both must lie within negative fragments
+pub fn is_position_within_negative_fragment(interaction : &Interaction,
+                                            position : &Position) -> bool {
+    match (interaction,position) {
+        (Interaction::Neg(_),Position::Left(_)) => {
+            return true;
+        },
+        (Interaction::Alt(i1,i2),Position::Both(p1,p2)) => {
+            return is_position_within_negative_fragment(i1,p1) && is_position_within_negative_fragment(i2,p2);
+        },
+        (_,Position::Epsilon(_)) => {
+            return false;
+        },
+        _ => {
+            return is_position_within_any_sub_interaction(interaction,position,&is_position_within_negative_fragment);
+        }
+    }
+}
+
+// the action at the given position lies within an assertion which the executed prefix has committed to
+// i.e. which is not within a choice that has yet to be made :
+// a branch of an alternative, a loop which may be skipped, an interruption or a negative fragment
+pub fn is_position_within_committed_assertion(interaction : &Interaction,
+                                              position : &Position) -> bool {
+    match (interaction,position) {
+        (Interaction::Assert(_),Position::Left(_)) => {
+            return true;
+        },
+        (_,Position::Epsilon(_)) => {
+            return false;
+        },
+        (Interaction::Alt(_,_),_) | (Interaction::Loop(_,_),_) | (Interaction::Neg(_),_) => {
+            return false;
+        },
+        (Interaction::BoundedLoop(_,min,_,i1),Position::Left(p1)) => {
+            return *min > 0 && is_position_within_committed_assertion(i1,p1);
+        },
+        (Interaction::Disrupt(_,_),Position::Right(_)) => {
+            return false;
+        },
+        _ => {
+            return is_position_within_any_sub_interaction(interaction,position,&is_position_within_committed_assertion);
+        }
+    }
+}
+
+// some immediately executable actions lie within a committed assertion
+// hence, from the current state, any deviation w.r.t. the interaction leads to a negative trace
+pub fn is_assertion_reached(interaction : &Interaction) -> bool {
+    return global_frontier(interaction,false).iter().any(|frt_elt| is_position_within_committed_assertion(interaction,&frt_elt.position));
+}
+
+fn is_position_within_any_sub_interaction(interaction : &Interaction,
+                                          position : &Position,
+                                          predicate : &dyn Fn(&Interaction,&Position) -> bool) -> bool {
+    let (sub_i1,sub_i2) : (Option<&Interaction>,Option<&Interaction>) = match interaction {
+        Interaction::Strict(i1,i2) => (Some(i1),Some(i2)),
+        Interaction::Seq(i1,i2) => (Some(i1),Some(i2)),
+        Interaction::CoReg(_,i1,i2) => (Some(i1),Some(i2)),
+        Interaction::Alt(i1,i2) => (Some(i1),Some(i2)),
+        Interaction::Par(i1,i2) => (Some(i1),Some(i2)),
+        Interaction::And(i1,i2) => (Some(i1),Some(i2)),
+        Interaction::Sync(_,i1,i2) => (Some(i1),Some(i2)),
+        Interaction::Disrupt(i1,i2) => (Some(i1),Some(i2)),
+        Interaction::Loop(_,i1) => (Some(i1),None),
+        Interaction::BoundedLoop(_,_,_,i1) => (Some(i1),None),
+        Interaction::Neg(i1) => (Some(i1),None),
+        Interaction::Assert(i1) => (Some(i1),None),
+        _ => (None,None)
+    };
+    match position {
+        Position::Left(p1) => {
+            return sub_i1.map_or(false, |i1| predicate(i1,p1));
+        },
+        Position::Right(p2) => {
+            return sub_i2.map_or(false, |i2| predicate(i2,p2));
+        },
+        Position::Both(p1,p2) => {
+            return sub_i1.map_or(false, |i1| predicate(i1,p1)) || sub_i2.map_or(false, |i2| predicate(i2,p2));
+        },
+        Position::Epsilon(_) => {
+            return false;
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::syntax::interaction::LoopKind;
+    use crate::util::test_fixtures::emission;
+    use super::*;
+
+    #[test]
+    fn positions_within_negative_fragments() {
+        // seq(a -- m1 ->|, neg(a -- m2 ->|))
+        let int = Interaction::Seq(Box::new(emission(0,0)),
+                                   Box::new(Interaction::Neg(Box::new(emission(0,1)))));
+        let in_neg = Position::Right(Box::new(Position::Left(Box::new(Position::Epsilon(None)))));
+        assert!(is_position_within_negative_fragment(&int,&in_neg));
+        assert!(!is_position_within_negative_fragment(&int,&Position::Left(Box::new(Position::Epsilon(None)))));
+    }
+
+    #[test]
+    fn assertions_are_reached_once_immediately_executable() {
+        // seq(a -- m1 ->|, assert(a -- m2 ->|))
+        let int = Interaction::Seq(Box::new(emission(0,0)),
+                                   Box::new(Interaction::Assert(Box::new(emission(0,1)))));
+        assert!(!is_assertion_reached(&int));
+        // par(a -- m1 ->|, assert(a -- m2 ->|))
+        let int = Interaction::Par(Box::new(emission(0,0)),
+                                   Box::new(Interaction::Assert(Box::new(emission(0,1)))));
+        assert!(is_assertion_reached(&int));
+    }
+
+    #[test]
+    fn assertions_within_untaken_branches_are_not_reached() {
+        // alt(assert(a -- m1 ->|), b -- m1 ->|)
+        let int = Interaction::Alt(Box::new(Interaction::Assert(Box::new(emission(0,0)))),
+                                   Box::new(emission(1,0)));
+        assert!(!is_assertion_reached(&int));
+        // loopS(assert(a -- m1 ->|))
+        let int = Interaction::Loop(LoopKind::SStrictSeq,Box::new(Interaction::Assert(Box::new(emission(0,0)))));
+        assert!(!is_assertion_reached(&int));
+        // loopS[1,2](assert(a -- m1 ->|))
+        let int = Interaction::BoundedLoop(LoopKind::SStrictSeq,1,2,Box::new(Interaction::Assert(Box::new(emission(0,0)))));
+        assert!(is_assertion_reached(&int));
+    }
+}
@@ -65,6 +65,21 @@ impl InteractionInterpretableAsTraceAction for Interaction {
             &Interaction::Loop(_, i1) => {
                 return i1.get_all_trace_actions();
             },
+            &Interaction::BoundedLoop(_, _, _, i1) => {
+                return i1.get_all_trace_actions();
+            },
+            &Interaction::Neg(i1) => {
+                return i1.get_all_trace_actions();
+            },
+            &Interaction::Assert(i1) => {
+                return i1.get_all_trace_actions();
+            },
+            &Interaction::Disrupt(ref i1, ref i2) => {
+                let mut acts1 = i1.get_all_trace_actions();
+                let acts2 = i2.get_all_trace_actions();
+                acts1.extend(acts2);
+                return acts1;
+            },
             &Interaction::Sync(_, ref i1, ref i2) => {
                 let mut acts1 = i1.get_all_trace_actions();
                 let acts2 = i2.get_all_trace_actions();
@@ -121,6 +136,21 @@ impl InteractionInterpretableAsTraceAction for Interaction {
             &Interaction::Loop(_, i1) => {
                 return btreeset!{};
             },
+            &Interaction::BoundedLoop(_, _, _, _) => {
+                return btreeset!{};
+            },
+            &Interaction::Neg(i1) => {
+                return i1.get_trace_actions_outside_loops();
+            },
+            &Interaction::Assert(i1) => {
+                return i1.get_trace_actions_outside_loops();
+            },
+            &Interaction::Disrupt(ref i1, ref i2) => {
+                let mut acts1 = i1.get_trace_actions_outside_loops();
+                let acts2 = i2.get_trace_actions_outside_loops();
+                acts1.extend(acts2);
+                return acts1;
+            },
             &Interaction::Sync(_, ref i1, ref i2) => {
                 let mut acts1 = i1.get_all_trace_actions();
                 let acts2 = i2.get_all_trace_actions();
@@ -162,6 +192,18 @@ impl InteractionInterpretableAsTraceAction for Interaction {
             &Interaction::Loop(_, i1) => {
                 return i1.get_atomic_actions_number();
             },
+            &Interaction::BoundedLoop(_, _, _, i1) => {
+                return i1.get_atomic_actions_number();
+            },
+            &Interaction::Neg(i1) => {
+                return i1.get_atomic_actions_number();
+            },
+            &Interaction::Assert(i1) => {
+                return i1.get_atomic_actions_number();
+            },
+            &Interaction::Disrupt(ref i1, ref i2) => {
+                return i1.get_atomic_actions_number() + i2.get_atomic_actions_number();
+            },
             &Interaction::Sync(_, ref i1, ref i2) => {
                 return i1.get_atomic_actions_number() + i2.get_atomic_actions_number();
             },
@@ -200,6 +242,18 @@ impl InteractionInterpretableAsTraceAction for Interaction {
             &Interaction::Loop(_, i1) => {
                 return 0;
             },
+            &Interaction::BoundedLoop(_, _, _, _) => {
+                return 0;
+            },
+            &Interaction::Neg(i1) => {
+                return i1.get_atomic_actions_number_outside_loops();
+            },
+            &Interaction::Assert(i1) => {
+                return i1.get_atomic_actions_number_outside_loops();
+            },
+            &Interaction::Disrupt(ref i1, ref i2) => {
+                return i1.get_atomic_actions_number_outside_loops() + i2.get_atomic_actions_number_outside_loops();
+            },
             &Interaction::Sync(_, ref i1, ref i2) => {
                 return i1.get_atomic_actions_number() + i2.get_atomic_actions_number();
             },
@@ -29,7 +29,7 @@ impl PrimitiveInterpretableAsTraceAction for EmissionAction {
         for target_ref in &self.targets {
             match target_ref {
                 &EmissionTargetRef::Lifeline(tar_lf_id) => {
-                    contents.insert( TraceAction::new(tar_lf_id,TraceActionKind::Reception, self.ms_id) );
+                    contents.insert( TraceAction::new(tar_lf_id,TraceActionKind::Reception, self.ms_id, self.args.clone()) );
                 },
                 _ => {}
             }
@@ -40,7 +40,8 @@ impl PrimitiveInterpretableAsTraceAction for EmissionAction {
     fn get_first_atomic_action(&self) -> TraceAction {
         return TraceAction::new(self.origin_lf_id,
                                 TraceActionKind::Emission,
-                                self.ms_id);
+                                self.ms_id,
+                                self.args.clone());
     }
 
     fn get_specific_atomic_action(&self,idx : usize) -> TraceAction {
@@ -53,7 +54,8 @@ impl PrimitiveInterpretableAsTraceAction for EmissionAction {
                     EmissionTargetRef::Lifeline(tar_lf_id) => {
                         return TraceAction::new(*tar_lf_id,
                                                 TraceActionKind::Reception,
-                                                self.ms_id);
+                                                self.ms_id,
+                                self.args.clone());
                     },
                     _ => {
                         panic!();
@@ -71,7 +73,8 @@ impl PrimitiveInterpretableAsTraceAction for ReceptionAction {
         for rc_lf_id in &self.recipients {
             contents.insert( TraceAction::new(*rc_lf_id,
                                               TraceActionKind::Reception,
-                                              self.ms_id) );
+                                              self.ms_id,
+                                              self.args.clone()) );
         }
         return contents;
     }
@@ -83,6 +86,7 @@ impl PrimitiveInterpretableAsTraceAction for ReceptionAction {
     fn get_specific_atomic_action(&self, idx : usize) -> TraceAction {
         return TraceAction::new(*self.recipients.get(idx).unwrap(),
                                 TraceActionKind::Reception,
-                                self.ms_id);
+                                self.ms_id,
+                                self.args.clone());
     }
 }
\ No newline at end of file
@@ -32,3 +32,41 @@ pub fn multi_trace_length(multi_trace : &MultiTrace) -> usize {
     }
     return length;
 }
+
+// unobserved actions are removed from the multi-trace, as well as the multi-actions which become empty
+pub fn multi_trace_without_messages(multi_trace : &MultiTrace, ms_ids : &BTreeSet<usize>) -> MultiTrace {
+    let mut new_multi_trace = vec![];
+    for trace in multi_trace {
+        let mut new_trace = vec![];
+        for multi_action in trace {
+            let new_multi_action : BTreeSet<TraceAction> = multi_action.iter()
+                .filter(|action| !ms_ids.contains(&action.ms_id))
+                .cloned()
+                .collect();
+            if !new_multi_action.is_empty() {
+                new_trace.push(new_multi_action);
+            }
+        }
+        new_multi_trace.push(new_trace);
+    }
+    return new_multi_trace;
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::execution::trace::trace::TraceActionKind;
+    use crate::util::test_fixtures::action;
+    use super::*;
+
+    #[test]
+    fn multi_actions_without_remaining_actions_are_removed() {
+        let mut multi_action = action(0,TraceActionKind::Emission,0);
+        multi_action.extend(action(0,TraceActionKind::Emission,1));
+        let multi_trace : MultiTrace = vec![vec![multi_action,action(0,TraceActionKind::Emission,1)],
+                                            vec![action(1,TraceActionKind::Reception,1)]];
+        let expected : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0)],vec![]];
+        assert_eq!(multi_trace_without_messages(&multi_trace,&btreeset!{1}), expected);
+    }
+}
@@ -20,25 +20,71 @@ limitations under the License.
 
 use std::collections::BTreeSet;
 
+use crate::core::language::syntax::value::{arguments_match, ValuePattern};
+
 #[derive(Clone, PartialEq, Eq, PartialOrd, Copy, Ord, Hash, Debug)]
 pub enum TraceActionKind {
     Reception,
     Emission
 }
 
-#[derive(Clone, PartialEq, Eq, PartialOrd, Copy, Ord, Hash, Debug)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct TraceAction {
     pub lf_id : usize,
     pub act_kind : TraceActionKind,
-    pub ms_id : usize
+    pub ms_id : usize,
+    pub args : Vec<ValuePattern> // concrete values in multi-traces, possibly wildcards if derived from interactions
 }
 
 impl TraceAction {
 
     pub fn new(lf_id : usize,
                act_kind : TraceActionKind,
-               ms_id : usize) -> TraceAction {
-        return TraceAction{lf_id,act_kind,ms_id};
+               ms_id : usize,
+               args : Vec<ValuePattern>) -> TraceAction {
+        return TraceAction{lf_id,act_kind,ms_id,args};
+    }
+
+    // whether or not a concrete action (e.g. from a multi-trace) matches this one
+    pub fn matches(&self, other : &TraceAction) -> bool {
+        return self.lf_id == other.lf_id
+            && self.act_kind == other.act_kind
+            && self.ms_id == other.ms_id
+            && arguments_match(&self.args, &other.args);
+    }
+
+    // whether or not each action of a concrete multi-action can be matched to a distinct action of this multi-action
+    pub fn multi_action_matches(patterns : &BTreeSet<TraceAction>, concrete : &BTreeSet<TraceAction>) -> bool {
+        if patterns.len() != concrete.len() {
+            return false;
+        }
+        let patterns : Vec<&TraceAction> = patterns.iter().collect();
+        let concrete : Vec<&TraceAction> = concrete.iter().collect();
+        let mut used : Vec<bool> = vec![false; patterns.len()];
+        return TraceAction::match_remaining(&patterns, &concrete, 0, &mut used);
+    }
+
+    fn match_remaining(patterns : &Vec<&TraceAction>,
+                       concrete : &Vec<&TraceAction>,
+                       idx : usize,
+                       used : &mut Vec<bool>) -> bool {
+        match concrete.get(idx) {
+            None => {
+                return true;
+            },
+            Some( got_act ) => {
+                for (pat_idx,pattern) in patterns.iter().enumerate() {
+                    if !used[pat_idx] && pattern.matches(got_act) {
+                        used[pat_idx] = true;
+                        if TraceAction::match_remaining(patterns, concrete, idx+1, used) {
+                            return true;
+                        }
+                        used[pat_idx] = false;
+                    }
+                }
+                return false;
+            }
+        }
     }
 
     pub fn get_actions_kinds(set_of_actions : &BTreeSet<TraceAction>) -> (i32,i32) {
@@ -59,3 +105,20 @@ impl TraceAction {
 }
 
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ok(lf_id : usize, arg : ValuePattern) -> TraceAction {
+        return TraceAction::new(lf_id,TraceActionKind::Emission,0,vec![arg]);
+    }
+
+    #[test]
+    fn multi_actions_match_whatever_the_order_of_patterns() {
+        // the wildcard must not be used for the value which only the other pattern matches
+        let patterns = btreeset!{ok(0,ValuePattern::Any),ok(0,ValuePattern::Integer(1))};
+        assert!(TraceAction::multi_action_matches(&patterns,&btreeset!{ok(0,ValuePattern::Integer(1)),ok(0,ValuePattern::Integer(2))}));
+        assert!(!TraceAction::multi_action_matches(&patterns,&btreeset!{ok(0,ValuePattern::Integer(2)),ok(0,ValuePattern::Integer(3))}));
+        assert!(!TraceAction::multi_action_matches(&patterns,&btreeset!{ok(0,ValuePattern::Integer(1))}));
+    }
+}
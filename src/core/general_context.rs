@@ -15,17 +15,22 @@ limitations under the License.
 */
 
 
-use std::collections::{BTreeSet, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::iter::FromIterator;
 
 
 use crate::core::error::HibouCoreError;
+use crate::core::language::syntax::value::ArgumentType;
 
 #[derive(Clone, PartialEq, Debug)]
 pub struct GeneralContext {
     lf_names : Vec<String>,
     ms_names : Vec<String>,
-    gt_names : Vec<String>
+    // types of the arguments of each message
+    ms_arg_types : Vec<Vec<ArgumentType>>,
+    gt_names : Vec<String>,
+    // families of lifelines, each one with the ids of its members
+    lf_families : Vec<(String,Vec<usize>)>
 }
 
 
@@ -36,7 +41,9 @@ impl GeneralContext {
         return GeneralContext {
             lf_names: Vec::new(),
             ms_names: Vec::new(),
-            gt_names: Vec::new()
+            ms_arg_types: Vec::new(),
+            gt_names: Vec::new(),
+            lf_families: Vec::new()
         }
     }
 
@@ -60,6 +67,7 @@ impl GeneralContext {
         match self.get_ms_id(&ms_name) {
             None => {
                 self.ms_names.push(ms_name);
+                self.ms_arg_types.push(Vec::new());
                 return self.ms_names.len() - 1;
             },
             Some(ms_id) => {
@@ -80,6 +88,45 @@ impl GeneralContext {
         }
     }
 
+    pub fn set_ms_arg_types(&mut self, ms_id : usize, arg_types : Vec<ArgumentType>) {
+        self.ms_arg_types[ms_id] = arg_types;
+    }
+
+    pub fn add_lf_family(&mut self, family_name : String, members : Vec<usize>) {
+        self.lf_families.push( (family_name,members) );
+    }
+
+    pub fn get_lf_families(&self) -> &Vec<(String,Vec<usize>)> {
+        return &self.lf_families;
+    }
+
+    pub fn get_lf_family(&self, family_name : &str) -> Option<&Vec<usize>> {
+        return self.lf_families.iter().find(|(name,_)| name == family_name).map(|(_,members)| members);
+    }
+
+    // the signature restricted to the given lifelines (messages and gates are all kept)
+    // lifeline families are kept only if all their members are
+    pub fn restricted_to_lifelines(&self, lf_ids : &BTreeSet<usize>) -> GeneralContext {
+        let mut restricted = GeneralContext::new();
+        let mut new_lf_ids : HashMap<usize,usize> = HashMap::new();
+        for lf_id in lf_ids {
+            new_lf_ids.insert(*lf_id, restricted.add_lf(self.lf_names[*lf_id].clone()));
+        }
+        for (ms_id,ms_name) in self.ms_names.iter().enumerate() {
+            let new_ms_id = restricted.add_msg(ms_name.clone());
+            restricted.set_ms_arg_types(new_ms_id, self.ms_arg_types[ms_id].clone());
+        }
+        for gt_name in &self.gt_names {
+            restricted.add_gt(gt_name.clone());
+        }
+        for (family_name,members) in &self.lf_families {
+            if members.iter().all(|lf_id| lf_ids.contains(lf_id)) {
+                restricted.add_lf_family(family_name.clone(), members.iter().map(|lf_id| new_lf_ids[lf_id]).collect());
+            }
+        }
+        return restricted;
+    }
+
     // ********** ********** ********** ********** ********** ********** **********
     // ********** ********** ********** ********** ********** ********** **********
     // ********** ********** ********** ********** ********** ********** **********
@@ -108,6 +155,10 @@ impl GeneralContext {
         return self.ms_names.len();
     }
 
+    pub fn get_gt_num(&self) -> usize {
+        return self.gt_names.len();
+    }
+
     // ********** ********** ********** ********** ********** ********** **********
     // ********** ********** ********** ********** ********** ********** **********
     // ********** ********** ********** ********** ********** ********** **********
@@ -142,6 +193,17 @@ impl GeneralContext {
         }
     }
 
+    pub fn get_ms_arg_types(&self, ms_id : usize) -> Result<Vec<ArgumentType>,HibouCoreError> {
+        match self.ms_arg_types.get(ms_id) {
+            None => {
+                return Err( HibouCoreError::UnknownMessage(ms_id) );
+            },
+            Some( arg_types ) => {
+                return Ok( arg_types.clone() );
+            }
+        }
+    }
+
     pub fn get_gt_name(&self, gt_id : usize) -> Result<String,HibouCoreError> {
         match self.gt_names.get(gt_id) {
             None => {
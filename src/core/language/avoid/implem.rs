@@ -71,6 +71,18 @@ impl AvoidsLifelines for Interaction {
             &Interaction::Loop(_, _) => {
                 return true;
             },
+            &Interaction::BoundedLoop(_, min, _, ref i1) => {
+                return min == 0 || i1.avoids_all_of(lf_ids);
+            },
+            &Interaction::Neg(_) => {
+                return true;
+            },
+            &Interaction::Assert(ref i1) => {
+                return i1.avoids_all_of(lf_ids);
+            },
+            &Interaction::Disrupt(ref i1, ref i2) => {
+                return i1.avoids_all_of(lf_ids) || i2.avoids_all_of(lf_ids);
+            },
             _ => {
                 panic!("non-conform interaction");
             }
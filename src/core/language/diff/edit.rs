@@ -0,0 +1,76 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+// an elementary edit transforming a (left) interaction into another (right) one
+// nodes are those of the interaction terms in which nested strict, seq, alt, par, and, coreg and sync
+// with the same parameters are flattened into n-ary operators (as when drawing sequence diagrams)
+// a node is given by its label i.e. the interaction at its position where all operands are replaced by the empty interaction
+#[derive(Clone, PartialEq, Debug)]
+pub enum InteractionEdit {
+    // the node at the given position of the left interaction is removed, its operands taking its place
+    DeleteNode(Position,Interaction),
+    // the whole sub-interaction at the given position of the left interaction is removed
+    DeleteSubInteraction(Position,Interaction),
+    // a node is added at the given position of the right interaction, enclosing some of the pre-existing operands
+    InsertNode(Position,Interaction),
+    // the whole sub-interaction at the given position of the right interaction is added
+    InsertSubInteraction(Position,Interaction),
+    // the label of the node at the first position of the left interaction becomes that of the node at the second position of the right interaction
+    Relabel(Position,Position,Interaction,Interaction)
+}
+
+impl InteractionEdit {
+
+    pub fn get_left_position(&self) -> Option<&Position> {
+        match self {
+            InteractionEdit::DeleteNode(pos,_) => {
+                return Some(pos);
+            },
+            InteractionEdit::DeleteSubInteraction(pos,_) => {
+                return Some(pos);
+            },
+            InteractionEdit::Relabel(pos,_,_,_) => {
+                return Some(pos);
+            },
+            _ => {
+                return None;
+            }
+        }
+    }
+
+    pub fn get_right_position(&self) -> Option<&Position> {
+        match self {
+            InteractionEdit::InsertNode(pos,_) => {
+                return Some(pos);
+            },
+            InteractionEdit::InsertSubInteraction(pos,_) => {
+                return Some(pos);
+            },
+            InteractionEdit::Relabel(_,pos,_,_) => {
+                return Some(pos);
+            },
+            _ => {
+                return None;
+            }
+        }
+    }
+
+}
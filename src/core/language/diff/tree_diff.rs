@@ -0,0 +1,388 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::core::language::diff::edit::InteractionEdit;
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+#[derive(Clone, Copy, PartialEq)]
+enum PathStep {
+    Left,
+    Right
+}
+
+fn path_as_position(path : &[PathStep]) -> Position {
+    let mut position = Position::Epsilon(None);
+    for step in path.iter().rev() {
+        match step {
+            PathStep::Left => {
+                position = Position::Left(Box::new(position));
+            },
+            PathStep::Right => {
+                position = Position::Right(Box::new(position));
+            }
+        }
+    }
+    return position;
+}
+
+fn extended_path(path : &[PathStep], step : PathStep) -> Vec<PathStep> {
+    let mut new_path = path.to_vec();
+    new_path.push(step);
+    return new_path;
+}
+
+// ***
+
+// the label of a node is the interaction at this node where all operands are replaced by the empty interaction
+fn node_label(interaction : &Interaction) -> Interaction {
+    let empty = Box::new(Interaction::Empty);
+    match interaction {
+        Interaction::Strict(_,_) => {
+            return Interaction::Strict(empty.clone(),empty);
+        },
+        Interaction::Seq(_,_) => {
+            return Interaction::Seq(empty.clone(),empty);
+        },
+        Interaction::CoReg(cr,_,_) => {
+            return Interaction::CoReg(cr.clone(),empty.clone(),empty);
+        },
+        Interaction::Alt(_,_) => {
+            return Interaction::Alt(empty.clone(),empty);
+        },
+        Interaction::Par(_,_) => {
+            return Interaction::Par(empty.clone(),empty);
+        },
+        Interaction::And(_,_) => {
+            return Interaction::And(empty.clone(),empty);
+        },
+        Interaction::Sync(sync_acts,_,_) => {
+            return Interaction::Sync(sync_acts.clone(),empty.clone(),empty);
+        },
+        Interaction::Disrupt(_,_) => {
+            return Interaction::Disrupt(empty.clone(),empty);
+        },
+        Interaction::Loop(lk,_) => {
+            return Interaction::Loop(lk.clone(),empty);
+        },
+        Interaction::BoundedLoop(lk,min,max,_) => {
+            return Interaction::BoundedLoop(lk.clone(),*min,*max,empty);
+        },
+        Interaction::Neg(_) => {
+            return Interaction::Neg(empty);
+        },
+        Interaction::Assert(_) => {
+            return Interaction::Assert(empty);
+        },
+        _ => {
+            return interaction.clone();
+        }
+    }
+}
+
+fn is_leaf_label(label : &Interaction) -> bool {
+    match label {
+        Interaction::Empty => {
+            return true;
+        },
+        Interaction::Emission(_) => {
+            return true;
+        },
+        Interaction::Reception(_) => {
+            return true;
+        },
+        _ => {
+            return false;
+        }
+    }
+}
+
+// operands which are nested under the same associative operator (with the same parameters) are gathered
+fn collect_flattened_operands<'a>(label : &Interaction,
+                                  interaction : &'a Interaction,
+                                  path : Vec<PathStep>,
+                                  operands : &mut Vec<(&'a Interaction,Vec<PathStep>)>) {
+    let nested = match (label,interaction) {
+        (Interaction::Strict(_,_),Interaction::Strict(i1,i2)) => Some((i1,i2)),
+        (Interaction::Seq(_,_),Interaction::Seq(i1,i2)) => Some((i1,i2)),
+        (Interaction::Alt(_,_),Interaction::Alt(i1,i2)) => Some((i1,i2)),
+        (Interaction::Par(_,_),Interaction::Par(i1,i2)) => Some((i1,i2)),
+        (Interaction::And(_,_),Interaction::And(i1,i2)) => Some((i1,i2)),
+        (Interaction::CoReg(cr1,_,_),Interaction::CoReg(cr2,i1,i2)) if cr1 == cr2 => Some((i1,i2)),
+        (Interaction::Sync(acts1,_,_),Interaction::Sync(acts2,i1,i2)) if acts1 == acts2 => Some((i1,i2)),
+        _ => None
+    };
+    match nested {
+        None => {
+            operands.push((interaction,path));
+        },
+        Some( (i1,i2) ) => {
+            collect_flattened_operands(label,i1,extended_path(&path,PathStep::Left),operands);
+            collect_flattened_operands(label,i2,extended_path(&path,PathStep::Right),operands);
+        }
+    }
+}
+
+fn get_operands<'a>(interaction : &'a Interaction,
+                    path : &[PathStep]) -> Vec<(&'a Interaction,Vec<PathStep>)> {
+    let mut operands = vec![];
+    match interaction {
+        Interaction::Strict(i1,i2) | Interaction::Seq(i1,i2) | Interaction::Alt(i1,i2)
+        | Interaction::Par(i1,i2) | Interaction::And(i1,i2)
+        | Interaction::CoReg(_,i1,i2) | Interaction::Sync(_,i1,i2) => {
+            let label = node_label(interaction);
+            collect_flattened_operands(&label,i1,extended_path(path,PathStep::Left),&mut operands);
+            collect_flattened_operands(&label,i2,extended_path(path,PathStep::Right),&mut operands);
+        },
+        Interaction::Disrupt(i1,i2) => {
+            operands.push((i1,extended_path(path,PathStep::Left)));
+            operands.push((i2,extended_path(path,PathStep::Right)));
+        },
+        Interaction::Loop(_,i1) | Interaction::BoundedLoop(_,_,_,i1)
+        | Interaction::Neg(i1) | Interaction::Assert(i1) => {
+            operands.push((i1,extended_path(path,PathStep::Left)));
+        },
+        _ => {}
+    }
+    return operands;
+}
+
+// ***
+
+struct DiffNode<'a> {
+    interaction : &'a Interaction,
+    label : Interaction,
+    position : Position,
+    // index (in postorder) of the leftmost leaf of the subtree rooted at this node
+    leftmost : usize,
+    parent : Option<usize>
+}
+
+// nodes are stored in postorder
+fn collect_nodes<'a>(interaction : &'a Interaction,
+                     path : &[PathStep],
+                     nodes : &mut Vec<DiffNode<'a>>) -> usize {
+    let leftmost = nodes.len();
+    let mut children = vec![];
+    for (operand,operand_path) in get_operands(interaction,path) {
+        children.push( collect_nodes(operand,&operand_path,nodes) );
+    }
+    let node_id = nodes.len();
+    for child_id in children {
+        nodes[child_id].parent = Some(node_id);
+    }
+    nodes.push( DiffNode{interaction,
+        label:node_label(interaction),
+        position:path_as_position(path),
+        leftmost,
+        parent:None} );
+    return node_id;
+}
+
+// forests are intervals [start,end) of nodes in postorder
+struct TreeDiffer<'a> {
+    left : Vec<DiffNode<'a>>,
+    right : Vec<DiffNode<'a>>,
+    memo : HashMap<(usize,usize,usize,usize),usize>,
+    // edit script found by backtracking
+    deleted : BTreeSet<usize>,
+    inserted : BTreeSet<usize>,
+    relabelled : Vec<(usize,usize)>
+}
+
+impl<'a> TreeDiffer<'a> {
+
+    fn relabel_cost(&self, left_id : usize, right_id : usize) -> Option<usize> {
+        let left_label = &self.left[left_id].label;
+        let right_label = &self.right[right_id].label;
+        if left_label == right_label {
+            return Some(0);
+        }
+        if is_leaf_label(left_label) == is_leaf_label(right_label) {
+            return Some(1);
+        }
+        // an action never becomes an operator and conversely
+        return None;
+    }
+
+    fn forest_distance(&mut self, l_start : usize, l_end : usize, r_start : usize, r_end : usize) -> usize {
+        if l_start == l_end {
+            return r_end - r_start;
+        }
+        if r_start == r_end {
+            return l_end - l_start;
+        }
+        if let Some(got) = self.memo.get(&(l_start,l_end,r_start,r_end)) {
+            return *got;
+        }
+        let l_root = l_end - 1;
+        let r_root = r_end - 1;
+        let mut distance = 1 + std::cmp::min(self.forest_distance(l_start,l_root,r_start,r_end),
+                                             self.forest_distance(l_start,l_end,r_start,r_root));
+        if let Some(cost) = self.relabel_cost(l_root,r_root) {
+            let l_leftmost = self.left[l_root].leftmost;
+            let r_leftmost = self.right[r_root].leftmost;
+            let matched = cost
+                + self.forest_distance(l_leftmost,l_root,r_leftmost,r_root)
+                + self.forest_distance(l_start,l_leftmost,r_start,r_leftmost);
+            distance = std::cmp::min(distance,matched);
+        }
+        self.memo.insert((l_start,l_end,r_start,r_end),distance);
+        return distance;
+    }
+
+    // follows the choices of an optimal edit script, matching nodes whenever possible
+    fn backtrack(&mut self, l_start : usize, l_end : usize, r_start : usize, r_end : usize) {
+        if l_start == l_end {
+            self.inserted.extend(r_start..r_end);
+            return;
+        }
+        if r_start == r_end {
+            self.deleted.extend(l_start..l_end);
+            return;
+        }
+        let distance = self.forest_distance(l_start,l_end,r_start,r_end);
+        let l_root = l_end - 1;
+        let r_root = r_end - 1;
+        if let Some(cost) = self.relabel_cost(l_root,r_root) {
+            let l_leftmost = self.left[l_root].leftmost;
+            let r_leftmost = self.right[r_root].leftmost;
+            let matched = cost
+                + self.forest_distance(l_leftmost,l_root,r_leftmost,r_root)
+                + self.forest_distance(l_start,l_leftmost,r_start,r_leftmost);
+            if matched == distance {
+                if cost > 0 {
+                    self.relabelled.push((l_root,r_root));
+                }
+                self.backtrack(l_leftmost,l_root,r_leftmost,r_root);
+                self.backtrack(l_start,l_leftmost,r_start,r_leftmost);
+                return;
+            }
+        }
+        if 1 + self.forest_distance(l_start,l_root,r_start,r_end) == distance {
+            self.deleted.insert(l_root);
+            self.backtrack(l_start,l_root,r_start,r_end);
+        } else {
+            self.inserted.insert(r_root);
+            self.backtrack(l_start,l_end,r_start,r_root);
+        }
+    }
+
+}
+
+// when all the nodes of a subtree are removed (resp. added) this is reported as a single edit on the whole sub-interaction
+fn gather_edits(nodes : &[DiffNode],
+                edited : &BTreeSet<usize>,
+                on_node : &dyn Fn(Position,Interaction) -> InteractionEdit,
+                on_sub_interaction : &dyn Fn(Position,Interaction) -> InteractionEdit) -> Vec<InteractionEdit> {
+    let whole_subtree = |node_id : usize| (nodes[node_id].leftmost..=node_id).all(|id| edited.contains(&id));
+    let mut edits = vec![];
+    for node_id in edited {
+        let node = &nodes[*node_id];
+        if whole_subtree(*node_id) {
+            let parent_whole = node.parent.is_some_and(whole_subtree);
+            if !parent_whole {
+                edits.push( on_sub_interaction(node.position.clone(),node.interaction.clone()) );
+            }
+        } else {
+            edits.push( on_node(node.position.clone(),node.label.clone()) );
+        }
+    }
+    return edits;
+}
+
+// returns the tree edit distance between both interactions (counted in node operations)
+// together with an edit script transforming the left interaction into the right one
+// edits on the left interaction come first, followed by insertions, each in prefix order of positions
+pub fn diff_interactions(left_int : &Interaction,
+                         right_int : &Interaction) -> (usize,Vec<InteractionEdit>) {
+    let mut left = vec![];
+    collect_nodes(left_int,&[],&mut left);
+    let mut right = vec![];
+    collect_nodes(right_int,&[],&mut right);
+    let (left_num,right_num) = (left.len(),right.len());
+    let mut differ = TreeDiffer{left,right,
+        memo:HashMap::new(),
+        deleted:BTreeSet::new(),
+        inserted:BTreeSet::new(),
+        relabelled:vec![]};
+    // ***
+    let distance = differ.forest_distance(0,left_num,0,right_num);
+    differ.backtrack(0,left_num,0,right_num);
+    // ***
+    let mut left_edits = gather_edits(&differ.left,
+                                      &differ.deleted,
+                                      &|pos,int| InteractionEdit::DeleteNode(pos,int),
+                                      &|pos,int| InteractionEdit::DeleteSubInteraction(pos,int));
+    for (left_id,right_id) in &differ.relabelled {
+        let (left_node,right_node) = (&differ.left[*left_id],&differ.right[*right_id]);
+        left_edits.push( InteractionEdit::Relabel(left_node.position.clone(),
+                                                  right_node.position.clone(),
+                                                  left_node.label.clone(),
+                                                  right_node.label.clone()) );
+    }
+    left_edits.sort_by_key(|edit| edit.get_left_position().unwrap().to_string());
+    let mut right_edits = gather_edits(&differ.right,
+                                       &differ.inserted,
+                                       &|pos,int| InteractionEdit::InsertNode(pos,int),
+                                       &|pos,int| InteractionEdit::InsertSubInteraction(pos,int));
+    right_edits.sort_by_key(|edit| edit.get_right_position().unwrap().to_string());
+    left_edits.append(&mut right_edits);
+    return (distance,left_edits);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::diff::edit::InteractionEdit;
+    use crate::core::language::diff::tree_diff::diff_interactions;
+    use crate::core::language::position::position::Position;
+    use crate::core::language::syntax::interaction::{Interaction, LoopKind};
+    use crate::util::test_fixtures::message;
+
+    #[test]
+    fn diff_flattens_operators_and_gathers_subtrees() {
+        let (a,b,c) = (message(0,0,1),message(1,1,0),message(0,2,1));
+        // seq(a,b,c) and seq(seq(a,b),c) only differ by associativity
+        let left = Interaction::Seq(Box::new(a.clone()),Box::new(Interaction::Seq(Box::new(b.clone()),Box::new(c.clone()))));
+        let reassociated = Interaction::Seq(Box::new(Interaction::Seq(Box::new(a.clone()),Box::new(b.clone()))),Box::new(c.clone()));
+        assert_eq!(diff_interactions(&left,&reassociated), (0,vec![]));
+        // par(a,loopS(seq(b,c)))
+        let loop_body = Interaction::Seq(Box::new(b.clone()),Box::new(c.clone()));
+        let right = Interaction::Par(Box::new(a.clone()),Box::new(Interaction::Loop(LoopKind::SStrictSeq,Box::new(loop_body.clone()))));
+        let (distance,edits) = diff_interactions(&left,&right);
+        // relabel seq into par, insert loopS and seq
+        assert_eq!(distance, 3);
+        assert_eq!(edits, vec![
+            InteractionEdit::Relabel(Position::Epsilon(None),Position::Epsilon(None),
+                                     Interaction::Seq(Box::new(Interaction::Empty),Box::new(Interaction::Empty)),
+                                     Interaction::Par(Box::new(Interaction::Empty),Box::new(Interaction::Empty))),
+            InteractionEdit::InsertNode(Position::Right(Box::new(Position::Epsilon(None))),Interaction::Loop(LoopKind::SStrictSeq,Box::new(Interaction::Empty))),
+            InteractionEdit::InsertNode(Position::Right(Box::new(Position::Left(Box::new(Position::Epsilon(None))))),
+                                        Interaction::Seq(Box::new(Interaction::Empty),Box::new(Interaction::Empty)))
+        ]);
+        // a wholly new operand is reported once
+        let extended = Interaction::Alt(Box::new(right.clone()),Box::new(a.clone()));
+        let (distance,edits) = diff_interactions(&a,&extended);
+        assert_eq!(distance, 7);
+        assert_eq!(edits.len(), 2);
+        assert_eq!(edits[1], InteractionEdit::InsertSubInteraction(Position::Left(Box::new(Position::Epsilon(None))),right));
+    }
+}
@@ -46,7 +46,8 @@ impl LifelineEliminable for EmissionAction {
                 let new_act = ReceptionAction::new(None,
                                                       self.ms_id,
                                                       self.synchronicity.clone(),
-                                                      target_lfs);
+                                                      target_lfs,
+                                                      self.args.clone());
                 return Interaction::Reception( new_act );
             } else {
                 return Interaction::Empty;
@@ -68,7 +69,8 @@ impl LifelineEliminable for EmissionAction {
             let new_act = EmissionAction::new(self.origin_lf_id,
                                                  self.ms_id,
                                                  self.synchronicity.clone(),
-                                                 targets);
+                                                 targets,
+                                                 self.args.clone());
             return Interaction::Emission( new_act );
         }
     }
@@ -91,7 +93,8 @@ impl LifelineEliminable for ReceptionAction {
             let new_act = ReceptionAction::new(self.origin_gt_id.clone(),
                                                   self.ms_id,
                                                   self.synchronicity.clone(),
-                                                  target_lfs);
+                                                  target_lfs,
+                                                  self.args.clone());
             return Interaction::Reception( new_act );
         } else {
             return Interaction::Empty;
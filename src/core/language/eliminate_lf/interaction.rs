@@ -188,6 +188,53 @@ impl LifelineEliminable for Interaction {
                     }
                 }
             },
+            Interaction::BoundedLoop(opkind,min,max,i1) => {
+                let new_i1 = i1.eliminate_lifelines(lfs_to_eliminate);
+                match &new_i1 {
+                    Interaction::Empty => {
+                        return Interaction::Empty;
+                    },
+                    _ => {
+                        return Interaction::BoundedLoop(opkind.clone(),*min,*max,Box::new(new_i1) );
+                    }
+                }
+            },
+            Interaction::Neg(i1) => {
+                let new_i1 = i1.eliminate_lifelines(lfs_to_eliminate);
+                match &new_i1 {
+                    Interaction::Empty => {
+                        return Interaction::Empty;
+                    },
+                    _ => {
+                        return Interaction::Neg(Box::new(new_i1) );
+                    }
+                }
+            },
+            Interaction::Assert(i1) => {
+                let new_i1 = i1.eliminate_lifelines(lfs_to_eliminate);
+                match &new_i1 {
+                    Interaction::Empty => {
+                        return Interaction::Empty;
+                    },
+                    _ => {
+                        return Interaction::Assert(Box::new(new_i1) );
+                    }
+                }
+            },
+            Interaction::Disrupt(i1,i2) => {
+                let new_i1 = i1.eliminate_lifelines(lfs_to_eliminate);
+                let new_i2 = i2.eliminate_lifelines(lfs_to_eliminate);
+                // an interruption which is no longer visible still cuts off the interrupted sub-interaction
+                // so that the empty interrupt is kept
+                match (&new_i1,&new_i2) {
+                    (Interaction::Empty,Interaction::Empty) => {
+                        return Interaction::Empty;
+                    },
+                    _ => {
+                        return Interaction::Disrupt(Box::new(new_i1), Box::new(new_i2));
+                    }
+                }
+            },
             _ => {
                 panic!("non-conform interaction");
             }
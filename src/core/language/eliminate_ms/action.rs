@@ -0,0 +1,48 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+use crate::core::language::eliminate_ms::eliminable::MessageEliminable;
+use crate::core::language::syntax::action::{EmissionAction, ReceptionAction};
+use crate::core::language::syntax::interaction::Interaction;
+
+
+// an emission is removed together with the receptions it targets
+impl MessageEliminable for EmissionAction {
+
+    fn eliminate_messages(&self, ms_to_eliminate: &BTreeSet<usize>) -> Interaction {
+        if ms_to_eliminate.contains(&self.ms_id) {
+            return Interaction::Empty;
+        } else {
+            return Interaction::Emission( self.clone() );
+        }
+    }
+
+}
+
+impl MessageEliminable for ReceptionAction {
+
+    fn eliminate_messages(&self, ms_to_eliminate: &BTreeSet<usize>) -> Interaction {
+        if ms_to_eliminate.contains(&self.ms_id) {
+            return Interaction::Empty;
+        } else {
+            return Interaction::Reception( self.clone() );
+        }
+    }
+
+}
@@ -0,0 +1,29 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+// removes all the actions carrying the given messages while keeping the operators which order the remaining ones
+// unlike lifeline elimination, no lifeline disappears so that no operator needs to be weakened
+pub trait MessageEliminable {
+
+    fn eliminate_messages(&self, ms_to_eliminate : &BTreeSet<usize>) -> Interaction;
+
+}
@@ -0,0 +1,206 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+use crate::core::language::eliminate_ms::eliminable::MessageEliminable;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+// for operators where an empty operand is neutral
+fn without_empty_operand(new_i1 : Interaction,
+                         new_i2 : Interaction,
+                         operator : &dyn Fn(Box<Interaction>,Box<Interaction>) -> Interaction) -> Interaction {
+    match (&new_i1,&new_i2) {
+        (Interaction::Empty,_) => {
+            return new_i2;
+        },
+        (_,Interaction::Empty) => {
+            return new_i1;
+        },
+        _ => {
+            return operator(Box::new(new_i1),Box::new(new_i2));
+        }
+    }
+}
+
+impl MessageEliminable for Interaction {
+
+    fn eliminate_messages(&self, ms_to_eliminate: &BTreeSet<usize>) -> Interaction {
+        match self {
+            Interaction::Empty => {
+                return Interaction::Empty;
+            },
+            Interaction::Emission( ref em_act ) => {
+                return em_act.eliminate_messages(ms_to_eliminate);
+            },
+            Interaction::Reception( ref rc_act ) => {
+                return rc_act.eliminate_messages(ms_to_eliminate);
+            },
+            Interaction::Strict(i1,i2) => {
+                return without_empty_operand(i1.eliminate_messages(ms_to_eliminate),
+                                             i2.eliminate_messages(ms_to_eliminate),
+                                             &Interaction::Strict);
+            },
+            Interaction::Seq(i1,i2) => {
+                return without_empty_operand(i1.eliminate_messages(ms_to_eliminate),
+                                             i2.eliminate_messages(ms_to_eliminate),
+                                             &Interaction::Seq);
+            },
+            Interaction::Par(i1,i2) => {
+                return without_empty_operand(i1.eliminate_messages(ms_to_eliminate),
+                                             i2.eliminate_messages(ms_to_eliminate),
+                                             &Interaction::Par);
+            },
+            Interaction::CoReg(cr,i1,i2) => {
+                return without_empty_operand(i1.eliminate_messages(ms_to_eliminate),
+                                             i2.eliminate_messages(ms_to_eliminate),
+                                             &|b1,b2| Interaction::CoReg(cr.clone(),b1,b2));
+            },
+            Interaction::Sync(sync_acts,i1,i2) => {
+                let new_sync_acts : Vec<_> = sync_acts.iter()
+                    .filter(|sync_act| !ms_to_eliminate.contains(&sync_act.ms_id))
+                    .cloned()
+                    .collect();
+                let new_i1 = i1.eliminate_messages(ms_to_eliminate);
+                let new_i2 = i2.eliminate_messages(ms_to_eliminate);
+                if new_sync_acts.is_empty() {
+                    return without_empty_operand(new_i1,new_i2,&Interaction::Par);
+                } else {
+                    return Interaction::Sync(new_sync_acts,Box::new(new_i1),Box::new(new_i2));
+                }
+            },
+            Interaction::Alt(i1,i2) => {
+                // the empty interaction remains an alternative
+                let new_i1 = i1.eliminate_messages(ms_to_eliminate);
+                let new_i2 = i2.eliminate_messages(ms_to_eliminate);
+                match (&new_i1,&new_i2) {
+                    (Interaction::Empty,Interaction::Empty) => {
+                        return Interaction::Empty;
+                    },
+                    _ => {
+                        return Interaction::Alt(Box::new(new_i1),Box::new(new_i2));
+                    }
+                }
+            },
+            Interaction::And(i1,i2) => {
+                let new_i1 = i1.eliminate_messages(ms_to_eliminate);
+                let new_i2 = i2.eliminate_messages(ms_to_eliminate);
+                match (&new_i1,&new_i2) {
+                    (Interaction::Empty,Interaction::Empty) => {
+                        return Interaction::Empty;
+                    },
+                    _ => {
+                        return Interaction::And(Box::new(new_i1),Box::new(new_i2));
+                    }
+                }
+            },
+            Interaction::Loop(opkind,i1) => {
+                let new_i1 = i1.eliminate_messages(ms_to_eliminate);
+                match &new_i1 {
+                    Interaction::Empty => {
+                        return Interaction::Empty;
+                    },
+                    Interaction::Loop(opkind2,i11) => {
+                        return Interaction::Loop((opkind.min(opkind2)).clone(), i11.clone());
+                    },
+                    _ => {
+                        return Interaction::Loop(opkind.clone(),Box::new(new_i1) );
+                    }
+                }
+            },
+            Interaction::BoundedLoop(opkind,min,max,i1) => {
+                let new_i1 = i1.eliminate_messages(ms_to_eliminate);
+                match &new_i1 {
+                    Interaction::Empty => {
+                        return Interaction::Empty;
+                    },
+                    _ => {
+                        return Interaction::BoundedLoop(opkind.clone(),*min,*max,Box::new(new_i1) );
+                    }
+                }
+            },
+            Interaction::Neg(i1) => {
+                let new_i1 = i1.eliminate_messages(ms_to_eliminate);
+                match &new_i1 {
+                    Interaction::Empty => {
+                        return Interaction::Empty;
+                    },
+                    _ => {
+                        return Interaction::Neg(Box::new(new_i1) );
+                    }
+                }
+            },
+            Interaction::Assert(i1) => {
+                let new_i1 = i1.eliminate_messages(ms_to_eliminate);
+                match &new_i1 {
+                    Interaction::Empty => {
+                        return Interaction::Empty;
+                    },
+                    _ => {
+                        return Interaction::Assert(Box::new(new_i1) );
+                    }
+                }
+            },
+            Interaction::Disrupt(i1,i2) => {
+                let new_i1 = i1.eliminate_messages(ms_to_eliminate);
+                let new_i2 = i2.eliminate_messages(ms_to_eliminate);
+                // an unobserved interruption still cuts off the interrupted sub-interaction
+                match (&new_i1,&new_i2) {
+                    (Interaction::Empty,Interaction::Empty) => {
+                        return Interaction::Empty;
+                    },
+                    _ => {
+                        return Interaction::Disrupt(Box::new(new_i1), Box::new(new_i2));
+                    }
+                }
+            }
+        }
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::syntax::interaction::LoopKind;
+    use crate::util::test_fixtures::message;
+    use super::*;
+
+    #[test]
+    fn eliminated_messages_leave_the_other_orderings_untouched() {
+        // strict(a -- m1 -> b, alt(b -- m2 -> a, loopS(a -- m2 -> b)), a -- m3 -> b)
+        let alt = Interaction::Alt(Box::new(message(1,1,0)),
+                                   Box::new(Interaction::Loop(LoopKind::SStrictSeq,Box::new(message(0,1,1)))));
+        let int = Interaction::Strict(Box::new(message(0,0,1)),
+                                      Box::new(Interaction::Strict(Box::new(alt),Box::new(message(0,2,1)))));
+        let expected = Interaction::Strict(Box::new(message(0,0,1)),Box::new(message(0,2,1)));
+        assert_eq!(int.eliminate_messages(&btreeset!{1}), expected);
+        // the empty interaction remains an alternative
+        let int = Interaction::Alt(Box::new(message(0,0,1)),Box::new(message(0,1,1)));
+        assert_eq!(int.eliminate_messages(&btreeset!{1}), Interaction::Alt(Box::new(message(0,0,1)),Box::new(Interaction::Empty)));
+    }
+
+    #[test]
+    fn unobserved_interruptions_are_kept() {
+        // disrupt(a -- m1 -> b, b -- m2 -> a)
+        let int = Interaction::Disrupt(Box::new(message(0,0,1)),Box::new(message(1,1,0)));
+        let got = int.eliminate_messages(&btreeset!{1});
+        assert_eq!(got, Interaction::Disrupt(Box::new(message(0,0,1)),Box::new(Interaction::Empty)));
+        assert!(got.express_empty());
+    }
+}
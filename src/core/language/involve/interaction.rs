@@ -70,6 +70,20 @@ impl InvolvesLifelines for Interaction {
             &Interaction::Loop(_, i1) => {
                 return i1.involved_lifelines();
             },
+            &Interaction::BoundedLoop(_, _, _, i1) => {
+                return i1.involved_lifelines();
+            },
+            &Interaction::Neg(i1) => {
+                return i1.involved_lifelines();
+            },
+            &Interaction::Assert(i1) => {
+                return i1.involved_lifelines();
+            },
+            &Interaction::Disrupt(ref i1, ref i2) => {
+                let mut lf_ids = i1.involved_lifelines();
+                lf_ids.extend(i2.involved_lifelines());
+                return lf_ids;
+            },
             _ => {
                 panic!("non-conform interaction");
             }
@@ -108,6 +122,18 @@ impl InvolvesLifelines for Interaction {
             &Interaction::Loop(_, ref i1) => {
                 return i1.involves_any_of(lf_ids);
             },
+            &Interaction::BoundedLoop(_, _, _, ref i1) => {
+                return i1.involves_any_of(lf_ids);
+            },
+            &Interaction::Neg(ref i1) => {
+                return i1.involves_any_of(lf_ids);
+            },
+            &Interaction::Assert(ref i1) => {
+                return i1.involves_any_of(lf_ids);
+            },
+            &Interaction::Disrupt(ref i1, ref i2) => {
+                return i1.involves_any_of(lf_ids) || i2.involves_any_of(lf_ids);
+            },
             _ => {
                 panic!("non-conform interaction");
             }
@@ -0,0 +1,271 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use crate::core::execution::trace::from_model::from_model::PrimitiveInterpretableAsTraceAction;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::lint::warning::{LintWarning, LintWarningKind};
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::action::EmissionTargetRef;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::core::language::syntax::util::get_recursive_frag::get_recursive_alt_frags;
+
+
+pub fn lint_interaction(gen_ctx : &GeneralContext, interaction : &Interaction) -> Vec<LintWarning> {
+    let mut warnings = lint_declarations(gen_ctx, interaction);
+    warnings.extend( lint_term(interaction, false) );
+    return warnings;
+}
+
+// ***
+
+struct DeclarationsUsage {
+    lifelines : BTreeSet<usize>,
+    messages : BTreeSet<usize>,
+    gates : BTreeSet<usize>
+}
+
+fn lint_declarations(gen_ctx : &GeneralContext, interaction : &Interaction) -> Vec<LintWarning> {
+    let mut usage = DeclarationsUsage{lifelines:btreeset!{},messages:btreeset!{},gates:btreeset!{}};
+    collect_declarations_usage(interaction, &mut usage);
+    // ***
+    let mut warnings = vec![];
+    for lf_id in 0..gen_ctx.get_lf_num() {
+        if !usage.lifelines.contains(&lf_id) {
+            warnings.push( LintWarning::new(LintWarningKind::UnusedLifeline(lf_id), Position::Epsilon(None)) );
+        }
+    }
+    for ms_id in 0..gen_ctx.get_ms_num() {
+        if !usage.messages.contains(&ms_id) {
+            warnings.push( LintWarning::new(LintWarningKind::UnusedMessage(ms_id), Position::Epsilon(None)) );
+        }
+    }
+    // if the interaction has no gate at all, all the declared gates are unused
+    let has_gates = interaction.get_characteristics().has_gates;
+    for gt_id in 0..gen_ctx.get_gt_num() {
+        if !has_gates || !usage.gates.contains(&gt_id) {
+            warnings.push( LintWarning::new(LintWarningKind::UnusedGate(gt_id), Position::Epsilon(None)) );
+        }
+    }
+    return warnings;
+}
+
+fn collect_declarations_usage(interaction : &Interaction, usage : &mut DeclarationsUsage) {
+    match interaction {
+        Interaction::Empty => {},
+        Interaction::Emission(ref em_act) => {
+            usage.lifelines.insert(em_act.origin_lf_id);
+            usage.messages.insert(em_act.ms_id);
+            for tar_ref in &em_act.targets {
+                match tar_ref {
+                    EmissionTargetRef::Lifeline(tar_lf_id) => {
+                        usage.lifelines.insert(*tar_lf_id);
+                    },
+                    EmissionTargetRef::Gate(tar_gt_id) => {
+                        usage.gates.insert(*tar_gt_id);
+                    }
+                }
+            }
+        },
+        Interaction::Reception(ref rc_act) => {
+            usage.messages.insert(rc_act.ms_id);
+            usage.lifelines.extend(rc_act.recipients.iter().cloned());
+            if let Some(gt_id) = rc_act.origin_gt_id {
+                usage.gates.insert(gt_id);
+            }
+        },
+        Interaction::Strict(ref i1, ref i2)
+        | Interaction::Seq(ref i1, ref i2)
+        | Interaction::Par(ref i1, ref i2)
+        | Interaction::Alt(ref i1, ref i2)
+        | Interaction::Disrupt(ref i1, ref i2)
+        | Interaction::And(ref i1, ref i2) => {
+            collect_declarations_usage(i1, usage);
+            collect_declarations_usage(i2, usage);
+        },
+        Interaction::CoReg(ref cr, ref i1, ref i2) => {
+            usage.lifelines.extend(cr.iter().cloned());
+            collect_declarations_usage(i1, usage);
+            collect_declarations_usage(i2, usage);
+        },
+        Interaction::Sync(ref sync_acts, ref i1, ref i2) => {
+            for action in sync_acts {
+                usage.lifelines.insert(action.lf_id);
+                usage.messages.insert(action.ms_id);
+            }
+            collect_declarations_usage(i1, usage);
+            collect_declarations_usage(i2, usage);
+        },
+        Interaction::Loop(_, ref i1)
+        | Interaction::BoundedLoop(_, _, _, ref i1)
+        | Interaction::Neg(ref i1)
+        | Interaction::Assert(ref i1) => {
+            collect_declarations_usage(i1, usage);
+        }
+    }
+}
+
+// ***
+
+// returns the atomic actions which may occur in the traces of an interaction (over-approximation)
+// values carried by messages are ignored
+fn get_occurring_actions(interaction : &Interaction) -> BTreeSet<TraceAction> {
+    match interaction {
+        Interaction::Empty => {
+            return btreeset!{};
+        },
+        Interaction::Emission(ref em_act) => {
+            return em_act.get_all_atomic_actions().into_iter().map(|a| TraceAction::new(a.lf_id,a.act_kind,a.ms_id,vec![])).collect();
+        },
+        Interaction::Reception(ref rc_act) => {
+            return rc_act.get_all_atomic_actions().into_iter().map(|a| TraceAction::new(a.lf_id,a.act_kind,a.ms_id,vec![])).collect();
+        },
+        Interaction::Strict(ref i1, ref i2)
+        | Interaction::Seq(ref i1, ref i2)
+        | Interaction::CoReg(_, ref i1, ref i2)
+        | Interaction::Par(ref i1, ref i2)
+        | Interaction::Alt(ref i1, ref i2)
+        | Interaction::Disrupt(ref i1, ref i2)
+        | Interaction::And(ref i1, ref i2)
+        | Interaction::Sync(_, ref i1, ref i2) => {
+            let mut actions = get_occurring_actions(i1);
+            actions.extend( get_occurring_actions(i2) );
+            return actions;
+        },
+        Interaction::Loop(_, ref i1)
+        | Interaction::BoundedLoop(_, _, _, ref i1)
+        | Interaction::Neg(ref i1)
+        | Interaction::Assert(ref i1) => {
+            return get_occurring_actions(i1);
+        }
+    }
+}
+
+fn lint_term(interaction : &Interaction, in_alt_chain : bool) -> Vec<LintWarning> {
+    let mut warnings : Vec<LintWarning> = vec![];
+    match interaction {
+        Interaction::Empty | Interaction::Emission(_) | Interaction::Reception(_) => {},
+        Interaction::Strict(ref i1, ref i2)
+        | Interaction::Seq(ref i1, ref i2)
+        | Interaction::CoReg(_, ref i1, ref i2)
+        | Interaction::Par(ref i1, ref i2)
+        | Interaction::Disrupt(ref i1, ref i2) => {
+            warnings.extend( lint_binary_operands(i1, i2, false) );
+        },
+        Interaction::Alt(ref i1, ref i2) => {
+            // branches are compared only once, at the root of the n-ary alternative
+            if !in_alt_chain {
+                let branches = get_recursive_alt_frags(interaction);
+                for (idx1, branch1) in branches.iter().enumerate() {
+                    for (idx2, branch2) in branches.iter().enumerate().skip(idx1 + 1) {
+                        if branch1 == branch2 {
+                            warnings.push( LintWarning::new(LintWarningKind::IdenticalAltBranches(idx1,idx2), Position::Epsilon(None)) );
+                        }
+                    }
+                }
+            }
+            warnings.extend( lint_binary_operands(i1, i2, true) );
+        },
+        Interaction::And(ref i1, ref i2) => {
+            // a non-empty trace of one operand would be made of actions that never occur in the other
+            let disjoint_actions = get_occurring_actions(i1).is_disjoint(&get_occurring_actions(i2));
+            if disjoint_actions && !(i1.express_empty() && i2.express_empty()) {
+                warnings.push( LintWarning::new(LintWarningKind::EmptyAnd, Position::Epsilon(None)) );
+            }
+            warnings.extend( lint_binary_operands(i1, i2, false) );
+        },
+        Interaction::Sync(ref sync_acts, ref i1, ref i2) => {
+            let mut occurring = get_occurring_actions(i1);
+            occurring.extend( get_occurring_actions(i2) );
+            let useless : Vec<TraceAction> = sync_acts.iter()
+                .filter(|a| !occurring.contains(&TraceAction::new(a.lf_id,a.act_kind,a.ms_id,vec![])))
+                .cloned()
+                .collect();
+            if !useless.is_empty() {
+                warnings.push( LintWarning::new(LintWarningKind::UselessSyncActions(useless), Position::Epsilon(None)) );
+            }
+            warnings.extend( lint_binary_operands(i1, i2, false) );
+        },
+        Interaction::Loop(_, ref i1) | Interaction::BoundedLoop(_, _, _, ref i1) => {
+            if get_occurring_actions(i1).is_empty() {
+                warnings.push( LintWarning::new(LintWarningKind::EmptyLoopBody, Position::Epsilon(None)) );
+            }
+            warnings.extend( lint_unary_operand(i1) );
+        },
+        Interaction::Neg(ref i1) | Interaction::Assert(ref i1) => {
+            warnings.extend( lint_unary_operand(i1) );
+        }
+    }
+    return warnings;
+}
+
+fn lint_binary_operands(i1 : &Interaction, i2 : &Interaction, in_alt_chain : bool) -> Vec<LintWarning> {
+    let mut warnings : Vec<LintWarning> = vec![];
+    for warning in lint_term(i1, in_alt_chain) {
+        warnings.push( LintWarning::new(warning.kind, Position::Left(Box::new(warning.position))) );
+    }
+    for warning in lint_term(i2, in_alt_chain) {
+        warnings.push( LintWarning::new(warning.kind, Position::Right(Box::new(warning.position))) );
+    }
+    return warnings;
+}
+
+fn lint_unary_operand(i1 : &Interaction) -> Vec<LintWarning> {
+    return lint_term(i1, false).into_iter()
+        .map(|warning| LintWarning::new(warning.kind, Position::Left(Box::new(warning.position))))
+        .collect();
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::syntax::interaction::LoopKind;
+    use crate::util::test_fixtures::{context_with, message};
+    use super::*;
+
+    #[test]
+    fn warnings_are_located_in_the_interaction() {
+        let gen_ctx = context_with(&["a","b","c"],&["m1","m2"]);
+        // seq(alt(a -- m1 -> b, b -- m1 -> a, a -- m1 -> b), loopS(∅))
+        let alt = Interaction::Alt(Box::new(message(0,0,1)),
+                                   Box::new(Interaction::Alt(Box::new(message(1,0,0)),Box::new(message(0,0,1)))));
+        let int = Interaction::Seq(Box::new(alt),
+                                   Box::new(Interaction::Loop(LoopKind::SStrictSeq,Box::new(Interaction::Empty))));
+        let got = lint_interaction(&gen_ctx,&int);
+        let expected = vec![LintWarning::new(LintWarningKind::UnusedLifeline(2),Position::Epsilon(None)),
+                            LintWarning::new(LintWarningKind::UnusedMessage(1),Position::Epsilon(None)),
+                            LintWarning::new(LintWarningKind::IdenticalAltBranches(0,2),Position::Left(Box::new(Position::Epsilon(None)))),
+                            LintWarning::new(LintWarningKind::EmptyLoopBody,Position::Right(Box::new(Position::Epsilon(None))))];
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn operands_of_and_without_common_actions() {
+        let gen_ctx = context_with(&["a","b"],&["m1","m2"]);
+        // and(a -- m1 -> b, a -- m2 -> b)
+        let int = Interaction::And(Box::new(message(0,0,1)),Box::new(message(0,1,1)));
+        assert_eq!(lint_interaction(&gen_ctx,&int), vec![LintWarning::new(LintWarningKind::EmptyAnd,Position::Epsilon(None))]);
+        // and(a -- m1 -> b, loopS(a -- m1 -> b))
+        let int = Interaction::And(Box::new(message(0,0,1)),
+                                   Box::new(Interaction::Loop(LoopKind::SStrictSeq,Box::new(message(0,0,1)))));
+        let got = lint_interaction(&gen_ctx,&int);
+        assert!(got.iter().all(|warning| warning.kind != LintWarningKind::EmptyAnd));
+    }
+}
@@ -0,0 +1,81 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::language::position::position::Position;
+
+
+// a well-formedness issue which does not prevent the interaction from being used
+// but which likely indicates a modelling mistake
+#[derive(Clone, PartialEq, Debug)]
+pub enum LintWarningKind {
+    UnusedLifeline(usize),
+    UnusedMessage(usize),
+    UnusedGate(usize),
+    // actions of a sync set which never occur in either operand
+    UselessSyncActions(Vec<TraceAction>),
+    // an "and" of operands that have no trace in common
+    EmptyAnd,
+    // a loop the body of which can only express the empty trace
+    EmptyLoopBody,
+    // the indices (in the flattened n-ary alt) of two syntactically identical branches
+    IdenticalAltBranches(usize,usize)
+}
+
+impl LintWarningKind {
+
+    pub fn get_identifier(&self) -> &'static str {
+        match self {
+            LintWarningKind::UnusedLifeline(_) => {
+                return "unused_lifeline";
+            },
+            LintWarningKind::UnusedMessage(_) => {
+                return "unused_message";
+            },
+            LintWarningKind::UnusedGate(_) => {
+                return "unused_gate";
+            },
+            LintWarningKind::UselessSyncActions(_) => {
+                return "useless_sync_actions";
+            },
+            LintWarningKind::EmptyAnd => {
+                return "empty_and";
+            },
+            LintWarningKind::EmptyLoopBody => {
+                return "empty_loop_body";
+            },
+            LintWarningKind::IdenticalAltBranches(_,_) => {
+                return "identical_alt_branches";
+            }
+        }
+    }
+
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct LintWarning {
+    pub kind : LintWarningKind,
+    // position in the interaction term of the sub-interaction concerned by the warning
+    // declarations from the signature are associated to the root position
+    pub position : Position
+}
+
+impl LintWarning {
+    pub fn new(kind : LintWarningKind, position : Position) -> LintWarning {
+        return LintWarning{kind,position};
+    }
+}
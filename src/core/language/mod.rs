@@ -18,10 +18,13 @@ limitations under the License.
 pub mod syntax;
 pub mod involve;
 pub mod eliminate_lf;
+pub mod eliminate_ms;
 pub mod position;
 pub mod avoid;
 pub mod prune;
 pub mod unfold;
+pub mod lint;
+pub mod diff;
 //pub mod include;
 mod ord;
 
@@ -179,6 +179,26 @@ impl Ord for Interaction {
             (_,Interaction::Loop(_,_)) => {
                 return Ordering::Greater;
             },
+            // ***
+            (Interaction::BoundedLoop(self_lk,self_min,self_max,self_i1),Interaction::BoundedLoop(other_lk,other_min,other_max,other_i1)) => {
+                let cmp_lk = self_lk.cmp(&other_lk)
+                    .then(self_min.cmp(other_min))
+                    .then(self_max.cmp(other_max));
+                match &cmp_lk {
+                    Ordering::Equal => {
+                        return self_i1.cmp(other_i1);
+                    },
+                    _ => {
+                        return cmp_lk;
+                    }
+                }
+            },
+            (Interaction::BoundedLoop(_,_,_,_),_) => {
+                return Ordering::Less;
+            },
+            (_,Interaction::BoundedLoop(_,_,_,_)) => {
+                return Ordering::Greater;
+            },
             (Interaction::Sync(self_acts,self_i1,self_i2),Interaction::Sync(other_acts,other_i1,other_i2)) => {
                 let max_acts_len = self_acts.len().max(other_acts.len());
                 for i in 0..max_acts_len {
@@ -233,6 +253,38 @@ impl Ord for Interaction {
             },
             (_,Interaction::And(_,_)) => {
                 return Ordering::Greater;
+            },
+            // ***
+            (Interaction::Neg(self_i1),Interaction::Neg(other_i1)) => {
+                return self_i1.cmp(other_i1);
+            },
+            (Interaction::Neg(_),_) => {
+                return Ordering::Less;
+            },
+            (_,Interaction::Neg(_)) => {
+                return Ordering::Greater;
+            },
+            // ***
+            (Interaction::Assert(self_i1),Interaction::Assert(other_i1)) => {
+                return self_i1.cmp(other_i1);
+            },
+            (Interaction::Assert(_),_) => {
+                return Ordering::Less;
+            },
+            (_,Interaction::Assert(_)) => {
+                return Ordering::Greater;
+            },
+            // ***
+            (Interaction::Disrupt(self_i1,self_i2),Interaction::Disrupt(other_i1,other_i2)) => {
+                let cmp_left = self_i1.cmp(other_i1);
+                match &cmp_left {
+                    Ordering::Equal => {
+                        return self_i2.cmp(other_i2);
+                    },
+                    _ => {
+                        return cmp_left;
+                    }
+                }
             }
         }
     }
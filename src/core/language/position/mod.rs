@@ -15,5 +15,6 @@ limitations under the License.
 */
 
 pub mod position;
+pub mod sub_interaction;
 
 
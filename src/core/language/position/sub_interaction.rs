@@ -0,0 +1,55 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+
+
+// the sub-interaction which lies at the given position, if any
+// positions targeting both operands at once do not designate a single sub-interaction
+pub fn get_sub_interaction_at_position<'a>(interaction : &'a Interaction,
+                                           position : &Position) -> Option<&'a Interaction> {
+    let (sub_i1,sub_i2) : (Option<&Interaction>,Option<&Interaction>) = match interaction {
+        Interaction::Strict(i1,i2) => (Some(i1),Some(i2)),
+        Interaction::Seq(i1,i2) => (Some(i1),Some(i2)),
+        Interaction::CoReg(_,i1,i2) => (Some(i1),Some(i2)),
+        Interaction::Alt(i1,i2) => (Some(i1),Some(i2)),
+        Interaction::Par(i1,i2) => (Some(i1),Some(i2)),
+        Interaction::And(i1,i2) => (Some(i1),Some(i2)),
+        Interaction::Sync(_,i1,i2) => (Some(i1),Some(i2)),
+        Interaction::Disrupt(i1,i2) => (Some(i1),Some(i2)),
+        Interaction::Loop(_,i1) => (Some(i1),None),
+        Interaction::BoundedLoop(_,_,_,i1) => (Some(i1),None),
+        Interaction::Neg(i1) => (Some(i1),None),
+        Interaction::Assert(i1) => (Some(i1),None),
+        _ => (None,None)
+    };
+    match position {
+        Position::Epsilon(_) => {
+            return Some(interaction);
+        },
+        Position::Left(p1) => {
+            return sub_i1.and_then(|i1| get_sub_interaction_at_position(i1,p1));
+        },
+        Position::Right(p2) => {
+            return sub_i2.and_then(|i2| get_sub_interaction_at_position(i2,p2));
+        },
+        Position::Both(_,_) => {
+            return None;
+        }
+    }
+}
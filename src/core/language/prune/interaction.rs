@@ -156,6 +156,51 @@ impl LifelinePrunable for Interaction {
                 }
                 return Interaction::Empty;
             },
+            Interaction::BoundedLoop(lkind, min, max, i1) => {
+                if i1.avoids_all_of(lf_ids) {
+                    let pruned_i1 = i1.prune(lf_ids);
+                    if pruned_i1 != Interaction::Empty {
+                        return Interaction::BoundedLoop(lkind.clone(), *min, *max, Box::new(pruned_i1) );
+                    }
+                }
+                return Interaction::Empty;
+            },
+            Interaction::Neg(i1) => {
+                if i1.avoids_all_of(lf_ids) {
+                    let pruned_i1 = i1.prune(lf_ids);
+                    if pruned_i1 != Interaction::Empty {
+                        return Interaction::Neg(Box::new(pruned_i1) );
+                    }
+                }
+                return Interaction::Empty;
+            },
+            Interaction::Assert(i1) => {
+                let pruned_i1 = i1.prune(lf_ids);
+                if pruned_i1 != Interaction::Empty {
+                    return Interaction::Assert(Box::new(pruned_i1) );
+                }
+                return Interaction::Empty;
+            },
+            Interaction::Disrupt(i1, i2) => {
+                // the interruption can only occur if the interrupting sub-interaction avoids the lifelines
+                // if the interrupted sub-interaction does not, it must be interrupted right away
+                if i2.avoids_all_of(lf_ids) {
+                    let pruned_i2 = i2.prune(lf_ids);
+                    if i1.avoids_all_of(lf_ids) {
+                        let pruned_i1 = i1.prune(lf_ids);
+                        // an empty interruption still allows any prefix of the interrupted sub-interaction
+                        if pruned_i1 == Interaction::Empty && pruned_i2 == Interaction::Empty {
+                            return Interaction::Empty;
+                        } else {
+                            return Interaction::Disrupt( Box::new(pruned_i1), Box::new(pruned_i2) );
+                        }
+                    } else {
+                        return pruned_i2;
+                    }
+                } else {
+                    return i1.prune(lf_ids);
+                }
+            },
             _ => {
                 panic!("non-conform interaction");
             }
@@ -260,6 +305,60 @@ impl LifelinePrunable for Interaction {
                 }
                 return (Interaction::Empty,i1.involved_lifelines());
             },
+            Interaction::BoundedLoop(lkind, min, max, i1) => {
+                if i1.avoids_all_of(lf_ids) {
+                    let (pruned_i1,aff1) = i1.prune_with_affected(lf_ids);
+                    if pruned_i1 != Interaction::Empty {
+                        return (Interaction::BoundedLoop(lkind.clone(), *min, *max, Box::new(pruned_i1) ),aff1);
+                    } else {
+                        return (Interaction::Empty,aff1);
+                    }
+                }
+                return (Interaction::Empty,i1.involved_lifelines());
+            },
+            Interaction::Neg(i1) => {
+                if i1.avoids_all_of(lf_ids) {
+                    let (pruned_i1,aff1) = i1.prune_with_affected(lf_ids);
+                    if pruned_i1 != Interaction::Empty {
+                        return (Interaction::Neg(Box::new(pruned_i1) ),aff1);
+                    } else {
+                        return (Interaction::Empty,aff1);
+                    }
+                }
+                return (Interaction::Empty,i1.involved_lifelines());
+            },
+            Interaction::Assert(i1) => {
+                let (pruned_i1,aff1) = i1.prune_with_affected(lf_ids);
+                if pruned_i1 != Interaction::Empty {
+                    return (Interaction::Assert(Box::new(pruned_i1) ),aff1);
+                } else {
+                    return (Interaction::Empty,aff1);
+                }
+            },
+            Interaction::Disrupt(i1, i2) => {
+                if i2.avoids_all_of(lf_ids) {
+                    if i1.avoids_all_of(lf_ids) {
+                        let (pruned_i1,mut aff1) = i1.prune_with_affected(lf_ids);
+                        let (pruned_i2,aff2) = i2.prune_with_affected(lf_ids);
+                        aff1.extend(aff2);
+                        if pruned_i1 == Interaction::Empty && pruned_i2 == Interaction::Empty {
+                            return (Interaction::Empty,aff1);
+                        } else {
+                            return (Interaction::Disrupt( Box::new(pruned_i1), Box::new(pruned_i2) ),aff1);
+                        }
+                    } else {
+                        let pruned_i2 = i2.prune(lf_ids);
+                        let mut aff = i1.involved_lifelines();
+                        aff.extend(i2.involved_lifelines());
+                        return (pruned_i2,aff);
+                    }
+                } else {
+                    let pruned_i1 = i1.prune(lf_ids);
+                    let mut aff = i1.involved_lifelines();
+                    aff.extend(i2.involved_lifelines());
+                    return (pruned_i1,aff);
+                }
+            },
             _ => {
                 panic!("non-conform interaction");
             }
@@ -18,6 +18,8 @@ limitations under the License.
 
 use std::fmt::Debug;
 
+use crate::core::language::syntax::value::ValuePattern;
+
 
 
 #[derive(Clone, PartialEq, Debug, Eq, Hash, PartialOrd)]
@@ -37,15 +39,17 @@ pub struct EmissionAction {
     pub origin_lf_id : usize,
     pub ms_id : usize,
     pub synchronicity : CommunicationSynchronicity,
-    pub targets : Vec<EmissionTargetRef> // both lf_ids and gt_ids possible
+    pub targets : Vec<EmissionTargetRef>, // both lf_ids and gt_ids possible
+    pub args : Vec<ValuePattern> // empty if no pattern is specified
 }
 
 impl EmissionAction {
     pub fn new(origin_lf_id : usize,
                ms_id : usize,
                synchronicity : CommunicationSynchronicity,
-               targets : Vec<EmissionTargetRef>) -> EmissionAction {
-        return EmissionAction{origin_lf_id,ms_id,synchronicity,targets}
+               targets : Vec<EmissionTargetRef>,
+               args : Vec<ValuePattern>) -> EmissionAction {
+        return EmissionAction{origin_lf_id,ms_id,synchronicity,targets,args}
     }
 }
 
@@ -54,15 +58,17 @@ pub struct ReceptionAction {
     pub origin_gt_id : Option<usize>,
     pub ms_id : usize,
     pub synchronicity : CommunicationSynchronicity,
-    pub recipients : Vec<usize> // only lf_ids here
+    pub recipients : Vec<usize>, // only lf_ids here
+    pub args : Vec<ValuePattern> // empty if no pattern is specified
 }
 
 impl ReceptionAction {
     pub fn new(origin_gt_id : Option<usize>,
                ms_id : usize,
                synchronicity : CommunicationSynchronicity,
-               recipients : Vec<usize>) -> ReceptionAction {
-        return ReceptionAction{origin_gt_id,ms_id,synchronicity,recipients}
+               recipients : Vec<usize>,
+               args : Vec<ValuePattern>) -> ReceptionAction {
+        return ReceptionAction{origin_gt_id,ms_id,synchronicity,recipients,args}
     }
 }
 
@@ -40,6 +40,10 @@ pub enum Interaction {
     Alt(Box<Interaction>,Box<Interaction>),
     Par(Box<Interaction>,Box<Interaction>),
     Loop(LoopKind,Box<Interaction>),
+    BoundedLoop(LoopKind,u32,u32,Box<Interaction>), // minimum and maximum number of iterations
+    Neg(Box<Interaction>), // forbidden behaviors
+    Assert(Box<Interaction>), // only valid continuations once reached
+    Disrupt(Box<Interaction>,Box<Interaction>), // the right sub-interaction may interrupt the left one at any point
     And(Box<Interaction>,Box<Interaction>),
     Sync(Vec<TraceAction>,Box<Interaction>,Box<Interaction>)
 }
@@ -76,6 +80,22 @@ impl Interaction {
             Interaction::Loop(ref lk, ref i1) => {
                 Interaction::Loop(lk.clone(), Box::new(i1.reverse()))
             },
+            Interaction::BoundedLoop(ref lk, min, max, ref i1) => {
+                Interaction::BoundedLoop(lk.clone(), min, max, Box::new(i1.reverse()))
+            },
+            Interaction::Neg(ref i1) => {
+                Interaction::Neg(Box::new(i1.reverse()))
+            },
+            Interaction::Assert(ref i1) => {
+                Interaction::Assert(Box::new(i1.reverse()))
+            },
+            Interaction::Disrupt(ref i1, ref i2) => {
+                // there is no exact reverse as i1 may be interrupted after any of its prefixes
+                // the first actions of this alternative are all the possible last actions of the disrupt
+                // (those of i1 or those of i2) so that it can be used to look for them
+                // its traces are not the reversed traces of the disrupt
+                Interaction::Alt(Box::new(i1.reverse()),Box::new(i2.reverse()))
+            },
             Interaction::Sync(ref s,ref i1, ref i2) => {
                 Interaction::Sync(s.clone(),Box::new(i2.reverse()),Box::new(i1.reverse()))
             },
@@ -114,11 +134,24 @@ impl Interaction {
             Interaction::Loop(_, _) => {
                 true
             },
+            Interaction::BoundedLoop(_, min, _, ref i1) => {
+                min == 0 || i1.express_empty()
+            },
+            Interaction::Neg(_) => {
+                true
+            },
+            Interaction::Assert(ref i1) => {
+                i1.express_empty()
+            },
+            Interaction::Disrupt(ref i1, ref i2) => {
+                // if the interruption may be empty, the interrupted sub-interaction may stop at any point
+                i1.express_empty() || i2.express_empty()
+            },
             Interaction::Sync(_,ref i1, ref i2) => {
                 i1.express_empty() && i2.express_empty()
             },
-            _ => {
-                panic!("non-conform interaction");
+            Interaction::And(ref i1, ref i2) => {
+                i1.express_empty() && i2.express_empty()
             }
         }
     }
@@ -172,6 +205,22 @@ impl Interaction {
             &Interaction::Loop(_, i1) => {
                 return i1.contained_model_actions();
             },
+            &Interaction::BoundedLoop(_, _, _, i1) => {
+                return i1.contained_model_actions();
+            },
+            &Interaction::Neg(i1) => {
+                return i1.contained_model_actions();
+            },
+            &Interaction::Assert(i1) => {
+                return i1.contained_model_actions();
+            },
+            &Interaction::Disrupt(ref i1, ref i2) => {
+                let (mut em,mut rc) = i1.contained_model_actions();
+                let (mut em2,mut rc2) = i2.contained_model_actions();
+                em.extend(em2);
+                rc.extend(rc2);
+                return (em,rc);
+            },
             &Interaction::Sync(_, ref i1, ref i2) => {
                 let (mut em,mut rc) = i1.contained_model_actions();
                 let (mut em2,mut rc2) = i2.contained_model_actions();
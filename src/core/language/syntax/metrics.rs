@@ -38,7 +38,10 @@ pub enum SymbolKind {
     CoReg,
     Alt,
     Sync,
-    And
+    And,
+    Neg,
+    Assert,
+    Disrupt
 }
 
 
@@ -175,7 +178,7 @@ impl InteractionMetrics {
                 *count += 1;
                 self.extract(i1,depth + 1, loop_depth);
                 self.extract(i2,depth + 1, loop_depth);
-            }, Interaction::Loop(lk, ref i1) => {
+            }, Interaction::Loop(lk, ref i1) | Interaction::BoundedLoop(lk, _, _, ref i1) => {
                 match *lk {
                     LoopKind::SStrictSeq => {
                         let count = self.symbols.get_mut(&SymbolKind::LoopS).unwrap();
@@ -187,6 +190,19 @@ impl InteractionMetrics {
                     }
                 }
                 self.extract(i1,depth + 1, loop_depth + 1);
+            }, Interaction::Neg(ref i1) => {
+                let count = self.symbols.get_mut(&SymbolKind::Neg).unwrap();
+                *count += 1;
+                self.extract(i1,depth + 1, loop_depth);
+            }, Interaction::Assert(ref i1) => {
+                let count = self.symbols.get_mut(&SymbolKind::Assert).unwrap();
+                *count += 1;
+                self.extract(i1,depth + 1, loop_depth);
+            }, Interaction::Disrupt(ref i1, ref i2) => {
+                let count = self.symbols.get_mut(&SymbolKind::Disrupt).unwrap();
+                *count += 1;
+                self.extract(i1,depth + 1, loop_depth);
+                self.extract(i2,depth + 1, loop_depth);
             }
         }
     }
@@ -216,6 +232,14 @@ impl Interaction {
                 return i1.max_nested_loop_depth().max(i2.max_nested_loop_depth());
             }, Interaction::Loop(_, ref i1) => {
                 return 1 + i1.max_nested_loop_depth();
+            }, Interaction::BoundedLoop(_, _, _, ref i1) => {
+                return 1 + i1.max_nested_loop_depth();
+            }, Interaction::Neg(ref i1) => {
+                return i1.max_nested_loop_depth();
+            }, Interaction::Assert(ref i1) => {
+                return i1.max_nested_loop_depth();
+            }, Interaction::Disrupt(ref i1, ref i2) => {
+                return i1.max_nested_loop_depth().max(i2.max_nested_loop_depth());
             }, Interaction::Sync(_, ref i1, ref i2) => {
                 return i1.max_nested_loop_depth().max(i2.max_nested_loop_depth());
             },
@@ -245,6 +269,14 @@ impl Interaction {
                 return i1.total_loop_num() + i2.total_loop_num();
             }, &Interaction::Loop(_, ref i1) => {
                 return 1 + i1.total_loop_num();
+            }, &Interaction::BoundedLoop(_, _, _, ref i1) => {
+                return 1 + i1.total_loop_num();
+            }, &Interaction::Neg(ref i1) => {
+                return i1.total_loop_num();
+            }, &Interaction::Assert(ref i1) => {
+                return i1.total_loop_num();
+            }, &Interaction::Disrupt(ref i1, ref i2) => {
+                return i1.total_loop_num() + i2.total_loop_num();
             }, &Interaction::Sync(_, ref i1, ref i2) => {
                 return i1.total_loop_num() + i2.total_loop_num();
             },
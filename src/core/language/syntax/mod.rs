@@ -17,6 +17,7 @@ limitations under the License.
 
 
 pub mod action;
+pub mod value;
 pub mod interaction;
 pub mod util;
 pub mod metrics;
@@ -122,7 +122,7 @@ impl Interaction {
             Interaction::Alt(ref i1, ref i2) => {
                 return i1.get_characteristics().merge( &i2.get_characteristics() );
             },
-            Interaction::Loop(ref sk, ref i1) => {
+            Interaction::Loop(ref sk, ref i1) | Interaction::BoundedLoop(ref sk, _, _, ref i1) => {
                 let mut charac = i1.get_characteristics();
                 match sk {
                     LoopKind::SStrictSeq => {
@@ -154,6 +154,15 @@ impl Interaction {
                 let mut charac = i1.get_characteristics().merge( &i2.get_characteristics() );
                 charac.has_sync = true;
                 return charac;
+            },
+            Interaction::Neg(ref i1) => {
+                return i1.get_characteristics();
+            },
+            Interaction::Assert(ref i1) => {
+                return i1.get_characteristics();
+            },
+            Interaction::Disrupt(ref i1, ref i2) => {
+                return i1.get_characteristics().merge( &i2.get_characteristics() );
             }
         }
     }
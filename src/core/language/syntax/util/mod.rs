@@ -16,4 +16,6 @@ limitations under the License.
 
 pub mod get_recursive_frag;
 pub mod fold_recursive_frags;
-pub mod check_interaction;
\ No newline at end of file
+pub mod check_interaction;
+pub mod substitute_lifeline;
+pub mod positive_part;
@@ -0,0 +1,68 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::core::language::syntax::interaction::Interaction;
+
+
+impl Interaction {
+
+    // the interaction which specifies the same valid behaviors but no forbidden ones
+    // i.e. in which "neg(i)" is replaced by "∅" and "assert(i)" by "i"
+    pub fn get_positive_part(&self) -> Interaction {
+        match self {
+            Interaction::Empty | Interaction::Emission(_) | Interaction::Reception(_) => {
+                return self.clone();
+            },
+            Interaction::Strict(ref i1, ref i2) => {
+                return Interaction::Strict( Box::new(i1.get_positive_part()), Box::new(i2.get_positive_part()) );
+            },
+            Interaction::Seq(ref i1, ref i2) => {
+                return Interaction::Seq( Box::new(i1.get_positive_part()), Box::new(i2.get_positive_part()) );
+            },
+            Interaction::CoReg(ref cr, ref i1, ref i2) => {
+                return Interaction::CoReg( cr.clone(), Box::new(i1.get_positive_part()), Box::new(i2.get_positive_part()) );
+            },
+            Interaction::Alt(ref i1, ref i2) => {
+                return Interaction::Alt( Box::new(i1.get_positive_part()), Box::new(i2.get_positive_part()) );
+            },
+            Interaction::Par(ref i1, ref i2) => {
+                return Interaction::Par( Box::new(i1.get_positive_part()), Box::new(i2.get_positive_part()) );
+            },
+            Interaction::Loop(ref lk, ref i1) => {
+                return Interaction::Loop( lk.clone(), Box::new(i1.get_positive_part()) );
+            },
+            Interaction::BoundedLoop(ref lk, min, max, ref i1) => {
+                return Interaction::BoundedLoop( lk.clone(), *min, *max, Box::new(i1.get_positive_part()) );
+            },
+            Interaction::Neg(_) => {
+                return Interaction::Empty;
+            },
+            Interaction::Assert(ref i1) => {
+                return i1.get_positive_part();
+            },
+            Interaction::Disrupt(ref i1, ref i2) => {
+                return Interaction::Disrupt( Box::new(i1.get_positive_part()), Box::new(i2.get_positive_part()) );
+            },
+            Interaction::And(ref i1, ref i2) => {
+                return Interaction::And( Box::new(i1.get_positive_part()), Box::new(i2.get_positive_part()) );
+            },
+            Interaction::Sync(ref sync_acts, ref i1, ref i2) => {
+                return Interaction::Sync( sync_acts.clone(), Box::new(i1.get_positive_part()), Box::new(i2.get_positive_part()) );
+            }
+        }
+    }
+
+}
@@ -0,0 +1,149 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::language::syntax::action::{EmissionAction, EmissionTargetRef, ReceptionAction};
+use crate::core::language::syntax::interaction::Interaction;
+
+
+fn substitute_in_lf_ids(lf_ids : &Vec<usize>, old_lf_id : usize, new_lf_id : usize) -> Vec<usize> {
+    let mut substituted : Vec<usize> = Vec::new();
+    for lf_id in lf_ids {
+        let got_lf_id = if *lf_id == old_lf_id { new_lf_id } else { *lf_id };
+        if !substituted.contains(&got_lf_id) {
+            substituted.push(got_lf_id);
+        }
+    }
+    return substituted;
+}
+
+impl Interaction {
+
+    // replaces every occurrence of a lifeline by another one
+    // used to instantiate replicated interactions over the members of a lifeline family
+    pub fn substitute_lifeline(&self, old_lf_id : usize, new_lf_id : usize) -> Interaction {
+        match self {
+            Interaction::Empty => {
+                return Interaction::Empty;
+            },
+            Interaction::Emission(ref em_act) => {
+                let origin_lf_id = if em_act.origin_lf_id == old_lf_id { new_lf_id } else { em_act.origin_lf_id };
+                let mut targets : Vec<EmissionTargetRef> = Vec::new();
+                for tar_ref in &em_act.targets {
+                    let got_tar_ref = match tar_ref {
+                        EmissionTargetRef::Lifeline(tar_lf_id) if *tar_lf_id == old_lf_id => {
+                            EmissionTargetRef::Lifeline(new_lf_id)
+                        },
+                        _ => {
+                            tar_ref.clone()
+                        }
+                    };
+                    if !targets.contains(&got_tar_ref) {
+                        targets.push(got_tar_ref);
+                    }
+                }
+                return Interaction::Emission( EmissionAction::new(origin_lf_id,
+                                                                  em_act.ms_id,
+                                                                  em_act.synchronicity.clone(),
+                                                                  targets,
+                                                                  em_act.args.clone()) );
+            },
+            Interaction::Reception(ref rc_act) => {
+                return Interaction::Reception( ReceptionAction::new(rc_act.origin_gt_id,
+                                                                    rc_act.ms_id,
+                                                                    rc_act.synchronicity.clone(),
+                                                                    substitute_in_lf_ids(&rc_act.recipients,old_lf_id,new_lf_id),
+                                                                    rc_act.args.clone()) );
+            },
+            Interaction::Strict(ref i1, ref i2) => {
+                return Interaction::Strict( Box::new(i1.substitute_lifeline(old_lf_id,new_lf_id)),
+                                            Box::new(i2.substitute_lifeline(old_lf_id,new_lf_id)) );
+            },
+            Interaction::Seq(ref i1, ref i2) => {
+                return Interaction::Seq( Box::new(i1.substitute_lifeline(old_lf_id,new_lf_id)),
+                                         Box::new(i2.substitute_lifeline(old_lf_id,new_lf_id)) );
+            },
+            Interaction::CoReg(ref cr, ref i1, ref i2) => {
+                return Interaction::CoReg( substitute_in_lf_ids(cr,old_lf_id,new_lf_id),
+                                           Box::new(i1.substitute_lifeline(old_lf_id,new_lf_id)),
+                                           Box::new(i2.substitute_lifeline(old_lf_id,new_lf_id)) );
+            },
+            Interaction::Alt(ref i1, ref i2) => {
+                return Interaction::Alt( Box::new(i1.substitute_lifeline(old_lf_id,new_lf_id)),
+                                         Box::new(i2.substitute_lifeline(old_lf_id,new_lf_id)) );
+            },
+            Interaction::Par(ref i1, ref i2) => {
+                return Interaction::Par( Box::new(i1.substitute_lifeline(old_lf_id,new_lf_id)),
+                                         Box::new(i2.substitute_lifeline(old_lf_id,new_lf_id)) );
+            },
+            Interaction::Loop(ref lk, ref i1) => {
+                return Interaction::Loop( lk.clone(), Box::new(i1.substitute_lifeline(old_lf_id,new_lf_id)) );
+            },
+            Interaction::BoundedLoop(ref lk, min, max, ref i1) => {
+                return Interaction::BoundedLoop( lk.clone(), *min, *max, Box::new(i1.substitute_lifeline(old_lf_id,new_lf_id)) );
+            },
+            Interaction::Neg(ref i1) => {
+                return Interaction::Neg( Box::new(i1.substitute_lifeline(old_lf_id,new_lf_id)) );
+            },
+            Interaction::Assert(ref i1) => {
+                return Interaction::Assert( Box::new(i1.substitute_lifeline(old_lf_id,new_lf_id)) );
+            },
+            Interaction::Disrupt(ref i1, ref i2) => {
+                return Interaction::Disrupt( Box::new(i1.substitute_lifeline(old_lf_id,new_lf_id)),
+                                             Box::new(i2.substitute_lifeline(old_lf_id,new_lf_id)) );
+            },
+            Interaction::And(ref i1, ref i2) => {
+                return Interaction::And( Box::new(i1.substitute_lifeline(old_lf_id,new_lf_id)),
+                                         Box::new(i2.substitute_lifeline(old_lf_id,new_lf_id)) );
+            },
+            Interaction::Sync(ref sync_acts, ref i1, ref i2) => {
+                let new_sync_acts : Vec<TraceAction> = sync_acts.iter().map(|act| {
+                    let mut new_act = act.clone();
+                    if new_act.lf_id == old_lf_id {
+                        new_act.lf_id = new_lf_id;
+                    }
+                    new_act
+                }).collect();
+                return Interaction::Sync( new_sync_acts,
+                                          Box::new(i1.substitute_lifeline(old_lf_id,new_lf_id)),
+                                          Box::new(i2.substitute_lifeline(old_lf_id,new_lf_id)) );
+            }
+        }
+    }
+
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::syntax::action::CommunicationSynchronicity;
+    use super::*;
+
+    #[test]
+    fn substituted_lifelines_are_not_duplicated() {
+        // a -- m1 -> (b,c) where "c" is substituted by "b"
+        let int = Interaction::Emission(EmissionAction::new(0,0,CommunicationSynchronicity::Asynchronous,
+                                                            vec![EmissionTargetRef::Lifeline(1),EmissionTargetRef::Lifeline(2)],vec![]));
+        let expected = Interaction::Emission(EmissionAction::new(0,0,CommunicationSynchronicity::Asynchronous,
+                                                                 vec![EmissionTargetRef::Lifeline(1)],vec![]));
+        assert_eq!(int.substitute_lifeline(2,1), expected);
+        // coreg over (a,c) where "c" is substituted by "a"
+        let int = Interaction::CoReg(vec![0,2],Box::new(Interaction::Empty),Box::new(Interaction::Empty));
+        assert_eq!(int.substitute_lifeline(2,0), Interaction::CoReg(vec![0],Box::new(Interaction::Empty),Box::new(Interaction::Empty)));
+    }
+}
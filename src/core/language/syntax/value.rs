@@ -0,0 +1,156 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fmt;
+
+
+// types of the arguments that may be declared for messages in the signature
+#[derive(Clone, PartialEq, Debug, Eq, Hash, PartialOrd, Ord)]
+pub enum ArgumentType {
+    Integer,
+    String,
+    Boolean
+}
+
+impl fmt::Display for ArgumentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArgumentType::Integer => {
+                write!(f,"int")
+            },
+            ArgumentType::String => {
+                write!(f,"str")
+            },
+            ArgumentType::Boolean => {
+                write!(f,"bool")
+            }
+        }
+    }
+}
+
+// arguments carried by actions
+// actions of multi-traces carry concrete values
+// while actions of interactions may also carry wildcards
+#[derive(Clone, PartialEq, Debug, Eq, Hash, PartialOrd, Ord)]
+pub enum ValuePattern {
+    Any,
+    Integer(i64),
+    String(String),
+    Boolean(bool)
+}
+
+impl ValuePattern {
+
+    pub fn get_type(&self) -> Option<ArgumentType> {
+        match self {
+            ValuePattern::Any => {
+                return None;
+            },
+            ValuePattern::Integer(_) => {
+                return Some(ArgumentType::Integer);
+            },
+            ValuePattern::String(_) => {
+                return Some(ArgumentType::String);
+            },
+            ValuePattern::Boolean(_) => {
+                return Some(ArgumentType::Boolean);
+            }
+        }
+    }
+
+    pub fn is_of_type(&self, arg_type : &ArgumentType) -> bool {
+        match self.get_type() {
+            None => {
+                return true;
+            },
+            Some( got_type ) => {
+                return got_type == *arg_type;
+            }
+        }
+    }
+
+    pub fn matches(&self, value : &ValuePattern) -> bool {
+        match (self,value) {
+            (ValuePattern::Any,_) => {
+                return true;
+            },
+            (_,ValuePattern::Any) => {
+                return true;
+            },
+            _ => {
+                return self == value;
+            }
+        }
+    }
+
+}
+
+// an empty list of patterns stands for any values
+pub fn arguments_match(patterns : &Vec<ValuePattern>, values : &Vec<ValuePattern>) -> bool {
+    if patterns.is_empty() || values.is_empty() {
+        return true;
+    }
+    if patterns.len() != values.len() {
+        return false;
+    }
+    return patterns.iter().zip(values.iter()).all(|(p,v)| p.matches(v));
+}
+
+impl fmt::Display for ValuePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValuePattern::Any => {
+                write!(f,"_")
+            },
+            ValuePattern::Integer(got_int) => {
+                write!(f,"{}",got_int)
+            },
+            ValuePattern::String(got_str) => {
+                write!(f,"\"{}\"",got_str)
+            },
+            ValuePattern::Boolean(got_bool) => {
+                write!(f,"{}",got_bool)
+            }
+        }
+    }
+}
+
+// textual representation of the arguments that follows the message name
+pub fn arguments_as_text(args : &Vec<ValuePattern>) -> String {
+    if args.is_empty() {
+        return "".to_string();
+    }
+    let args_strs : Vec<String> = args.iter().map(|a| a.to_string()).collect();
+    return format!("({})", args_strs.join(","));
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wildcards_match_values_of_any_type() {
+        let patterns = vec![ValuePattern::Integer(200),ValuePattern::Any];
+        assert!(arguments_match(&patterns,&vec![ValuePattern::Integer(200),ValuePattern::String("ok".to_string())]));
+        assert!(arguments_match(&patterns,&vec![ValuePattern::Integer(200),ValuePattern::Boolean(false)]));
+        assert!(!arguments_match(&patterns,&vec![ValuePattern::Integer(404),ValuePattern::String("ok".to_string())]));
+        assert!(!arguments_match(&patterns,&vec![ValuePattern::Integer(200)]));
+        // actions without arguments match any values
+        assert!(arguments_match(&patterns,&vec![]));
+    }
+}
@@ -28,12 +28,12 @@ impl AtomicUnfoldableAsInteraction for EmissionAction {
         if self.targets.len() == 0 {
             return Interaction::Emission(self.clone());
         } else {
-            let emission = EmissionAction::new(self.origin_lf_id,self.ms_id,CommunicationSynchronicity::Asynchronous,vec![]);
+            let emission = EmissionAction::new(self.origin_lf_id,self.ms_id,CommunicationSynchronicity::Asynchronous,vec![],self.args.clone());
             let mut receptions = vec![];
             for target_ref in &self.targets {
                 match target_ref {
                     EmissionTargetRef::Lifeline(tar_lf_id) => {
-                        receptions.push( ReceptionAction::new(None,self.ms_id,CommunicationSynchronicity::Asynchronous,vec![*tar_lf_id]));
+                        receptions.push( ReceptionAction::new(None,self.ms_id,CommunicationSynchronicity::Asynchronous,vec![*tar_lf_id],self.args.clone()));
                     },
                     _ => {
                         // nothing
@@ -60,7 +60,7 @@ impl AtomicUnfoldableAsInteraction for ReceptionAction {
             _ => {
                 let mut receptions = vec![];
                 for rcp_lf_id in &self.recipients {
-                    receptions.push( ReceptionAction::new(None,self.ms_id,CommunicationSynchronicity::Asynchronous,vec![*rcp_lf_id]));
+                    receptions.push( ReceptionAction::new(None,self.ms_id,CommunicationSynchronicity::Asynchronous,vec![*rcp_lf_id],self.args.clone()));
                 }
                 return deploy_receptions(&mut receptions);
             }
@@ -105,6 +105,40 @@ pub fn get_all_transformations_rec(transfos : &Vec<InteractionTransformationKind
                                                                          Interaction::Loop(lk.clone(), Box::new(left_transfo.result))
                 ) );
             }
+        }, &Interaction::BoundedLoop(ref lk, min, max, ref i1) => {
+            for left_transfo in get_all_transformations_rec(transfos,i1) {
+                results.push( InteractionTransformationResult::new(left_transfo.kind,
+                                                                         Position::Left(Box::new(left_transfo.position)),
+                                                                         Interaction::BoundedLoop(lk.clone(), min, max, Box::new(left_transfo.result))
+                ) );
+            }
+        }, &Interaction::Neg(ref i1) => {
+            for left_transfo in get_all_transformations_rec(transfos,i1) {
+                results.push( InteractionTransformationResult::new(left_transfo.kind,
+                                                                         Position::Left(Box::new(left_transfo.position)),
+                                                                         Interaction::Neg(Box::new(left_transfo.result))
+                ) );
+            }
+        }, &Interaction::Assert(ref i1) => {
+            for left_transfo in get_all_transformations_rec(transfos,i1) {
+                results.push( InteractionTransformationResult::new(left_transfo.kind,
+                                                                         Position::Left(Box::new(left_transfo.position)),
+                                                                         Interaction::Assert(Box::new(left_transfo.result))
+                ) );
+            }
+        }, &Interaction::Disrupt(ref i1, ref i2) => {
+            for left_transfo in get_all_transformations_rec(transfos,i1) {
+                results.push( InteractionTransformationResult::new(left_transfo.kind,
+                                                                         Position::Left(Box::new(left_transfo.position)),
+                                                                         Interaction::Disrupt(Box::new(left_transfo.result),i2.clone())
+                ) );
+            }
+            for right_transfo in get_all_transformations_rec(transfos,i2) {
+                results.push( InteractionTransformationResult::new(right_transfo.kind,
+                                                                         Position::Right(Box::new(right_transfo.position)),
+                                                                         Interaction::Disrupt(i1.clone(), Box::new(right_transfo.result))
+                ) );
+            }
         }, &Interaction::Sync(ref sync_acts, ref i1, ref i2) => {
             for left_transfo in get_all_transformations_rec(transfos,i1) {
                 results.push( InteractionTransformationResult::new(left_transfo.kind,
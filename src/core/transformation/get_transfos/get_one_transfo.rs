@@ -131,6 +131,50 @@ pub fn get_one_transformation_rec(transfos : &Vec<InteractionTransformationKind>
                         },
                         None => {}
                     }
+                }, &Interaction::BoundedLoop(ref lk, min, max, ref i1) => {
+                    match get_one_transformation_rec(transfos,i1) {
+                        Some(sub_transfo) => {
+                            return Some( InteractionTransformationResult::new(sub_transfo.kind,
+                                                                            Position::Left(Box::new(sub_transfo.position)),
+                                                                            Interaction::BoundedLoop(lk.clone(), min, max, Box::new(sub_transfo.result))) );
+                        },
+                        None => {}
+                    }
+                }, &Interaction::Neg(ref i1) => {
+                    match get_one_transformation_rec(transfos,i1) {
+                        Some(sub_transfo) => {
+                            return Some( InteractionTransformationResult::new(sub_transfo.kind,
+                                                                            Position::Left(Box::new(sub_transfo.position)),
+                                                                            Interaction::Neg(Box::new(sub_transfo.result))) );
+                        },
+                        None => {}
+                    }
+                }, &Interaction::Assert(ref i1) => {
+                    match get_one_transformation_rec(transfos,i1) {
+                        Some(sub_transfo) => {
+                            return Some( InteractionTransformationResult::new(sub_transfo.kind,
+                                                                            Position::Left(Box::new(sub_transfo.position)),
+                                                                            Interaction::Assert(Box::new(sub_transfo.result))) );
+                        },
+                        None => {}
+                    }
+                }, &Interaction::Disrupt(ref i1, ref i2) => {
+                    match get_one_transformation_rec(transfos,i1) {
+                        Some(left_transfo) => {
+                            return Some( InteractionTransformationResult::new(left_transfo.kind,
+                                                                            Position::Left(Box::new(left_transfo.position)),
+                                                                            Interaction::Disrupt(Box::new(left_transfo.result),i2.clone())) );
+                        },
+                        None => {}
+                    }
+                    match get_one_transformation_rec(transfos,i2) {
+                        Some(right_transfo) => {
+                            return Some( InteractionTransformationResult::new(right_transfo.kind,
+                                                                            Position::Right(Box::new(right_transfo.position)),
+                                                                            Interaction::Disrupt(i1.clone(), Box::new(right_transfo.result))) );
+                        },
+                        None => {}
+                    }
                 }, &Interaction::Sync(ref sync_acts, ref i1, ref i2) => {
                     match get_one_transformation_rec(transfos,i1) {
                         Some(left_transfo) => {
@@ -28,7 +28,7 @@ pub fn transfo_sort_action_content(interaction : &Interaction) -> Vec<Interactio
             let mut new_targets = em_act.targets.clone();
             new_targets.sort();
             if new_targets != em_act.targets {
-                let new_emission = EmissionAction::new(em_act.origin_lf_id,em_act.ms_id,em_act.synchronicity.clone(),new_targets);
+                let new_emission = EmissionAction::new(em_act.origin_lf_id,em_act.ms_id,em_act.synchronicity.clone(),new_targets,em_act.args.clone());
                 return vec![Interaction::Emission(new_emission)];
             }
         },
@@ -36,7 +36,7 @@ pub fn transfo_sort_action_content(interaction : &Interaction) -> Vec<Interactio
             let mut new_targets = rc_act.recipients.clone();
             new_targets.sort();
             if new_targets != rc_act.recipients {
-                let new_reception = ReceptionAction::new(rc_act.origin_gt_id.clone(),rc_act.ms_id,rc_act.synchronicity.clone(),new_targets);
+                let new_reception = ReceptionAction::new(rc_act.origin_gt_id.clone(),rc_act.ms_id,rc_act.synchronicity.clone(),new_targets,rc_act.args.clone());
                 return vec![Interaction::Reception(new_reception)];
             }
         },
@@ -64,7 +64,8 @@ pub fn transfo_unfold_action(interaction : &Interaction) -> Vec<Interaction> {
                                         None,
                                         em_act.ms_id,
                                         CommunicationSynchronicity::Asynchronous,
-                                        vec![lf_id])
+                                        vec![lf_id],
+                                        em_act.args.clone())
                                 )
                             );
                         },
@@ -78,7 +79,7 @@ pub fn transfo_unfold_action(interaction : &Interaction) -> Vec<Interaction> {
                     return vec![];
                 }
                 let new_em = Interaction::Emission(
-                    EmissionAction::new(em_act.origin_lf_id,em_act.ms_id,CommunicationSynchronicity::Asynchronous,gates_targets)
+                    EmissionAction::new(em_act.origin_lf_id,em_act.ms_id,CommunicationSynchronicity::Asynchronous,gates_targets,em_act.args.clone())
                 );
                 let receptions = fold_recursive_par_frags(&mut targets_as_ints.iter().collect());
                 let new_int = Interaction::Strict(
@@ -106,7 +107,8 @@ pub fn transfo_unfold_action(interaction : &Interaction) -> Vec<Interaction> {
                                     rc_act.origin_gt_id.clone(),
                                     rc_act.ms_id,
                                     CommunicationSynchronicity::Asynchronous,
-                                    vec![*rec_lf_id]
+                                    vec![*rec_lf_id],
+                                    rc_act.args.clone()
                                 )
                             )
                         );
@@ -28,6 +28,11 @@ pub fn transfo_loop_empty_simpl(interaction : &Interaction) -> Vec<Interaction>
                 _ => {}
             }
         },
+        &Interaction::BoundedLoop(_, _, max, ref i1) => {
+            if max == 0 || **i1 == Interaction::Empty {
+                return vec![Interaction::Empty];
+            }
+        },
         _ => {}
     }
     return vec![];
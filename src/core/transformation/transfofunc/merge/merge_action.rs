@@ -49,6 +49,10 @@ fn merge_action_inner(emission : &EmissionAction,
         return None;
     }
     // ***
+    if emission.args != reception.args {
+        return None;
+    }
+    // ***
     match &reception.origin_gt_id {
         None => {
             return None;
@@ -75,7 +79,7 @@ fn merge_action_inner(emission : &EmissionAction,
             }
             // ***
             if is_match {
-                let new_action = EmissionAction::new(emission.origin_lf_id,emission.ms_id,emission.synchronicity.clone(),targets);
+                let new_action = EmissionAction::new(emission.origin_lf_id,emission.ms_id,emission.synchronicity.clone(),targets,emission.args.clone());
                 return Some(Interaction::Emission(new_action));
             } else {
                 return None;
@@ -23,6 +23,8 @@ use crate::core::language::syntax::interaction::Interaction;
 pub fn transfo_strict_to_seq(interaction : &Interaction) -> Vec<Interaction> {
     match interaction {
         &Interaction::Strict(ref i1, ref i2) => {
+            // the reverse of a disrupt only gives an over-approximation of its last actions
+            // which is enough to check that they all occur on the same lifeline
             let last_actions_of_i1 = global_frontier(&i1.reverse(),true);
             let last_locations_on_i1 : HashSet<usize> = last_actions_of_i1.iter()
                 .fold( HashSet::new(),|mut p, x| {p.extend(x.target_lf_ids.clone()); p});
@@ -38,4 +40,26 @@ pub fn transfo_strict_to_seq(interaction : &Interaction) -> Vec<Interaction> {
         _ => {}
     }
     return vec![];
-}
\ No newline at end of file
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test_fixtures::message;
+    use super::*;
+
+    #[test]
+    fn last_actions_of_a_disrupt_include_those_of_its_interruption() {
+        // lifelines "a" (0) and "b" (1), messages "m1" (0) to "m4" (3)
+        let interrupted = Box::new(Interaction::Strict(Box::new(message(0,0,1)),Box::new(message(1,1,0))));
+        // strict(disrupt(strict(a -- m1 -> b, b -- m2 -> a), b -- m3 -> a), b -- m4 -> a)
+        let i1 = Box::new(Interaction::Disrupt(interrupted.clone(),Box::new(message(1,2,0))));
+        let int = Interaction::Strict(i1.clone(),Box::new(message(1,3,0)));
+        assert_eq!(transfo_strict_to_seq(&int), vec![Interaction::Seq(i1,Box::new(message(1,3,0)))]);
+        // strict(disrupt(strict(a -- m1 -> b, b -- m2 -> a), a -- m3 -> b), b -- m4 -> a)
+        // may end with "a!m3" so that it cannot be turned into a seq
+        let i1 = Box::new(Interaction::Disrupt(interrupted,Box::new(message(0,2,1))));
+        let int = Interaction::Strict(i1,Box::new(message(1,3,0)));
+        assert!(transfo_strict_to_seq(&int).is_empty());
+    }
+}
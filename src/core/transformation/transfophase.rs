@@ -60,6 +60,15 @@ impl InteractionTransformationPhase {
         }
     }
 
+    // applies transformations of the phase one at a time until none is applicable
+    pub fn apply_exhaustively(&self, interaction : &Interaction) -> Interaction {
+        let mut current = interaction.clone();
+        while let Some(got) = get_one_transformation_rec(&self.transfos,&current) {
+            current = got.result;
+        }
+        return current;
+    }
+
     /*pub fn apply_phase(&self,interaction : &Interaction) -> Vec<InteractionTransformationResult> {
         if self.ordered {
             panic!("not implemented");
@@ -87,3 +96,22 @@ impl InteractionTransformationPhase {
 }
 
 
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, ReceptionAction};
+    use crate::util::test_fixtures::message;
+    use super::*;
+
+    #[test]
+    fn projections_are_simplified() {
+        // seq(a -- m1 -> b, c -- m2 -> c) projected onto "b"
+        let int = Interaction::Seq(Box::new(message(0,0,1)),Box::new(message(2,1,2)));
+        let simpl_phase = InteractionTransformationPhase::new(vec![InteractionTransformationKind::Simpl,
+                                                                   InteractionTransformationKind::FlushRight]);
+        let projected = simpl_phase.apply_exhaustively(&int.eliminate_lifelines(&btreeset!{0,2}));
+        let expected = Interaction::Reception(ReceptionAction::new(None,0,CommunicationSynchronicity::Asynchronous,vec![1],vec![]));
+        assert_eq!(projected, expected);
+    }
+}
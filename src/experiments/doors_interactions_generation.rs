@@ -28,15 +28,18 @@ fn basic_door_on_lifeline(num_possible_letters : u32,
     let recA = Interaction::Reception(ReceptionAction::new(None,
                                                            0,
                                                            CommunicationSynchronicity::Asynchronous,
-                                                           vec![0]));
+                                                           vec![0],
+                                                           vec![]));
     let recB = Interaction::Reception(ReceptionAction::new(None,
                                                            1,
                                                            CommunicationSynchronicity::Asynchronous,
-                                                           vec![0]));
+                                                           vec![0],
+                                                           vec![]));
     let recC = Interaction::Reception(ReceptionAction::new(None,
                                                            2,
                                                            CommunicationSynchronicity::Asynchronous,
-                                                           vec![0]));
+                                                           vec![0],
+                                                           vec![]));
     let altfrag = match num_possible_letters {
         2 => {
             Interaction::Alt(
@@ -39,7 +39,8 @@ pub fn generate_random_action(signature : &GeneralContext,rng : &mut StdRng) ->
         let emission = EmissionAction::new(
             *lifelines.get(0).unwrap(),
             ms_id,
-            CommunicationSynchronicity::Asynchronous,vec![]
+            CommunicationSynchronicity::Asynchronous,vec![],
+            vec![]
         );
         Interaction::Emission(emission)
     } else {
@@ -47,7 +48,8 @@ pub fn generate_random_action(signature : &GeneralContext,rng : &mut StdRng) ->
             None,
             ms_id,
             CommunicationSynchronicity::Asynchronous,
-            vec![*lifelines.get(0).unwrap()]
+            vec![*lifelines.get(0).unwrap()],
+            vec![]
         );
         Interaction::Reception(reception)
     }
@@ -71,7 +73,8 @@ pub fn generate_random_pattern(signature : &GeneralContext,
         orig_lf_id,
         ms_id,
         CommunicationSynchronicity::Asynchronous,
-        targets
+        targets,
+        vec![]
     );
     Interaction::Emission(emission)
 }
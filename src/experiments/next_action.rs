@@ -36,6 +36,7 @@ impl NextActionSpec {
             self.next_lf,
             self.next_ms,
             CommunicationSynchronicity::Asynchronous,
+            vec![],
             vec![]
         );
         let int = Interaction::Emission(act);
@@ -25,4 +25,6 @@ pub static HIBOU_CONFIGURATION_FILE_EXTENSION : &'static str = "hcf";
 
 pub static HIBOU_INTERACTION_FILE_EXTENSION : &'static str = "hif";
 
+pub static HIBOU_JSON_FILE_EXTENSION : &'static str = "json";
+
 
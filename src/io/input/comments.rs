@@ -0,0 +1,109 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use pest::iterators::Pair;
+use pest::RuleType;
+
+
+// returns the comments "/* ... */" which occur in a piece of text
+// the text must not contain string literals
+pub fn extract_comments(text : &str) -> Vec<String> {
+    let mut comments = vec![];
+    let mut remaining = text;
+    while let Some(start) = remaining.find("/*") {
+        match remaining[start+2..].find("*/") {
+            None => {
+                break;
+            },
+            Some(end) => {
+                let end = start + 2 + end + 2;
+                comments.push( remaining[start..end].to_string() );
+                remaining = &remaining[end..];
+            }
+        }
+    }
+    return comments;
+}
+
+
+// used by the formatters to retrieve the comments which are discarded by the parsers
+// the cursor only moves forward so that each comment is retrieved once
+pub struct CommentCursor<'a> {
+    source : &'a str,
+    keep_comments : bool,
+    position : usize
+}
+
+impl<'a> CommentCursor<'a> {
+
+    pub fn new(source : &'a str, keep_comments : bool) -> CommentCursor<'a> {
+        return CommentCursor{source,keep_comments,position:0};
+    }
+
+    // comments between the current position and a given offset
+    pub fn take_until(&mut self, offset : usize) -> Vec<String> {
+        if offset <= self.position {
+            return vec![];
+        }
+        let comments = if self.keep_comments {
+            extract_comments(&self.source[self.position..offset])
+        } else {
+            vec![]
+        };
+        self.position = offset;
+        return comments;
+    }
+
+    // comments before a pair and within it, outside of its tokens (which may contain string literals)
+    pub fn take_through<R : RuleType>(&mut self, pair : &Pair<R>) -> Vec<String> {
+        let span = pair.as_span();
+        let mut comments = self.take_until(span.start());
+        for leaf in pair.clone().into_inner().flatten().filter(|p| p.clone().into_inner().next().is_none()) {
+            comments.extend( self.take_until(leaf.as_span().start()) );
+            self.position = self.position.max(leaf.as_span().end());
+        }
+        // the span of a pair may end with skipped whitespaces and comments which rather precede the next element
+        let end = span.start() + trimmed_length(span.as_str());
+        comments.extend( self.take_until(end) );
+        return comments;
+    }
+
+}
+
+// length of a piece of text without its trailing whitespaces and comments
+fn trimmed_length(text : &str) -> usize {
+    let mut trimmed = text.trim_end();
+    while trimmed.ends_with("*/") {
+        match trimmed.rfind("/*") {
+            None => {
+                break;
+            },
+            Some(start) => {
+                trimmed = trimmed[..start].trim_end();
+            }
+        }
+    }
+    return trimmed.len();
+}
+
+// comments placed before an element on the same line
+pub fn comments_as_inline_prefix(comments : &Vec<String>) -> String {
+    if comments.is_empty() {
+        return "".to_string();
+    }
+    return format!("{} ", comments.join(" "));
+}
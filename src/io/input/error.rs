@@ -17,11 +17,17 @@ limitations under the License.
 
 use std::fmt;
 
+use pest::error::InputLocation;
+use pest::{RuleType, Span};
+
+use crate::io::input::location::SourceLocation;
+
 #[derive(Debug)]
 pub enum HibouParsingError {
     FileFormatError(String,String),
     FileError(String),
     MatchError(String),
+    JsonError(String),
     // ***
     HsfSetupError(String),
     HcfSetupError(String),
@@ -32,12 +38,96 @@ pub enum HibouParsingError {
     MissingLifelineDeclarationError(String),
     MissingGateDeclarationError(String),
     MissingLifelineOrGateDeclarationError(String),
+    MissingLifelineFamilyDeclarationError(String),
+    MissingInteractionDefinitionError(String),
+    CyclicInteractionDefinitionError(String),
     // ***
     EmissionDefinitionError(String),
+    MessageArgumentError(String),
     OtherDefinitionError(String),
     // ***
     NonDisjointTraceComponents,
-    IllDefinedTraceComponents(String)
+    IllDefinedTraceComponents(String),
+    // ***
+    Located(Box<HibouParsingError>,SourceLocation),
+    Multiple(Vec<HibouParsingError>)
+}
+
+impl HibouParsingError {
+
+    // attaches the position of the parsed element to an error
+    // if it has not already been located more precisely
+    pub fn locate(self, span : &Span) -> HibouParsingError {
+        return self.located_at(SourceLocation::from_span(span));
+    }
+
+    pub fn located_at(self, location : SourceLocation) -> HibouParsingError {
+        match self {
+            HibouParsingError::Located(_,_) | HibouParsingError::Multiple(_) => {
+                return self;
+            },
+            HibouParsingError::FileFormatError(_,_) | HibouParsingError::FileError(_) => {
+                return self;
+            },
+            _ => {
+                return HibouParsingError::Located(Box::new(self), location);
+            }
+        }
+    }
+
+    pub fn with_file_path(self, file_path : &str) -> HibouParsingError {
+        match self {
+            HibouParsingError::Located(err, mut loc) => {
+                if loc.file_path.is_none() {
+                    loc.file_path = Some(file_path.to_string());
+                }
+                return HibouParsingError::Located(err, loc);
+            },
+            HibouParsingError::Multiple(errs) => {
+                return HibouParsingError::Multiple(errs.into_iter().map(|e| e.with_file_path(file_path)).collect());
+            },
+            _ => {
+                return self;
+            }
+        }
+    }
+
+    pub fn from_errors(errors : Vec<HibouParsingError>) -> HibouParsingError {
+        let mut flattened = vec![];
+        for err in errors {
+            match err {
+                HibouParsingError::Multiple(sub_errs) => {
+                    flattened.extend(sub_errs);
+                },
+                _ => {
+                    flattened.push(err);
+                }
+            }
+        }
+        if flattened.len() == 1 {
+            return flattened.remove(0);
+        }
+        return HibouParsingError::Multiple(flattened);
+    }
+
+    // returns the offset and length of the location of a pest error in its input
+    pub fn pest_error_span<R : RuleType>(error : &pest::error::Error<R>) -> (usize,usize) {
+        match error.location {
+            InputLocation::Pos(pos) => {
+                return (pos,1);
+            },
+            InputLocation::Span((start,end)) => {
+                return (start,end - start);
+            }
+        }
+    }
+
+    pub fn from_pest_error<R : RuleType>(error : pest::error::Error<R>, source : &str) -> HibouParsingError {
+        let (offset,len) = HibouParsingError::pest_error_span(&error);
+        let message = error.variant.message().to_string();
+        return HibouParsingError::MatchError(message).located_at(SourceLocation::new(source, offset, len));
+    }
+
 }
 
 impl fmt::Display for HibouParsingError {
@@ -50,7 +140,10 @@ impl fmt::Display for HibouParsingError {
                 return write!(f, "{}", format!("error while reading SD conf file : {:}", sub_e));
             },
             HibouParsingError::MatchError(sub_e) => {
-                return write!(f, "{}", format!("error while parsing SD string : {:}", sub_e));
+                return write!(f, "{}", format!("syntax error : {:}", sub_e));
+            },
+            HibouParsingError::JsonError(sub_e) => {
+                return write!(f, "{}", format!("JSON error : {:}", sub_e));
             },
             // ***
             HibouParsingError::HsfSetupError(sub_e) => {
@@ -78,10 +171,22 @@ impl fmt::Display for HibouParsingError {
             HibouParsingError::MissingLifelineOrGateDeclarationError(sub_e) => {
                 return write!(f, "{}", format!("error while parsing ; missing lifeline or gate declaration : {:}", sub_e));
             },
+            HibouParsingError::MissingLifelineFamilyDeclarationError(sub_e) => {
+                return write!(f, "{}", format!("error while parsing ; missing lifeline family declaration : {:}", sub_e));
+            },
+            HibouParsingError::MissingInteractionDefinitionError(sub_e) => {
+                return write!(f, "{}", format!("error while parsing ; missing definition for referenced interaction : {:}", sub_e));
+            },
+            HibouParsingError::CyclicInteractionDefinitionError(sub_e) => {
+                return write!(f, "{}", format!("error while parsing ; cyclic interaction references : {:}", sub_e));
+            },
             // ***
             HibouParsingError::EmissionDefinitionError(sub_e) => {
                 return write!(f, "{}", format!("error while parsing ; emission definition error : {:}", sub_e));
             },
+            HibouParsingError::MessageArgumentError(sub_e) => {
+                return write!(f, "{}", format!("error while parsing ; message argument error : {:}", sub_e));
+            },
             HibouParsingError::OtherDefinitionError(sub_e) => {
                 return write!(f, "{}", format!("error while parsing ; other definition error : {:}", sub_e));
             },
@@ -91,6 +196,14 @@ impl fmt::Display for HibouParsingError {
             },
             HibouParsingError::IllDefinedTraceComponents(sub_e) => {
                 return write!(f, "{}", format!("error while parsing ; ill defined trace canals : {:}", sub_e));
+            },
+            // ***
+            HibouParsingError::Located(sub_e, location) => {
+                return write!(f, "{}\n{}", sub_e, location);
+            },
+            HibouParsingError::Multiple(sub_errs) => {
+                let errs_strs : Vec<String> = sub_errs.iter().map(|e| e.to_string()).collect();
+                return write!(f, "{}", errs_strs.join("\n\n"));
             }
         }
     }
@@ -44,7 +44,14 @@ pub fn parse_hcf_file_for_ana(gen_ctx : &GeneralContext,
     let file_name : &str = path_object.file_stem().unwrap().to_str().unwrap();
     match fs::read_to_string(file_path) {
         Ok( unparsed_hcf_str ) => {
-            return parse_hcf_string_for_ana(gen_ctx,unparsed_hcf_str, file_name);
+            match parse_hcf_string_for_ana(gen_ctx,unparsed_hcf_str, file_name) {
+                Err(e) => {
+                    return Err( e.with_file_path(file_path) );
+                },
+                Ok( ana_options ) => {
+                    return Ok( ana_options );
+                }
+            }
         },
         Err(e) => {
             return Err( HibouParsingError::FileError(e.to_string()) );
@@ -52,6 +59,22 @@ pub fn parse_hcf_file_for_ana(gen_ctx : &GeneralContext,
     }
 }
 
+// loggers are disabled so that repeated analyses do not overwrite their outputs
+// options for repeated analyses are then obtained with "clone_without_loggers"
+pub fn get_analysis_options_without_loggers(gen_ctx : &GeneralContext,
+                                            hcf_file_path : Option<&str>) -> Result<HibouAnalyzeOptions,HibouParsingError> {
+    let mut ana_opts = match hcf_file_path {
+        None => {
+            HibouAnalyzeOptions::default()
+        },
+        Some( got_path ) => {
+            parse_hcf_file_for_ana(gen_ctx,got_path)?
+        }
+    };
+    ana_opts.loggers = vec![];
+    return Ok(ana_opts);
+}
+
 
 fn parse_hcf_string_for_ana(gen_ctx : &GeneralContext,
                                 hcf_string : String,
@@ -69,7 +92,7 @@ fn parse_hcf_string_for_ana(gen_ctx : &GeneralContext,
             }
         },
         Err(e) => {
-            return Err( HibouParsingError::MatchError(e.to_string()) );
+            return Err( HibouParsingError::from_pest_error(e, &hcf_string) );
         }
     }
 }
@@ -88,7 +111,7 @@ fn parse_conf_pair_for_ana(gen_ctx : &GeneralContext,
         match current_pair.as_rule() {
             Rule::ANALYZE_OPTION_SECTION => {
                 if got_section_ana_options {
-                    return Err( HibouParsingError::HsfSetupError("several '@analyze_option' sections declared".to_string()));
+                    return Err( HibouParsingError::HcfSetupError("several '@analyze_option' sections declared".to_string()).locate(&current_pair.as_span()) );
                 }
                 got_section_ana_options = true;
                 // ***
@@ -115,3 +138,97 @@ fn parse_conf_pair_for_ana(gen_ctx : &GeneralContext,
 
     return Ok(ana_options);
 }
+
+
+
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::general_context::GeneralContext;
+    use crate::io::input::error::HibouParsingError;
+    use crate::process::ana::param::anakind::AnalysisKind;
+    use super::*;
+
+    fn parse_analyze_section(options : &str) -> Result<HibouAnalyzeOptions,HibouParsingError> {
+        return parse_hcf_string_for_ana(&GeneralContext::new(),
+                                        format!("@analyze_option{{\n    {}\n}}", options),
+                                        "test");
+    }
+
+    #[test]
+    fn alignment_is_an_analysis_kind() {
+        match parse_analyze_section("analysis_kind = align") {
+            Ok( ana_opts ) => {
+                assert_eq!(ana_opts.ana_param.ana_kind, AnalysisKind::Align(None));
+            },
+            Err(e) => {
+                panic!("unexpected parsing error : {:?}", e);
+            }
+        }
+        match parse_analyze_section("analysis_kind = align[max_cost=3]") {
+            Ok( ana_opts ) => {
+                assert_eq!(ana_opts.ana_param.ana_kind, AnalysisKind::Align(Some(3)));
+            },
+            Err(e) => {
+                panic!("unexpected parsing error : {:?}", e);
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_range_alignment_cost_is_a_located_error() {
+        match parse_analyze_section("analysis_kind = align[max_cost=99999999999]") {
+            Err( HibouParsingError::Located(err,loc) ) => {
+                assert!(matches!(*err, HibouParsingError::HcfSetupError(_)));
+                assert_eq!(loc.line, 2);
+                assert_eq!(loc.column, 36);
+            },
+            Err(e) => {
+                panic!("unexpected parsing error : {:?}", e);
+            },
+            Ok( _ ) => {
+                panic!("out of range cost was accepted");
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_range_number_of_threads_is_a_located_error() {
+        match parse_analyze_section("threads = 99999999999999999999") {
+            Err( HibouParsingError::Located(err,loc) ) => {
+                assert!(matches!(*err, HibouParsingError::HcfSetupError(_)));
+                assert_eq!(loc.line, 2);
+                assert_eq!(loc.column, 15);
+            },
+            Err(e) => {
+                panic!("unexpected parsing error : {:?}", e);
+            },
+            Ok( _ ) => {
+                panic!("out of range number of threads was accepted");
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_range_resource_limits_are_located_errors() {
+        for (options,column) in [("max_time = 18446744073709551615 min",16),
+                                 ("max_nodes_in_memory = 99999999999",27)] {
+            match parse_analyze_section(options) {
+                Err( HibouParsingError::Located(err,loc) ) => {
+                    assert!(matches!(*err, HibouParsingError::HcfSetupError(_)));
+                    assert_eq!(loc.line, 2);
+                    assert_eq!(loc.column, column);
+                },
+                Err(e) => {
+                    panic!("unexpected parsing error : {:?}", e);
+                },
+                Ok( _ ) => {
+                    panic!("out of range limit was accepted : {}", options);
+                }
+            }
+        }
+        assert!(parse_analyze_section("max_time = 18446744073709551615 ms").is_ok());
+    }
+}
@@ -15,14 +15,15 @@ limitations under the License.
 */
 
 
+use std::collections::BTreeSet;
 use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
-use graph_process_manager_core::handler::filter::AbstractFilter;
 use graph_process_manager_core::manager::logger::AbstractProcessLogger;
 use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
 use pest::iterators::Pair;
 use crate::core::general_context::GeneralContext;
 
 use crate::io::input::error::HibouParsingError;
+use crate::io::input::hcf::limits::parse_resource_limit;
 use crate::io::input::hcf::loggers::graphviz::parse_graphic_logger;
 use crate::io::input::hcf::loggers::tracegen::parse_tracegen_logger;
 
@@ -34,33 +35,43 @@ use crate::io::input::hcf::parser::{HcfParser,Rule};
 
 
 use crate::process::ana::conf::AnalysisConfig;
-use crate::process::ana::filter::elim::AnalysisFilterEliminationKind;
-use crate::process::ana::filter::filter::{AnalysisFilter, AnalysisFilterCriterion};
+use crate::process::ana::filter::filter::AnalysisFilter;
 use crate::process::ana::param::anakind::{AnalysisKind, SimulationActionCriterion, SimulationConfiguration, SimulationLoopCriterion};
 use crate::process::ana::param::param::{AnalysisParameterization, LocalAnalysisLifelineSelectionPolicy, LocalAnalysisParameterization};
 use crate::process::ana::priorities::AnalysisPriorities;
 use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::parallel::limits::ProcessResourceLimits;
 
 
 pub struct HibouAnalyzeOptions {
     pub loggers : Vec<Box< dyn AbstractProcessLogger<AnalysisConfig>>>,
     pub strategy : QueueSearchStrategy,
-    pub filters : Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>>,
+    pub filters : Vec<AnalysisFilter>,
     pub priorities : GenericProcessPriorities<AnalysisPriorities>,
     pub use_memoization : bool,
     pub goal : Option<AnalysisGlobalVerdict>,
-    pub ana_param : AnalysisParameterization
+    pub ana_param : AnalysisParameterization,
+    // messages which are hidden from the interaction and the multi-trace before the analysis
+    pub unobserved_messages : BTreeSet<usize>,
+    // number of threads processing the steps of the analysis
+    pub threads : usize,
+    // budgets beyond which the analysis is stopped
+    pub limits : ProcessResourceLimits
 }
 
 impl HibouAnalyzeOptions {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(loggers : Vec<Box< dyn AbstractProcessLogger<AnalysisConfig>>>,
                strategy : QueueSearchStrategy,
-               filters : Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>>,
+               filters : Vec<AnalysisFilter>,
                priorities : GenericProcessPriorities<AnalysisPriorities>,
                ana_param : AnalysisParameterization,
                use_memoization : bool,
-               goal : Option<AnalysisGlobalVerdict>) -> HibouAnalyzeOptions {
-        HibouAnalyzeOptions{loggers,strategy,filters,priorities,use_memoization,goal,ana_param}
+               goal : Option<AnalysisGlobalVerdict>,
+               unobserved_messages : BTreeSet<usize>,
+               threads : usize,
+               limits : ProcessResourceLimits) -> HibouAnalyzeOptions {
+        HibouAnalyzeOptions{loggers,strategy,filters,priorities,use_memoization,goal,ana_param,unobserved_messages,threads,limits}
     }
 
     pub fn default() -> HibouAnalyzeOptions {
@@ -78,9 +89,32 @@ impl HibouAnalyzeOptions {
             GenericProcessPriorities::new(AnalysisPriorities::default(),false),
             default_param,
             true,
-            Some(AnalysisGlobalVerdict::WeakPass)
+            Some(AnalysisGlobalVerdict::WeakPass),
+            BTreeSet::new(),
+            1,
+            ProcessResourceLimits::unlimited()
         )
     }
+
+    // loggers cannot be shared between analyses
+    pub fn clone_without_loggers(&self) -> HibouAnalyzeOptions {
+        let strategy = match self.strategy {
+            QueueSearchStrategy::BFS => QueueSearchStrategy::BFS,
+            QueueSearchStrategy::DFS => QueueSearchStrategy::DFS,
+            QueueSearchStrategy::HCS => QueueSearchStrategy::HCS
+        };
+        let priorities = GenericProcessPriorities::new(self.priorities.specific.clone(),self.priorities.randomize);
+        return HibouAnalyzeOptions::new(vec![],
+                                        strategy,
+                                        self.filters.clone(),
+                                        priorities,
+                                        self.ana_param.clone(),
+                                        self.use_memoization,
+                                        self.goal.clone(),
+                                        self.unobserved_messages.clone(),
+                                        self.threads,
+                                        self.limits.clone());
+    }
 }
 
 
@@ -89,7 +123,7 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                              file_name : &str) -> Result<HibouAnalyzeOptions,HibouParsingError> {
     let mut loggers : Vec<Box< dyn AbstractProcessLogger<AnalysisConfig>>> = Vec::new();
     let mut strategy : QueueSearchStrategy = QueueSearchStrategy::BFS;
-    let mut filters : Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>> = Vec::new();
+    let mut filters : Vec<AnalysisFilter> = Vec::new();
     let mut priorities : GenericProcessPriorities<AnalysisPriorities> = GenericProcessPriorities::new(AnalysisPriorities::default(),false);
     let mut ana_kind = AnalysisKind::Prefix;
     let mut use_locana = true;
@@ -100,8 +134,14 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
     let mut use_partial_order_reduction = false;
     let mut use_memoization = true;
     let mut goal = Some(AnalysisGlobalVerdict::WeakPass);
+    let mut unobserved_messages : BTreeSet<usize> = BTreeSet::new();
+    let mut threads : usize = 1;
+    let mut limits = ProcessResourceLimits::unlimited();
     // ***
+    // errors in independent options are all reported
+    let mut errors : Vec<HibouParsingError> = Vec::new();
     for option_decl_pair in option_pair.into_inner() {
+        let option_span = option_decl_pair.as_span();
         match option_decl_pair.as_rule() {
             Rule::OPTION_LOGGER_DECL => {
                 loggers = Vec::new();
@@ -117,7 +157,7 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                         Rule::OPTION_TRACEGEN_LOGGER => {
                             match parse_tracegen_logger(logger_id, gen_ctx,file_name,logger_kind_pair) {
                                 Err(e) => {
-                                    return Err(e);
+                                    errors.push( e.locate(&option_span) );
                                 },
                                 Ok( tlogger ) => {
                                     loggers.push( Box::new(tlogger));
@@ -125,7 +165,7 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                             }
                         },
                         Rule::OPTION_NFAIT_LOGGER => {
-                            return Err(HibouParsingError::HcfSetupError("cannot use NFAIT logger for trace analysis".to_string()));
+                            errors.push( HibouParsingError::HcfSetupError("cannot use NFAIT logger for trace analysis".to_string()).locate(&option_span) );
                         },
                         _ => {
                             panic!("what rule then ? : {:?}", logger_kind_pair.as_rule() );
@@ -156,7 +196,7 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                         filters = got_filters;
                     },
                     Err(e) => {
-                        return Err(e);
+                        errors.push( e.locate(&option_span) );
                     }
                 }
             },
@@ -169,7 +209,7 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                                 priorities = got_priorities;
                             },
                             Err(e) => {
-                                return Err(e);
+                                errors.push( e.locate(&option_span) );
                             }
                         }
                     },
@@ -221,7 +261,26 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                                         ana_kind = AnalysisKind::Simulate(sim_config);
                                     },
                                     Err(e) => {
-                                        return Err(e);
+                                        errors.push( e.locate(&option_span) );
+                                    }
+                                }
+                            }
+                        }
+                    },
+                    Rule::OPTION_ANA_KIND_align => {
+                        match ana_kind_pair.into_inner().next() {
+                            None => {
+                                ana_kind = AnalysisKind::Align(None);
+                            },
+                            Some( max_cost_pair ) => {
+                                let content_str : String = max_cost_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                                match content_str.parse::<u32>() {
+                                    Err(_) => {
+                                        errors.push( HibouParsingError::HcfSetupError(
+                                            format!("maximum alignment cost '{}' is out of range (at most {})", content_str, u32::MAX) ).locate(&max_cost_pair.as_span()) );
+                                    },
+                                    Ok( max_cost ) => {
+                                        ana_kind = AnalysisKind::Align(Some(max_cost));
                                     }
                                 }
                             }
@@ -248,7 +307,7 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
             },
             Rule::OPTION_LOCANA => {
                 match parse_local_analyses_config(option_decl_pair) {
-                    Err(e) => {return Err(e);},
+                    Err(e) => {errors.push( e.locate(&option_span) );},
                     Ok(got) => {
                         if let Some(got_locana_param) = got {
                             use_locana = true;
@@ -276,6 +335,41 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
                     }
                 }
             },
+            Rule::OPTION_UNOBSERVED_MESSAGES => {
+                unobserved_messages = BTreeSet::new();
+                for ms_name_pair in option_decl_pair.into_inner() {
+                    let ms_name : String = ms_name_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                    match gen_ctx.get_ms_id(&ms_name) {
+                        None => {
+                            errors.push( HibouParsingError::MissingMessageDeclarationError(ms_name).locate(&option_span) );
+                        },
+                        Some( ms_id ) => {
+                            unobserved_messages.insert(ms_id);
+                        }
+                    }
+                }
+            },
+            Rule::OPTION_THREADS => {
+                let content = option_decl_pair.into_inner().next().unwrap();
+                let content_str : String = content.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                match content_str.parse::<usize>() {
+                    Err(_) => {
+                        errors.push( HibouParsingError::HcfSetupError(
+                            format!("number of threads '{}' is out of range (at most {})", content_str, usize::MAX) ).locate(&content.as_span()) );
+                    },
+                    Ok( 0 ) => {
+                        errors.push( HibouParsingError::HcfSetupError("the number of threads must be at least 1".to_string()).locate(&option_span) );
+                    },
+                    Ok( my_val ) => {
+                        threads = my_val;
+                    }
+                }
+            },
+            Rule::OPTION_MAX_TIME | Rule::OPTION_MAX_NODES_IN_MEMORY => {
+                if let Err(e) = parse_resource_limit(option_decl_pair,&mut limits) {
+                    errors.push(e);
+                }
+            },
             _ => {
                 panic!("what rule then ? : {:?}", option_decl_pair.as_rule() );
             }
@@ -288,32 +382,35 @@ pub fn parse_analyze_options(gen_ctx : &GeneralContext,
         None
     };
     let param = AnalysisParameterization::new(ana_kind,locana_param, use_partial_order_reduction);
-    let hoptions = HibouAnalyzeOptions::new(loggers,strategy,filters,priorities,param,use_memoization,goal);
+    let hoptions = HibouAnalyzeOptions::new(loggers,strategy,filters,priorities,param,use_memoization,goal,unobserved_messages,threads,limits);
+    if !errors.is_empty() {
+        return Err( HibouParsingError::from_errors(errors) );
+    }
     return Ok(hoptions);
 }
 
 
-fn parse_filters(filters_decl_pair : Pair<Rule>) -> Result<Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>>,HibouParsingError> {
-    let mut filters : Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>> = Vec::new();
+fn parse_filters(filters_decl_pair : Pair<Rule>) -> Result<Vec<AnalysisFilter>,HibouParsingError> {
+    let mut filters : Vec<AnalysisFilter> = Vec::new();
     for filter_pair in filters_decl_pair.into_inner() {
         match filter_pair.as_rule() {
             Rule::OPTION_FILTER_MAX_DEPTH => {
                 let content = filter_pair.into_inner().next().unwrap();
                 let content_str : String = content.as_str().chars().filter(|c| !c.is_whitespace()).collect();
                 let my_val : u32 = content_str.parse::<u32>().unwrap();
-                filters.push(Box::new(AnalysisFilter::MaxProcessDepth(my_val)));
+                filters.push(AnalysisFilter::MaxProcessDepth(my_val));
             },
             Rule::OPTION_FILTER_MAX_LOOP_DEPTH  => {
                 let content = filter_pair.into_inner().next().unwrap();
                 let content_str : String = content.as_str().chars().filter(|c| !c.is_whitespace()).collect();
                 let my_val : u32 = content_str.parse::<u32>().unwrap();
-                filters.push(Box::new(AnalysisFilter::MaxLoopInstanciation(my_val)));
+                filters.push(AnalysisFilter::MaxLoopInstanciation(my_val));
             },
             Rule::OPTION_FILTER_MAX_NODE_NUMBER  => {
                 let content = filter_pair.into_inner().next().unwrap();
                 let content_str : String = content.as_str().chars().filter(|c| !c.is_whitespace()).collect();
                 let my_val : u32 = content_str.parse::<u32>().unwrap();
-                filters.push(Box::new(AnalysisFilter::MaxNodeNumber(my_val)));
+                filters.push(AnalysisFilter::MaxNodeNumber(my_val));
             },
             _ => {
                 panic!("what rule then ? : {:?}", filter_pair.as_rule() );
@@ -42,7 +42,14 @@ pub fn parse_hcf_file_for_canonize(gen_ctx : &GeneralContext,
     let file_name : &str = path_object.file_stem().unwrap().to_str().unwrap();
     match fs::read_to_string(file_path) {
         Ok( unparsed_hcf_str ) => {
-            return parse_hcf_string_for_canonize(gen_ctx,unparsed_hcf_str, file_name);
+            match parse_hcf_string_for_canonize(gen_ctx,unparsed_hcf_str, file_name) {
+                Err(e) => {
+                    return Err( e.with_file_path(file_path) );
+                },
+                Ok( canon_options ) => {
+                    return Ok( canon_options );
+                }
+            }
         },
         Err(e) => {
             return Err( HibouParsingError::FileError(e.to_string()) );
@@ -67,7 +74,7 @@ fn parse_hcf_string_for_canonize(gen_ctx : &GeneralContext,
             }
         },
         Err(e) => {
-            return Err( HibouParsingError::MatchError(e.to_string()) );
+            return Err( HibouParsingError::from_pest_error(e, &hcf_string) );
         }
     }
 }
@@ -86,7 +93,7 @@ fn parse_conf_pair_for_canonize(gen_ctx : &GeneralContext,
         match current_pair.as_rule() {
             Rule::CANONIZE_OPTION_SECTION => {
                 if got_section_canonize_options {
-                    return Err( HibouParsingError::HsfSetupError("several '@canonize_option' sections declared".to_string()));
+                    return Err( HibouParsingError::HcfSetupError("several '@canonize_option' sections declared".to_string()).locate(&current_pair.as_span()) );
                 }
                 got_section_canonize_options = true;
                 // ***
@@ -23,6 +23,7 @@ use pest::iterators::Pair;
 
 use crate::core::general_context::GeneralContext;
 use crate::io::input::error::HibouParsingError;
+use crate::io::input::hcf::limits::parse_resource_limit;
 use crate::io::input::hcf::loggers::graphviz::parse_graphic_logger;
 use crate::io::input::hcf::loggers::tracegen::parse_tracegen_logger;
 
@@ -37,6 +38,7 @@ use crate::process::explo::conf::ExplorationConfig;
 use crate::process::explo::filter::elim::ExplorationFilterEliminationKind;
 use crate::process::explo::filter::filter::{ExplorationFilter, ExplorationFilterCriterion};
 use crate::process::explo::priorities::ExplorationPriorities;
+use crate::process::parallel::limits::ProcessResourceLimits;
 
 
 pub struct HibouExploreOptions {
@@ -44,7 +46,11 @@ pub struct HibouExploreOptions {
     pub strategy : QueueSearchStrategy,
     pub filters : Vec<Box<dyn AbstractFilter<ExplorationFilterCriterion,ExplorationFilterEliminationKind>>>,
     pub priorities : GenericProcessPriorities<ExplorationPriorities>,
-    pub use_memoization : bool
+    pub use_memoization : bool,
+    // number of threads processing the steps of the exploration
+    pub threads : usize,
+    // budgets beyond which the exploration is stopped
+    pub limits : ProcessResourceLimits
 }
 
 
@@ -54,8 +60,10 @@ impl HibouExploreOptions {
                strategy : QueueSearchStrategy,
                filters : Vec<Box<dyn AbstractFilter<ExplorationFilterCriterion,ExplorationFilterEliminationKind>>>,
                priorities : GenericProcessPriorities<ExplorationPriorities>,
-               use_memoization : bool) -> HibouExploreOptions {
-        return HibouExploreOptions{loggers,strategy,filters,priorities,use_memoization};
+               use_memoization : bool,
+               threads : usize,
+               limits : ProcessResourceLimits) -> HibouExploreOptions {
+        return HibouExploreOptions{loggers,strategy,filters,priorities,use_memoization,threads,limits};
     }
 
     pub fn default() -> HibouExploreOptions {
@@ -63,7 +71,9 @@ impl HibouExploreOptions {
                                         QueueSearchStrategy::BFS,
                                         vec![Box::new(ExplorationFilter::MaxLoopInstanciation(1))],
                                         GenericProcessPriorities::new(ExplorationPriorities::default(),false),
-                                        false);
+                                        false,
+                                        1,
+                                        ProcessResourceLimits::unlimited());
     }
 
 }
@@ -79,8 +89,13 @@ pub fn parse_explore_options(gen_ctx: &GeneralContext,
     let mut filters : Vec<Box<dyn AbstractFilter<ExplorationFilterCriterion,ExplorationFilterEliminationKind>>> = Vec::new();
     let mut priorities : GenericProcessPriorities<ExplorationPriorities> = GenericProcessPriorities::new(ExplorationPriorities::default(),false);
     let mut use_memoization = false;
+    let mut threads : usize = 1;
+    let mut limits = ProcessResourceLimits::unlimited();
     // ***
+    // errors in independent options are all reported
+    let mut errors : Vec<HibouParsingError> = Vec::new();
     for option_decl_pair in option_pair.into_inner() {
+        let option_span = option_decl_pair.as_span();
         match option_decl_pair.as_rule() {
             Rule::OPTION_LOGGER_DECL => {
                 loggers = Vec::new();
@@ -96,7 +111,7 @@ pub fn parse_explore_options(gen_ctx: &GeneralContext,
                         Rule::OPTION_TRACEGEN_LOGGER => {
                             match parse_tracegen_logger(logger_id, gen_ctx,file_name,logger_kind_pair) {
                                 Err(e) => {
-                                    return Err(e);
+                                    errors.push( e.locate(&option_span) );
                                 },
                                 Ok( tlogger ) => {
                                     loggers.push( Box::new(tlogger));
@@ -106,7 +121,7 @@ pub fn parse_explore_options(gen_ctx: &GeneralContext,
                         Rule::OPTION_NFAIT_LOGGER => {
                             match parse_nfait_logger(logger_id, gen_ctx,file_name,logger_kind_pair) {
                                 Err(e) => {
-                                    return Err(e);
+                                    errors.push( e.locate(&option_span) );
                                 },
                                 Ok( tlogger ) => {
                                     loggers.push( Box::new(tlogger));
@@ -142,7 +157,7 @@ pub fn parse_explore_options(gen_ctx: &GeneralContext,
                         filters = got_filters;
                     },
                     Err(e) => {
-                        return Err(e);
+                        errors.push( e.locate(&option_span) );
                     }
                 }
             },
@@ -155,7 +170,7 @@ pub fn parse_explore_options(gen_ctx: &GeneralContext,
                                 priorities = got_priorities;
                             },
                             Err(e) => {
-                                return Err(e);
+                                errors.push( e.locate(&option_span) );
                             }
                         }
                     },
@@ -178,13 +193,37 @@ pub fn parse_explore_options(gen_ctx: &GeneralContext,
                     }
                 }
             },
+            Rule::OPTION_THREADS => {
+                let content = option_decl_pair.into_inner().next().unwrap();
+                let content_str : String = content.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                match content_str.parse::<usize>() {
+                    Err(_) => {
+                        errors.push( HibouParsingError::HcfSetupError(
+                            format!("number of threads '{}' is out of range (at most {})", content_str, usize::MAX) ).locate(&content.as_span()) );
+                    },
+                    Ok( 0 ) => {
+                        errors.push( HibouParsingError::HcfSetupError("the number of threads must be at least 1".to_string()).locate(&option_span) );
+                    },
+                    Ok( my_val ) => {
+                        threads = my_val;
+                    }
+                }
+            },
+            Rule::OPTION_MAX_TIME | Rule::OPTION_MAX_NODES_IN_MEMORY => {
+                if let Err(e) = parse_resource_limit(option_decl_pair,&mut limits) {
+                    errors.push(e);
+                }
+            },
             _ => {
                 panic!("what rule then ? : {:?}", option_decl_pair.as_rule() );
             }
         }
     }
     // ***
-    let hoptions = HibouExploreOptions::new(loggers,strategy,filters,priorities,use_memoization);
+    let hoptions = HibouExploreOptions::new(loggers,strategy,filters,priorities,use_memoization,threads,limits);
+    if !errors.is_empty() {
+        return Err( HibouParsingError::from_errors(errors) );
+    }
     return Ok(hoptions);
 }
 
@@ -0,0 +1,83 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::time::Duration;
+
+use pest::iterators::Pair;
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hcf::parser::{HcfParser,Rule};
+
+use crate::io::input::error::HibouParsingError;
+use crate::process::parallel::limits::ProcessResourceLimits;
+
+
+// the grammar does not bound the number of digits of the values, which may hence be out of range
+pub fn parse_resource_limit(limit_decl_pair : Pair<Rule>,
+                            limits : &mut ProcessResourceLimits) -> Result<(),HibouParsingError> {
+    match limit_decl_pair.as_rule() {
+        Rule::OPTION_MAX_TIME => {
+            let mut content = limit_decl_pair.into_inner();
+            let value_pair = content.next().unwrap();
+            let value_str : String = value_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+            // in seconds by default
+            let max_time = match (value_str.parse::<u64>(),content.next().map(|unit_pair| unit_pair.as_rule())) {
+                (Ok(value),Some(Rule::OPTION_TIME_UNIT_ms)) => {
+                    Some(Duration::from_millis(value))
+                },
+                (Ok(value),Some(Rule::OPTION_TIME_UNIT_min)) => {
+                    value.checked_mul(60).map(Duration::from_secs)
+                },
+                (Ok(value),_) => {
+                    Some(Duration::from_secs(value))
+                },
+                (Err(_),_) => {
+                    None
+                }
+            };
+            match max_time {
+                None => {
+                    return Err( HibouParsingError::HcfSetupError(
+                        format!("maximum time '{}' is out of range (at most {} seconds)", value_str, u64::MAX) ).locate(&value_pair.as_span()) );
+                },
+                Some( got_max_time ) => {
+                    limits.max_time = Some(got_max_time);
+                }
+            }
+        },
+        Rule::OPTION_MAX_NODES_IN_MEMORY => {
+            let value_pair = limit_decl_pair.into_inner().next().unwrap();
+            let value_str : String = value_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+            match value_str.parse::<u32>() {
+                Err(_) => {
+                    return Err( HibouParsingError::HcfSetupError(
+                        format!("maximum number of nodes in memory '{}' is out of range (at most {})", value_str, u32::MAX) ).locate(&value_pair.as_span()) );
+                },
+                Ok( value ) => {
+                    limits.max_nodes_in_memory = Some(value);
+                }
+            }
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", limit_decl_pair.as_rule() );
+        }
+    }
+    return Ok(());
+}
@@ -18,6 +18,7 @@ limitations under the License.
 mod parser;
 
 pub mod loggers;
+pub mod limits;
 
 pub mod explo;
 pub mod canon;
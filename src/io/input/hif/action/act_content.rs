@@ -19,7 +19,9 @@ use pest::iterators::Pair;
 
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::action::CommunicationSynchronicity;
+use crate::core::language::syntax::value::ValuePattern;
 use crate::io::input::error::HibouParsingError;
+use crate::io::input::value::{check_arguments_against_types, parse_value_pattern};
 
 
 
@@ -56,56 +58,59 @@ pub fn parse_comm_act_origin(gen_ctx : &GeneralContext, origin_pair : Pair<Rule>
     }
 }
 
-pub fn parse_comm_content(gen_ctx : &GeneralContext, comm_content_pair : Pair<Rule>) -> Result<(CommunicationSynchronicity,usize),HibouParsingError> {
-    let mut contents = comm_content_pair.into_inner();
-    let first_pair = contents.next().unwrap();
+pub fn parse_comm_content(gen_ctx : &GeneralContext, comm_content_pair : Pair<Rule>) -> Result<(CommunicationSynchronicity,usize,Vec<ValuePattern>),HibouParsingError> {
+    let contents = comm_content_pair.into_inner();
     let mut comm_type = CommunicationSynchronicity::Asynchronous;
     let mut got_ms_id : Option<usize> = None;
-    match first_pair.as_rule() {
-        Rule::COMM_ASYNCH => {
-            comm_type = CommunicationSynchronicity::Asynchronous;
-        },
-        Rule::COMM_SYNCH => {
-            comm_type = CommunicationSynchronicity::Synchronous;
-        },
-        Rule::HIBOU_LABEL => {
-            let ms_name : String = first_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
-            match gen_ctx.get_ms_id( &ms_name ) {
-                None => {
-                    return Err( HibouParsingError::MissingMessageDeclarationError( ms_name ) );
-                },
-                Some( ms_id ) => {
-                    got_ms_id = Some(ms_id);
+    let mut args : Vec<ValuePattern> = Vec::new();
+    for content_pair in contents {
+        match content_pair.as_rule() {
+            Rule::COMM_ASYNCH => {
+                comm_type = CommunicationSynchronicity::Asynchronous;
+            },
+            Rule::COMM_SYNCH => {
+                comm_type = CommunicationSynchronicity::Synchronous;
+            },
+            Rule::HIBOU_LABEL => {
+                let ms_name : String = content_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                match gen_ctx.get_ms_id( &ms_name ) {
+                    None => {
+                        return Err( HibouParsingError::MissingMessageDeclarationError( ms_name ) );
+                    },
+                    Some( ms_id ) => {
+                        got_ms_id = Some(ms_id);
+                    }
                 }
-            }
-        },
-        _ => {
-            panic!("what rule then ? : {:?}", first_pair.as_rule() );
-        }
-    }
-    // ***
-    match got_ms_id {
-        None => {
-            let second_pair = contents.next().unwrap();
-            match second_pair.as_rule() {
-                Rule::HIBOU_LABEL => {
-                    let ms_name : String = second_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
-                    match gen_ctx.get_ms_id( &ms_name ) {
-                        None => {
-                            return Err( HibouParsingError::MissingMessageDeclarationError( ms_name ) );
+            },
+            Rule::SD_MESSAGE_ARGS => {
+                for arg_pair in content_pair.into_inner() {
+                    match parse_value_pattern(arg_pair.as_str()) {
+                        Err(e) => {
+                            return Err( e.locate(&arg_pair.as_span()) );
                         },
-                        Some( ms_id ) => {
-                            return Ok( (comm_type,ms_id) );
+                        Ok( arg ) => {
+                            args.push(arg);
                         }
                     }
-                },
-                _ => {
-                    panic!("what rule then ? : {:?}", second_pair.as_rule() );
                 }
+            },
+            _ => {
+                panic!("what rule then ? : {:?}", content_pair.as_rule() );
             }
-        },
-        Some(ms_id) => {
-            return Ok( (comm_type,ms_id) );
         }
     }
-}
\ No newline at end of file
+    // ***
+    let ms_id = got_ms_id.unwrap();
+    // when no arguments are given, any values are accepted
+    if !args.is_empty() {
+        match check_arguments_against_types(&gen_ctx.get_ms_name(ms_id).unwrap(),
+                                            &args,
+                                            &gen_ctx.get_ms_arg_types(ms_id).unwrap()) {
+            Err(e) => {
+                return Err(e);
+            },
+            Ok(_) => {}
+        }
+    }
+    return Ok( (comm_type,ms_id,args) );
+}
@@ -64,7 +64,7 @@ pub fn parse_communication_action(gen_ctx : &GeneralContext, contents : &mut Pai
         Err(e) => {
             return Err(e);
         },
-        Ok( (comm_synchro, ms_id) ) => {
+        Ok( (comm_synchro, ms_id, args) ) => {
             match origin_info {
                 None => {
                     match parse_comm_act_targets_as_lifelines(gen_ctx,comm_act_target_pair) {
@@ -72,7 +72,7 @@ pub fn parse_communication_action(gen_ctx : &GeneralContext, contents : &mut Pai
                             return Err(e);
                         },
                         Ok( tar_lf_ids) => {
-                            let rc_act = ReceptionAction::new(None,ms_id,comm_synchro,tar_lf_ids);
+                            let rc_act = ReceptionAction::new(None,ms_id,comm_synchro,tar_lf_ids,args);
                             return Ok( Interaction::Reception(rc_act) );
                         }
                     }
@@ -85,7 +85,7 @@ pub fn parse_communication_action(gen_ctx : &GeneralContext, contents : &mut Pai
                                     return Err(e);
                                 },
                                 Ok( tar_lf_ids) => {
-                                    let rc_act = ReceptionAction::new(Some(gt_id),ms_id,comm_synchro,tar_lf_ids);
+                                    let rc_act = ReceptionAction::new(Some(gt_id),ms_id,comm_synchro,tar_lf_ids,args);
                                     return Ok( Interaction::Reception(rc_act) );
                                 }
                             }
@@ -96,7 +96,7 @@ pub fn parse_communication_action(gen_ctx : &GeneralContext, contents : &mut Pai
                                     return Err(e);
                                 },
                                 Ok( tar_refs) => {
-                                    let em_act = EmissionAction::new(lf_id,ms_id,comm_synchro,tar_refs);
+                                    let em_act = EmissionAction::new(lf_id,ms_id,comm_synchro,tar_refs,args);
                                     return Ok( Interaction::Emission(em_act) );
                                 }
                             }
@@ -0,0 +1,294 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use pest::iterators::Pair;
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hif::parser::{HifParser,Rule};
+
+use crate::io::input::comments::{CommentCursor, comments_as_inline_prefix};
+use crate::io::input::error::HibouParsingError;
+use crate::io::textual_convention::{SYNTAX_EMPTY, SYNTAX_REF};
+
+
+// the formatting is purely syntactic : references, replications and imports are kept as such
+// and no signature is required
+pub fn format_hif_string(hif_string : &str, keep_comments : bool) -> Result<String,HibouParsingError> {
+    match HifParser::parse(Rule::HIF_PEST_FILE, hif_string) {
+        Err(e) => {
+            return Err( HibouParsingError::from_pest_error(e, hif_string) );
+        },
+        Ok( got_pairs ) => {
+            let mut cursor = CommentCursor::new(hif_string, keep_comments);
+            let mut imports : Vec<String> = vec![];
+            let mut blocks : Vec<String> = vec![];
+            for pair in got_pairs {
+                match pair.as_rule() {
+                    Rule::HIF_IMPORT => {
+                        let mut lines = cursor.take_until(pair.as_span().start());
+                        lines.push( format!("@import \"{}\"", pair.clone().into_inner().next().unwrap().as_str()) );
+                        cursor.take_until(pair.as_span().end());
+                        imports.extend(lines);
+                    },
+                    Rule::HIF_DEFINITION => {
+                        let mut lines = cursor.take_until(pair.as_span().start());
+                        let mut def_content = pair.into_inner();
+                        let def_name = def_content.next().unwrap().as_str();
+                        let def_int = def_content.next().unwrap();
+                        let prefix = comments_as_inline_prefix(&cursor.take_until(def_int.as_span().start()));
+                        lines.push( format!("@def {} = {}{}", def_name, prefix, format_interaction(def_int, 0, &mut cursor)) );
+                        blocks.push( lines.join("\n") );
+                    },
+                    Rule::SD_INTERACTION => {
+                        let mut lines = cursor.take_until(pair.as_span().start());
+                        lines.push( format_interaction(pair, 0, &mut cursor) );
+                        blocks.push( lines.join("\n") );
+                    },
+                    Rule::EOI => {
+                        let trailing = cursor.take_until(hif_string.len());
+                        if !trailing.is_empty() {
+                            blocks.push( trailing.join("\n") );
+                        }
+                    },
+                    _ => {
+                        panic!("what rule then ? : {:?}", pair.as_rule() );
+                    }
+                }
+            }
+            if !imports.is_empty() {
+                blocks.insert(0, imports.join("\n"));
+            }
+            return Ok( format!("{}\n", blocks.join("\n\n")) );
+        }
+    }
+}
+
+fn indent(depth : usize) -> String {
+    return "\t".repeat(depth);
+}
+
+// the first line is not indented, the following ones are indented relatively to the depth
+fn format_interaction(interaction_pair : Pair<Rule>, depth : usize, cursor : &mut CommentCursor) -> String {
+    let content_pair = interaction_pair.into_inner().next().unwrap();
+    let content_end = content_pair.as_span().end();
+    match content_pair.as_rule() {
+        Rule::SD_EMPTY_INTERACTION => {
+            let prefix = comments_as_inline_prefix(&cursor.take_through(&content_pair));
+            return format!("{}{}", prefix, SYNTAX_EMPTY);
+        },
+        Rule::SD_REF_INT => {
+            let prefix = comments_as_inline_prefix(&cursor.take_through(&content_pair));
+            let ref_name = content_pair.into_inner().next().unwrap().as_str();
+            return format!("{}{}({})", prefix, SYNTAX_REF, ref_name);
+        },
+        Rule::SD_COMMUNICATION_ACTION => {
+            let prefix = comments_as_inline_prefix(&cursor.take_through(&content_pair));
+            return format!("{}{}", prefix, format_communication_action(content_pair));
+        },
+        Rule::SD_FORALL_INT => {
+            let mut contents = content_pair.into_inner();
+            let op_name = contents.next().unwrap().as_str();
+            let var_name = contents.next().unwrap().as_str();
+            let family_name = contents.next().unwrap().as_str();
+            let body_pair = contents.next().unwrap();
+            let prefix = comments_as_inline_prefix(&cursor.take_until(body_pair.as_span().start()));
+            return format!("{} forall {} in {}: {}{}", op_name, var_name, family_name, prefix, format_interaction(body_pair, depth, cursor));
+        },
+        Rule::SD_STRICT_INT | Rule::SD_SEQ_INT | Rule::SD_ALT_INT | Rule::SD_PAR_INT => {
+            // nested applications of a same associative operator are flattened
+            let rule = content_pair.as_rule();
+            let mut operands = vec![];
+            let mut contents = content_pair.into_inner();
+            let op_name = contents.next().unwrap().as_str().to_string();
+            for sub_pair in contents {
+                collect_flattened_operands(sub_pair, rule, &mut operands);
+            }
+            return format_operands(&op_name, operands, depth, content_end, cursor);
+        },
+        Rule::SD_AND_INT | Rule::SD_COREG_INT | Rule::SD_SYNC_INT => {
+            let mut contents = content_pair.into_inner();
+            let mut header = contents.next().unwrap().as_str().to_string();
+            let mut operands = vec![];
+            for sub_pair in contents {
+                match sub_pair.as_rule() {
+                    Rule::HIBOU_LABEL_LIST => {
+                        header.push_str( &format_label_list(sub_pair) );
+                    },
+                    Rule::TRACE_ACTION => {
+                        header.push_str( &format!("({})", format_trace_action(sub_pair)) );
+                    },
+                    Rule::TRACE_ACTION_SET => {
+                        let actions : Vec<String> = sub_pair.into_inner().map(|p| format_trace_action(p)).collect();
+                        header.push_str( &format!("{{{}}}", actions.join(",")) );
+                    },
+                    _ => {
+                        operands.push(sub_pair);
+                    }
+                }
+            }
+            return format_operands(&header, operands, depth, content_end, cursor);
+        },
+        Rule::SD_LOOP_INT => {
+            let mut contents = content_pair.into_inner();
+            let loop_kind = contents.next().unwrap().into_inner().next().unwrap().as_str();
+            return format_operands(&format!("loop{}", loop_kind), contents.collect(), depth, content_end, cursor);
+        },
+        Rule::SD_BOUNDED_LOOP_INT => {
+            let mut contents = content_pair.into_inner();
+            let mut header = "loop".to_string();
+            for loop_pair in contents.next().unwrap().into_inner() {
+                match loop_pair.as_rule() {
+                    Rule::SD_LOOP_BOUNDS => {
+                        let bounds : Vec<&str> = loop_pair.into_inner().map(|p| p.as_str()).collect();
+                        header.push_str( &format!("[{}]", bounds.join(",")) );
+                    },
+                    _ => {
+                        header.push_str( loop_pair.as_str() );
+                    }
+                }
+            }
+            return format_operands(&header, contents.collect(), depth, content_end, cursor);
+        },
+        Rule::SD_NEG_INT => {
+            return format_operands("neg", content_pair.into_inner().collect(), depth, content_end, cursor);
+        },
+        Rule::SD_ASSERT_INT => {
+            return format_operands("assert", content_pair.into_inner().collect(), depth, content_end, cursor);
+        },
+        Rule::SD_DISRUPT_INT => {
+            return format_operands("disrupt", content_pair.into_inner().collect(), depth, content_end, cursor);
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", content_pair.as_rule());
+        }
+    }
+}
+
+fn collect_flattened_operands<'a>(interaction_pair : Pair<'a,Rule>, rule : Rule, operands : &mut Vec<Pair<'a,Rule>>) {
+    let content_pair = interaction_pair.clone().into_inner().next().unwrap();
+    if content_pair.as_rule() == rule {
+        let mut contents = content_pair.into_inner();
+        contents.next(); // get rid of the operator name
+        for sub_pair in contents {
+            collect_flattened_operands(sub_pair, rule, operands);
+        }
+    } else {
+        operands.push(interaction_pair);
+    }
+}
+
+// one operand per line, comments between operands are kept on their own lines
+fn format_operands(header : &str, operands : Vec<Pair<Rule>>, depth : usize, end : usize, cursor : &mut CommentCursor) -> String {
+    let operands_num = operands.len();
+    let mut lines : Vec<String> = vec![];
+    for (idx, operand) in operands.into_iter().enumerate() {
+        for comment in cursor.take_until(operand.as_span().start()) {
+            lines.push( format!("{}{}", indent(depth+1), comment) );
+        }
+        let separator = if idx + 1 < operands_num { "," } else { "" };
+        lines.push( format!("{}{}{}", indent(depth+1), format_interaction(operand, depth+1, cursor), separator) );
+    }
+    for comment in cursor.take_until(end) {
+        lines.push( format!("{}{}", indent(depth+1), comment) );
+    }
+    return format!("{}(\n{}\n{})", header, lines.join("\n"), indent(depth));
+}
+
+fn format_label_list(list_pair : Pair<Rule>) -> String {
+    let labels : Vec<&str> = list_pair.into_inner().map(|p| p.as_str()).collect();
+    return format!("({})", labels.join(","));
+}
+
+fn format_trace_action(action_pair : Pair<Rule>) -> String {
+    let parts : Vec<&str> = action_pair.into_inner().map(|p| p.as_str()).collect();
+    return parts.join("");
+}
+
+fn format_communication_action(action_pair : Pair<Rule>) -> String {
+    let mut origin = "".to_string();
+    let mut content = "".to_string();
+    let mut target = "".to_string();
+    for part in action_pair.into_inner() {
+        match part.as_rule() {
+            Rule::SD_COMMUNICATION_ORIGIN => {
+                origin = format!("{} -- ", part.into_inner().next().unwrap().as_str());
+            },
+            Rule::SD_COMMUNICATION_CONTENT => {
+                for content_part in part.into_inner() {
+                    match content_part.as_rule() {
+                        Rule::COMM_SYNCH => {
+                            content.push_str("<synch>");
+                        },
+                        Rule::COMM_ASYNCH => {
+                            // asynchronous communication is the default
+                        },
+                        Rule::HIBOU_LABEL => {
+                            content.push_str( content_part.as_str() );
+                        },
+                        Rule::SD_MESSAGE_ARGS => {
+                            let args : Vec<&str> = content_part.into_inner().map(|p| p.as_str()).collect();
+                            content.push_str( &format!("({})", args.join(",")) );
+                        },
+                        _ => {
+                            panic!("what rule then ? : {:?}", content_part.as_rule() );
+                        }
+                    }
+                }
+            },
+            Rule::SD_COMMUNICATION_TARGET => {
+                let target_pair = part.into_inner().next().unwrap();
+                match target_pair.as_rule() {
+                    Rule::HIBOU_LABEL => {
+                        target = format!(" -> {}", target_pair.as_str());
+                    },
+                    Rule::HIBOU_LABEL_LIST => {
+                        target = format!(" -> {}", format_label_list(target_pair));
+                    },
+                    Rule::ENVIRONMENT_TARGET => {
+                        target = " ->|".to_string();
+                    },
+                    _ => {
+                        panic!("what rule then ? : {:?}", target_pair.as_rule() );
+                    }
+                }
+            },
+            _ => {
+                panic!("what rule then ? : {:?}", part.as_rule() );
+            }
+        }
+    }
+    return format!("{}{}{}", origin, content, target);
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::io::input::hif::format::format_hif_string;
+
+    #[test]
+    fn format_flattens_and_is_idempotent() {
+        let source = "seq(seq(/* c */ l1 -- <asynch>m1 -> l2, o), l2 -- m2 ->|)";
+        let expected = "seq(\n\t/* c */\n\tl1 -- m1 -> l2,\n\t∅,\n\tl2 -- m2 ->|\n)\n";
+        let formatted = format_hif_string(source, true).unwrap();
+        assert_eq!(formatted, expected);
+        assert_eq!(format_hif_string(&formatted, true).unwrap(), formatted);
+        // comments are dropped by default
+        assert_eq!(format_hif_string(source, false).unwrap(), expected.replace("\t/* c */\n", ""));
+    }
+}
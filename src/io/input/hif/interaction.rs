@@ -16,10 +16,15 @@ limitations under the License.
 
 
 
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
 use pest::iterators::{Pair, Pairs};
 use crate::core::execution::trace::trace::TraceAction;
 
 use crate::core::general_context::GeneralContext;
+use crate::core::language::position::position::Position;
 use crate::core::language::syntax::interaction::{Interaction, LoopKind};
 use crate::io::input::error::HibouParsingError;
 use crate::io::input::hif::action::action::parse_communication_action;
@@ -30,40 +35,266 @@ use pest::Parser;
 #[allow(unused_imports)]
 use crate::io::input::hif::parser::{HifParser,Rule};
 use crate::io::input::hif::trace::sync_acts_from_pair;
+use crate::io::input::hif::reference::HifDefinitions;
+use crate::io::file_extensions::HIBOU_INTERACTION_FILE_EXTENSION;
 
 
-pub fn parse_hif_string(gen_ctx : &GeneralContext, hif_string : String) -> Result<Interaction,HibouParsingError> {
-    match HifParser::parse(Rule::HIF_PEST_FILE, &hif_string) {
-        Ok( ref mut got_pair ) => {
-            let int_pair = got_pair.next().unwrap();
-            match int_pair.as_rule() {
-                Rule::SD_INTERACTION => {
-                    return parse_interaction(gen_ctx,int_pair);
+// returns the main interaction as well as the positions of the references to named sub-interactions it contains
+// the optional file path is used to resolve imports relative to the location of the parsed file
+// errors found in independent sub-interactions and definitions are all reported
+pub fn parse_hif_string_with_references(gen_ctx : &GeneralContext,
+                                         hif_string : String,
+                                         file_path : Option<&Path>) -> Result<(Interaction,Vec<(Position,String)>),HibouParsingError> {
+    let mut defs = HifDefinitions::new();
+    let base_dir : Option<PathBuf>;
+    match file_path {
+        None => {
+            base_dir = None;
+        },
+        Some( path ) => {
+            if let Ok( canonical ) = path.canonicalize() {
+                defs.visit_file( canonical );
+            }
+            base_dir = path.parent().map(|p| p.to_path_buf());
+        }
+    }
+    let hif_source = Rc::new(hif_string);
+    defs.enter_file(hif_source.clone(), file_path.map(|p| p.display().to_string()));
+    match HifParser::parse(Rule::HIF_PEST_FILE, &hif_source) {
+        Ok( got_pairs ) => {
+            let mut errors : Vec<HibouParsingError> = vec![];
+            let main_int_pair = collect_hif_definitions(&mut defs, &base_dir, got_pairs, &mut errors);
+            // all definitions are resolved, even those which are not used, so as to report errors within them
+            for def_name in defs.get_definition_names() {
+                if let Err(e) = resolve_interaction_reference(gen_ctx,&mut defs,&def_name) {
+                    errors.push(e);
+                }
+            }
+            match main_int_pair {
+                None => {
+                    errors.push( HibouParsingError::OtherDefinitionError("no main interaction found after the named definitions".to_string()) );
                 },
-                _ => {
-                    panic!("what rule then ? : {:?}", int_pair.as_rule() );
+                Some( int_pair ) => {
+                    match parse_interaction(gen_ctx,&mut defs,int_pair) {
+                        Err(e) => {
+                            errors.push(e);
+                        },
+                        Ok( interaction ) => {
+                            if errors.is_empty() {
+                                return Ok( (interaction,defs.into_reference_uses()) );
+                            }
+                        }
+                    }
                 }
             }
+            return Err( HibouParsingError::from_errors(errors) );
         },
         Err(e) => {
-            return Err( HibouParsingError::MatchError(e.to_string()) );
+            return Err( defs.locate_pest_error(e) );
         }
     }
 }
 
+// errors are accumulated so that the parsing of the remaining declarations can go on
+fn collect_hif_definitions<'a>(defs : &mut HifDefinitions,
+                               base_dir : &Option<PathBuf>,
+                               file_pairs : Pairs<'a,Rule>,
+                               errors : &mut Vec<HibouParsingError>) -> Option<Pair<'a,Rule>> {
+    let mut main_int_pair : Option<Pair<Rule>> = None;
+    for pair in file_pairs {
+        match pair.as_rule() {
+            Rule::HIF_IMPORT => {
+                let import_span = pair.as_span();
+                let import_path = pair.into_inner().next().unwrap().as_str();
+                if let Err(e) = import_hif_definitions(defs, base_dir, import_path, errors) {
+                    errors.push( defs.locate(e, &import_span) );
+                }
+            },
+            Rule::HIF_DEFINITION => {
+                let def_span = pair.as_span();
+                let mut def_content = pair.into_inner();
+                let def_name = def_content.next().unwrap().as_str();
+                let def_int_span = def_content.next().unwrap().as_span();
+                if let Err(e) = defs.add_definition(def_name, &def_int_span) {
+                    errors.push( defs.locate(e, &def_span) );
+                }
+            },
+            Rule::SD_INTERACTION => {
+                main_int_pair = Some( pair );
+            },
+            Rule::EOI => {},
+            _ => {
+                panic!("what rule then ? : {:?}", pair.as_rule() );
+            }
+        }
+    }
+    return main_int_pair;
+}
 
+// only the named definitions of an imported file are kept, its main interaction (if any) is ignored
+fn import_hif_definitions(defs : &mut HifDefinitions,
+                          base_dir : &Option<PathBuf>,
+                          import_path : &str,
+                          errors : &mut Vec<HibouParsingError>) -> Result<(),HibouParsingError> {
+    let path : PathBuf;
+    match base_dir {
+        None => {
+            path = PathBuf::from(import_path);
+        },
+        Some( dir ) => {
+            path = dir.join(import_path);
+        }
+    }
+    let file_extension : &str = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    if file_extension != HIBOU_INTERACTION_FILE_EXTENSION {
+        return Err( HibouParsingError::OtherDefinitionError(format!("cannot import '{:}' : expected '.{:}' file", import_path, HIBOU_INTERACTION_FILE_EXTENSION)) );
+    }
+    match path.canonicalize() {
+        Err(e) => {
+            return Err( HibouParsingError::OtherDefinitionError(format!("cannot import '{:}' : {:}", import_path, e)) );
+        },
+        Ok( canonical ) => {
+            if !defs.visit_file(canonical) {
+                // already imported
+                return Ok(());
+            }
+        }
+    }
+    match fs::read_to_string(&path) {
+        Ok( unparsed_hif_str ) => {
+            let imported_source = Rc::new(unparsed_hif_str);
+            defs.enter_file(imported_source.clone(), Some(path.display().to_string()));
+            match HifParser::parse(Rule::HIF_PEST_FILE, &imported_source) {
+                Ok( got_pairs ) => {
+                    let imported_base_dir = path.parent().map(|p| p.to_path_buf());
+                    collect_hif_definitions(defs, &imported_base_dir, got_pairs, errors);
+                },
+                Err(e) => {
+                    errors.push( defs.locate_pest_error(e) );
+                }
+            }
+            defs.exit_file();
+            return Ok(());
+        },
+        Err(e) => {
+            return Err( HibouParsingError::OtherDefinitionError(format!("cannot import '{:}' : {:}", import_path, e)) );
+        }
+    }
+}
+
+fn resolve_interaction_reference(gen_ctx : &GeneralContext,
+                                 defs : &mut HifDefinitions,
+                                 name : &str) -> Result<Interaction,HibouParsingError> {
+    if let Some( interaction ) = defs.get_resolved(name) {
+        return Ok( interaction );
+    }
+    if defs.has_failed(name) {
+        // the errors within that definition have already been reported
+        return Ok( Interaction::Empty );
+    }
+    match defs.start_resolution(name) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( unparsed_int ) => {
+            let parsed : Result<Interaction,HibouParsingError>;
+            match HifParser::parse(Rule::SD_INTERACTION, &unparsed_int) {
+                Ok( ref mut got_pair ) => {
+                    parsed = parse_interaction(gen_ctx,defs,got_pair.next().unwrap());
+                },
+                Err(e) => {
+                    parsed = Err( defs.locate_pest_error(e) );
+                }
+            }
+            match parsed {
+                Err(e) => {
+                    defs.abort_resolution(name);
+                    return Err(e);
+                },
+                Ok( interaction ) => {
+                    defs.end_resolution(name, interaction.clone());
+                    return Ok( interaction );
+                }
+            }
+        }
+    }
+}
+
+// locates the errors which are not located more precisely at the position of the parsed interaction
+fn parse_interaction(gen_ctx : &GeneralContext, defs : &mut HifDefinitions, interaction_pair : Pair<Rule>) -> Result<Interaction,HibouParsingError> {
+    let int_span = interaction_pair.as_span();
+    match parse_interaction_content(gen_ctx,defs,interaction_pair) {
+        Err(e) => {
+            return Err( defs.locate(e, &int_span) );
+        },
+        Ok( interaction ) => {
+            return Ok( interaction );
+        }
+    }
+}
 
-fn parse_interaction(gen_ctx : &GeneralContext, interaction_pair : Pair<Rule>) -> Result<Interaction,HibouParsingError> {
+fn parse_interaction_content(gen_ctx : &GeneralContext, defs : &mut HifDefinitions, interaction_pair : Pair<Rule>) -> Result<Interaction,HibouParsingError> {
     let content_pair = interaction_pair.into_inner().next().unwrap();
     match content_pair.as_rule() {
         Rule::SD_EMPTY_INTERACTION => {
             return Ok( Interaction::Empty );
         },
+        Rule::SD_REF_INT => {
+            let ref_name = content_pair.into_inner().next().unwrap().as_str();
+            defs.record_reference_use(ref_name);
+            return resolve_interaction_reference(gen_ctx,defs,ref_name);
+        },
+        Rule::SD_FORALL_INT => {
+            let mut forall_content = content_pair.into_inner();
+            let op_kind : BinaryOperatorKind;
+            let op_pair = forall_content.next().unwrap().into_inner().next().unwrap();
+            match op_pair.as_rule() {
+                Rule::SD_SEQ => {
+                    op_kind = BinaryOperatorKind::Seq;
+                },
+                Rule::SD_PAR => {
+                    op_kind = BinaryOperatorKind::Par;
+                },
+                _ => {
+                    panic!("what rule then ? : {:?}", op_pair.as_rule() );
+                }
+            }
+            let var_name : String = forall_content.next().unwrap().as_str().to_string();
+            let family_name : String = forall_content.next().unwrap().as_str().to_string();
+            let members : Vec<usize>;
+            match gen_ctx.get_lf_family(&family_name) {
+                None => {
+                    return Err( HibouParsingError::MissingLifelineFamilyDeclarationError(family_name) );
+                },
+                Some( got_members ) => {
+                    members = got_members.clone();
+                }
+            }
+            if gen_ctx.get_lf_id(&var_name).is_some() || gen_ctx.get_lf_family(&var_name).is_some() {
+                return Err( HibouParsingError::OtherDefinitionError( format!("replication variable '{:}' shadows a declared lifeline or lifeline family", var_name) ) );
+            }
+            // the body is parsed once, the variable standing for an additional lifeline
+            // which is then substituted by each member of the family
+            let mut body_ctx = gen_ctx.clone();
+            let var_lf_id = body_ctx.add_lf(var_name);
+            let first_body_ref_use = defs.get_reference_uses_num();
+            match parse_interaction(&body_ctx,defs,forall_content.next().unwrap()) {
+                Err(e) => {
+                    return Err(e);
+                },
+                Ok( body ) => {
+                    let copies_steps : Vec<Vec<u8>> = (0..members.len()).map(|idx| get_nary_operand_steps(idx,members.len())).collect();
+                    defs.replicate_reference_uses(first_body_ref_use,&copies_steps);
+                    let mut sub_ints : Vec<Interaction> = members.iter().map(|lf_id| body.substitute_lifeline(var_lf_id,*lf_id)).collect();
+                    return Ok( fold_interactions_in_binary_operator(&op_kind,&mut sub_ints) );
+                }
+            }
+        },
         Rule::SD_COMMUNICATION_ACTION => {
             return parse_communication_action(gen_ctx,&mut content_pair.into_inner());
         },
         Rule::SD_STRICT_INT => {
-            match get_nary_sub_interactions_from_pair(gen_ctx, content_pair) {
+            match get_nary_sub_interactions_from_pair(gen_ctx,defs,content_pair) {
                 Err(e) => {
                     return Err(e);
                 },
@@ -73,7 +304,7 @@ fn parse_interaction(gen_ctx : &GeneralContext, interaction_pair : Pair<Rule>) -
             }
         },
         Rule::SD_SEQ_INT => {
-            match get_nary_sub_interactions_from_pair(gen_ctx, content_pair) {
+            match get_nary_sub_interactions_from_pair(gen_ctx,defs,content_pair) {
                 Err(e) => {
                     return Err(e);
                 },
@@ -92,7 +323,7 @@ fn parse_interaction(gen_ctx : &GeneralContext, interaction_pair : Pair<Rule>) -
                     return Err(e);
                 },
                 Ok(sync_acts) => {
-                    match get_nary_sub_interactions(gen_ctx, content) {
+                    match get_nary_sub_interactions(gen_ctx,defs,content) {
                         Err(e) => {
                             return Err(e);
                         },
@@ -118,18 +349,18 @@ fn parse_interaction(gen_ctx : &GeneralContext, interaction_pair : Pair<Rule>) -
                         // ***
                         match gen_ctx.get_lf_id( &target_lf_name ) {
                             None => {
-                                return Err( HibouParsingError::MissingLifelineDeclarationError( target_lf_name ) );
+                                return Err( defs.locate(HibouParsingError::MissingLifelineDeclarationError( target_lf_name ), &tar_lf_pair.as_span()) );
                             },
                             Some( tar_lf_id ) => {
                                 if target_lfs.contains(&tar_lf_id) {
-                                    return Err( HibouParsingError::OtherDefinitionError( format!("duplicate lifeline in co-region : {:}", target_lf_name) ) );
+                                    return Err( defs.locate(HibouParsingError::OtherDefinitionError( format!("duplicate lifeline in co-region : {:}", target_lf_name) ), &tar_lf_pair.as_span()) );
                                 } else {
                                     target_lfs.push(tar_lf_id);
                                 }
                             }
                         }
                     }
-                    match get_nary_sub_interactions(gen_ctx, content) {
+                    match get_nary_sub_interactions(gen_ctx,defs,content) {
                         Err(e) => {
                             return Err(e);
                         },
@@ -144,7 +375,7 @@ fn parse_interaction(gen_ctx : &GeneralContext, interaction_pair : Pair<Rule>) -
             }
         },
         Rule::SD_ALT_INT => {
-            match get_nary_sub_interactions_from_pair(gen_ctx, content_pair) {
+            match get_nary_sub_interactions_from_pair(gen_ctx,defs,content_pair) {
                 Err(e) => {
                     return Err(e);
                 },
@@ -154,7 +385,7 @@ fn parse_interaction(gen_ctx : &GeneralContext, interaction_pair : Pair<Rule>) -
             }
         },
         Rule::SD_PAR_INT => {
-            match get_nary_sub_interactions_from_pair(gen_ctx, content_pair) {
+            match get_nary_sub_interactions_from_pair(gen_ctx,defs,content_pair) {
                 Err(e) => {
                     return Err(e);
                 },
@@ -166,7 +397,7 @@ fn parse_interaction(gen_ctx : &GeneralContext, interaction_pair : Pair<Rule>) -
         Rule::SD_LOOP_INT => {
             let mut loop_content = content_pair.into_inner();
             let loop_kind_pair = loop_content.next().unwrap().into_inner().next().unwrap();
-            match parse_interaction(gen_ctx,loop_content.next().unwrap()) {
+            match parse_operand(gen_ctx,defs,loop_content.next().unwrap(),&[1]) {
                 Err(e) => {
                     return Err(e);
                 },
@@ -191,8 +422,98 @@ fn parse_interaction(gen_ctx : &GeneralContext, interaction_pair : Pair<Rule>) -
                 }
             }
         },
+        Rule::SD_BOUNDED_LOOP_INT => {
+            let mut loop_content = content_pair.into_inner();
+            let mut loop_kind = LoopKind::WWeakSeq;
+            let mut bounds : Vec<u32> = vec![];
+            for loop_pair in loop_content.next().unwrap().into_inner() {
+                match loop_pair.as_rule() {
+                    Rule::SD_LOOP_KIND_S => {
+                        loop_kind = LoopKind::SStrictSeq;
+                    },
+                    Rule::SD_LOOP_KIND_H => {
+                        loop_kind = LoopKind::HHeadFirstWS;
+                    },
+                    Rule::SD_LOOP_KIND_W => {
+                        loop_kind = LoopKind::WWeakSeq;
+                    },
+                    Rule::SD_LOOP_KIND_P => {
+                        loop_kind = LoopKind::PInterleaving;
+                    },
+                    Rule::SD_LOOP_BOUNDS => {
+                        for bound_pair in loop_pair.into_inner() {
+                            match bound_pair.as_str().parse::<u32>() {
+                                Ok( bound ) => {
+                                    bounds.push(bound);
+                                },
+                                Err(_) => {
+                                    return Err( HibouParsingError::OtherDefinitionError( format!("loop bound is not a valid integer : {:}", bound_pair.as_str()) ) );
+                                }
+                            }
+                        }
+                    },
+                    _ => {
+                        unreachable!();
+                    }
+                }
+            }
+            let min = *bounds.get(0).unwrap();
+            let max = *bounds.get(1).unwrap();
+            if max == 0 {
+                return Err( HibouParsingError::OtherDefinitionError( "maximum number of loop iterations must be at least 1".to_string() ) );
+            }
+            if min > max {
+                return Err( HibouParsingError::OtherDefinitionError( format!("minimum number of loop iterations {:} is greater than maximum {:}", min, max) ) );
+            }
+            match parse_operand(gen_ctx,defs,loop_content.next().unwrap(),&[1]) {
+                Err(e) => {
+                    return Err(e);
+                },
+                Ok( sub_int ) => {
+                    return Ok( Interaction::BoundedLoop(loop_kind,min,max,Box::new(sub_int)) );
+                }
+            }
+        },
+        Rule::SD_NEG_INT => {
+            match parse_operand(gen_ctx,defs,content_pair.into_inner().next().unwrap(),&[1]) {
+                Err(e) => {
+                    return Err(e);
+                },
+                Ok( sub_int ) => {
+                    return Ok( Interaction::Neg(Box::new(sub_int)) );
+                }
+            }
+        },
+        Rule::SD_ASSERT_INT => {
+            match parse_operand(gen_ctx,defs,content_pair.into_inner().next().unwrap(),&[1]) {
+                Err(e) => {
+                    return Err(e);
+                },
+                Ok( sub_int ) => {
+                    return Ok( Interaction::Assert(Box::new(sub_int)) );
+                }
+            }
+        },
+        Rule::SD_DISRUPT_INT => {
+            let mut disrupt_content = content_pair.into_inner();
+            match parse_operand(gen_ctx,defs,disrupt_content.next().unwrap(),&[1]) {
+                Err(e) => {
+                    return Err(e);
+                },
+                Ok( interrupted_int ) => {
+                    match parse_operand(gen_ctx,defs,disrupt_content.next().unwrap(),&[2]) {
+                        Err(e) => {
+                            return Err(e);
+                        },
+                        Ok( interrupting_int ) => {
+                            return Ok( Interaction::Disrupt(Box::new(interrupted_int),Box::new(interrupting_int)) );
+                        }
+                    }
+                }
+            }
+        },
         Rule::SD_AND_INT => {
-            match get_nary_sub_interactions_from_pair(gen_ctx, content_pair) {
+            match get_nary_sub_interactions_from_pair(gen_ctx,defs,content_pair) {
                 Err(e) => {
                     return Err(e);
                 },
@@ -207,24 +528,49 @@ fn parse_interaction(gen_ctx : &GeneralContext, interaction_pair : Pair<Rule>) -
     }
 }
 
-fn get_nary_sub_interactions_from_pair(gen_ctx : &GeneralContext, sd_content_pair : Pair<Rule>) -> Result<Vec<Interaction>,HibouParsingError> {
+fn get_nary_sub_interactions_from_pair(gen_ctx : &GeneralContext, defs : &mut HifDefinitions, sd_content_pair : Pair<Rule>) -> Result<Vec<Interaction>,HibouParsingError> {
     let mut content = sd_content_pair.into_inner();
     content.next(); // get rid of the operator name
-    return get_nary_sub_interactions(gen_ctx, content);
+    return get_nary_sub_interactions(gen_ctx,defs,content);
+}
+
+// the steps leading from an n-ary operator to its operands once folded into binary operators
+// e.g. "seq(i1,i2,i3)" is folded into "seq(i1,seq(i2,i3))" so that "i2" lies at position "21"
+fn get_nary_operand_steps(idx : usize, num : usize) -> Vec<u8> {
+    let mut steps = vec![2;idx];
+    if idx + 1 < num {
+        steps.push(1);
+    }
+    return steps;
 }
 
-fn get_nary_sub_interactions(gen_ctx : &GeneralContext, content : Pairs<Rule>) -> Result<Vec<Interaction>,HibouParsingError> {
+// parses a sub-interaction which lies at the given steps from the one being parsed
+fn parse_operand(gen_ctx : &GeneralContext, defs : &mut HifDefinitions, interaction_pair : Pair<Rule>, steps : &[u8]) -> Result<Interaction,HibouParsingError> {
+    defs.enter_operand(steps);
+    let parsed = parse_interaction(gen_ctx,defs,interaction_pair);
+    defs.exit_operand(steps);
+    return parsed;
+}
+
+// sub-interactions are independent so that errors in each of them are all reported
+fn get_nary_sub_interactions(gen_ctx : &GeneralContext, defs : &mut HifDefinitions, content : Pairs<Rule>) -> Result<Vec<Interaction>,HibouParsingError> {
     let mut sub_ints : Vec<Interaction> = Vec::new();
-    for sub_interaction in content {
-        match parse_interaction(gen_ctx,sub_interaction) {
+    let mut errors : Vec<HibouParsingError> = Vec::new();
+    let sub_pairs : Vec<Pair<Rule>> = content.collect();
+    let num = sub_pairs.len();
+    for (idx,sub_interaction) in sub_pairs.into_iter().enumerate() {
+        match parse_operand(gen_ctx,defs,sub_interaction,&get_nary_operand_steps(idx,num)) {
             Err(e) => {
-                return Err(e);
+                errors.push(e);
             },
             Ok( parsed_sub_int ) => {
                 sub_ints.push( parsed_sub_int );
             }
         }
     }
+    if !errors.is_empty() {
+        return Err( HibouParsingError::from_errors(errors) );
+    }
     return Ok( sub_ints );
 }
 
@@ -274,3 +620,130 @@ fn fold_interactions_in_binary_operator(op_kind : &BinaryOperatorKind, sub_ints
 
 
 
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::value::ArgumentType;
+    use crate::io::input::error::HibouParsingError;
+    use crate::core::language::position::sub_interaction::get_sub_interaction_at_position;
+    use crate::io::input::hif::interaction::parse_hif_string_with_references;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::util::test_fixtures::{context_with, message};
+
+    fn unlocated(error : HibouParsingError) -> HibouParsingError {
+        match error {
+            HibouParsingError::Located(err,_) => {
+                return unlocated(*err);
+            },
+            _ => {
+                return error;
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_range_integer_argument_is_a_located_error() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("a".to_string());
+        gen_ctx.add_lf("b".to_string());
+        let ms_id = gen_ctx.add_msg("OK".to_string());
+        gen_ctx.set_ms_arg_types(ms_id,vec![ArgumentType::Integer]);
+        let parsed = parse_hif_string_with_references(&gen_ctx,"a -- OK(99999999999999999999) -> b".to_string(),None);
+        match parsed {
+            Err( HibouParsingError::Located(err,loc) ) => {
+                assert!(matches!(*err, HibouParsingError::MessageArgumentError(_)));
+                assert_eq!(loc.column, 9);
+            },
+            other => {
+                panic!("unexpected parsing result : {:?}", other);
+            }
+        }
+        assert!(parse_hif_string_with_references(&gen_ctx,"a -- OK(-42) -> b".to_string(),None).is_ok());
+    }
+
+    #[test]
+    fn references_are_located_where_they_occur() {
+        let mut gen_ctx = context_with(&["a","b","c1","c2"],&["m1","m2"]);
+        gen_ctx.add_lf_family("c".to_string(),vec![2,3]);
+        let hif = "@def ping = a -- m1 -> b\n\
+                   @def twice = seq(ref(ping),ref(ping))\n\
+                   seq(a -- m1 -> b, ref(twice), loopS(ref(ping)), par forall x in c: seq(x -- m2 -> a, ref(ping)))";
+        let (int,references) = parse_hif_string_with_references(&gen_ctx,hif.to_string(),None).unwrap();
+        // the first message is identical to "ping" but is not a reference
+        // and the references within "twice" are hidden by the collapsed use of "twice"
+        let located : Vec<(String,String)> = references.iter().map(|(pos,name)| (pos.to_string(),name.clone())).collect();
+        assert_eq!(located, vec![("21".to_string(),"twice".to_string()),
+                                 ("2211".to_string(),"ping".to_string()),
+                                 ("22212".to_string(),"ping".to_string()),
+                                 ("22222".to_string(),"ping".to_string())]);
+        for (pos,_) in &references {
+            assert!(get_sub_interaction_at_position(&int,pos).is_some());
+        }
+        assert_eq!(get_sub_interaction_at_position(&int,&references[1].0), Some(&message(0,0,1)));
+    }
+
+    #[test]
+    fn references_are_replaced_by_their_definitions() {
+        let gen_ctx = context_with(&["a","b"],&["m1","m2"]);
+        // definitions may refer to those which are declared after them
+        let hif = "@def twice = seq(ref(ping),ref(ping))\n\
+                   @def ping = a -- m1 -> b\n\
+                   strict(ref(twice), b -- m2 -> a)";
+        let (int,_) = parse_hif_string_with_references(&gen_ctx,hif.to_string(),None).unwrap();
+        let twice = Interaction::Seq(Box::new(message(0,0,1)),Box::new(message(0,0,1)));
+        assert_eq!(int, Interaction::Strict(Box::new(twice),Box::new(message(1,1,0))));
+    }
+
+    #[test]
+    fn cyclic_references_are_rejected() {
+        let gen_ctx = context_with(&["a","b"],&["m1"]);
+        let hif = "@def ping = seq(a -- m1 -> b, ref(pong))\n\
+                   @def pong = ref(ping)\n\
+                   ref(ping)";
+        match parse_hif_string_with_references(&gen_ctx,hif.to_string(),None).map_err(unlocated) {
+            Err( HibouParsingError::CyclicInteractionDefinitionError(cycle) ) => {
+                assert_eq!(cycle, "ping -> pong -> ping");
+            },
+            other => {
+                panic!("unexpected parsing result : {:?}", other);
+            }
+        }
+        let parsed = parse_hif_string_with_references(&gen_ctx,"ref(pong)".to_string(),None).map_err(unlocated);
+        assert!(matches!(parsed, Err(HibouParsingError::MissingInteractionDefinitionError(_))));
+    }
+
+    #[test]
+    fn definitions_are_imported_relatively_to_the_importing_file() {
+        let gen_ctx = context_with(&["a","b"],&["m1"]);
+        let dir = std::env::temp_dir().join(format!("hibou_hif_import_{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("lib")).unwrap();
+        std::fs::write(dir.join("lib").join("common.hif"), "@def ping = a -- m1 -> b\nb -- m1 -> a").unwrap();
+        let main_path = dir.join("main.hif");
+        let hif = "@import \"lib/common.hif\"\nseq(ref(ping),ref(ping))";
+        let parsed = parse_hif_string_with_references(&gen_ctx,hif.to_string(),Some(main_path.as_path()));
+        std::fs::remove_dir_all(&dir).unwrap();
+        let (int,_) = parsed.unwrap();
+        // the main interaction of the imported file is ignored
+        assert_eq!(int, Interaction::Seq(Box::new(message(0,0,1)),Box::new(message(0,0,1))));
+    }
+
+    #[test]
+    fn replications_are_expanded_over_the_members_of_the_family() {
+        let mut gen_ctx = context_with(&["server","client_1","client_2","client_3"],&["req"]);
+        gen_ctx.add_lf_family("client".to_string(),vec![1,2,3]);
+        // seq(client_1 -- req -> server, seq(client_2 -- req -> server, client_3 -- req -> server))
+        let (int,_) = parse_hif_string_with_references(&gen_ctx,"seq forall c in client: c -- req -> server".to_string(),None).unwrap();
+        let expected = Interaction::Seq(Box::new(message(1,0,0)),
+                                        Box::new(Interaction::Seq(Box::new(message(2,0,0)),
+                                                                  Box::new(message(3,0,0)))));
+        assert_eq!(int, expected);
+        // the variable cannot shadow a declared lifeline
+        let parsed = parse_hif_string_with_references(&gen_ctx,"par forall server in client: server -- req ->|".to_string(),None).map_err(unlocated);
+        assert!(matches!(parsed, Err(HibouParsingError::OtherDefinitionError(_))));
+        let parsed = parse_hif_string_with_references(&gen_ctx,"par forall c in server: c -- req ->|".to_string(),None).map_err(unlocated);
+        assert!(matches!(parsed, Err(HibouParsingError::MissingLifelineFamilyDeclarationError(_))));
+    }
+}
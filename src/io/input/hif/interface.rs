@@ -21,22 +21,47 @@ use std::path::Path;
 
 
 use crate::core::general_context::GeneralContext;
+use crate::core::language::position::position::Position;
 use crate::core::language::syntax::interaction::Interaction;
 use crate::io::input::error::HibouParsingError;
-use crate::io::input::hif::interaction::parse_hif_string;
+use crate::io::input::hif::interaction::parse_hif_string_with_references;
 
-use crate::io::file_extensions::{HIBOU_INTERACTION_FILE_EXTENSION};
+use crate::io::input::json::interface::parse_json_interaction_file;
+use crate::io::file_extensions::{HIBOU_INTERACTION_FILE_EXTENSION, HIBOU_JSON_FILE_EXTENSION};
 
 
 pub fn parse_hif_file(gen_ctx : &GeneralContext, file_path : &str) -> Result<Interaction,HibouParsingError> {
+    match parse_hif_file_with_references(gen_ctx,file_path) {
+        Ok( (interaction,_) ) => {
+            return Ok( interaction );
+        },
+        Err(e) => {
+            return Err(e);
+        }
+    }
+}
+
+// also returns the positions in the interaction of the references to named sub-interactions (declared with "@def" or imported)
+pub fn parse_hif_file_with_references(gen_ctx : &GeneralContext, file_path : &str) -> Result<(Interaction,Vec<(Position,String)>),HibouParsingError> {
     let path_object = Path::new(file_path);
     let file_extension : &str = path_object.extension().unwrap().to_str().unwrap();
+    if file_extension == HIBOU_JSON_FILE_EXTENSION {
+        // there are no named sub-interactions in the JSON representation
+        match parse_json_interaction_file(gen_ctx,file_path) {
+            Err(e) => {
+                return Err(e);
+            },
+            Ok( interaction ) => {
+                return Ok( (interaction,vec![]) );
+            }
+        }
+    }
     if file_extension != HIBOU_INTERACTION_FILE_EXTENSION {
         return Err( HibouParsingError::FileFormatError(file_extension.to_string(),HIBOU_INTERACTION_FILE_EXTENSION.to_string()));
     }
     match fs::read_to_string(file_path) {
         Ok( unparsed_hif_str ) => {
-            return parse_hif_string(gen_ctx,unparsed_hif_str);
+            return parse_hif_string_with_references(gen_ctx,unparsed_hif_str,Some(path_object));
         },
         Err(e) => {
             return Err( HibouParsingError::FileError(e.to_string()) );
@@ -18,4 +18,6 @@ mod parser;
 mod action;
 mod trace;
 mod interaction;
+mod reference;
 pub mod interface;
+pub mod format;
@@ -0,0 +1,222 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+use std::rc::Rc;
+
+use pest::{RuleType, Span};
+
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::location::SourceLocation;
+
+
+// a text being parsed : either a whole .hif file or the body of a named definition
+// which starts at a given offset of the file in which it is declared
+struct HifSource {
+    text : Rc<String>,
+    base_offset : usize,
+    file_path : Option<String>
+}
+
+struct HifUnparsedDefinition {
+    source : Rc<String>,
+    start : usize,
+    end : usize,
+    file_path : Option<String>
+}
+
+// named sub-interactions declared with "@def" (possibly in imported files)
+// are kept unparsed until they are resolved
+// resolution is done at most once per name and is memoized
+pub struct HifDefinitions {
+    unparsed : HashMap<String,HifUnparsedDefinition>,
+    resolved : BTreeMap<String,Interaction>,
+    // names the resolution of which failed, so that their errors are reported only once
+    failed : HashSet<String>,
+    // stack of the names being currently resolved, used to detect cyclic references
+    resolving : Vec<String>,
+    // stack of the texts being currently parsed, used to locate errors
+    sources : Vec<HifSource>,
+    // files already visited, so that a same file is not imported twice
+    visited_files : HashSet<PathBuf>,
+    // position of the sub-interaction being parsed within the main interaction
+    // as a sequence of 1 (left operand) and 2 (right operand)
+    path : Vec<u8>,
+    // positions of the references which occur in the main interaction (and not within definitions)
+    reference_uses : Vec<(Vec<u8>,String)>
+}
+
+impl HifDefinitions {
+
+    pub fn new() -> HifDefinitions {
+        return HifDefinitions{unparsed:HashMap::new(),
+            resolved:BTreeMap::new(),
+            failed:HashSet::new(),
+            resolving:Vec::new(),
+            sources:Vec::new(),
+            visited_files:HashSet::new(),
+            path:Vec::new(),
+            reference_uses:Vec::new()};
+    }
+
+    pub fn enter_file(&mut self, text : Rc<String>, file_path : Option<String>) {
+        self.sources.push( HifSource{text,base_offset:0,file_path} );
+    }
+
+    pub fn exit_file(&mut self) {
+        self.sources.pop();
+    }
+
+    fn current_location(&self, offset : usize, len : usize) -> SourceLocation {
+        let source = self.sources.last().unwrap();
+        let mut location = SourceLocation::new(&source.text, source.base_offset + offset, len);
+        location.file_path = source.file_path.clone();
+        return location;
+    }
+
+    // attaches to an error the position of the element being parsed in the current text
+    pub fn locate(&self, error : HibouParsingError, span : &Span) -> HibouParsingError {
+        return error.located_at( self.current_location(span.start(), span.end() - span.start()) );
+    }
+
+    pub fn locate_pest_error<R : RuleType>(&self, error : pest::error::Error<R>) -> HibouParsingError {
+        let (offset,len) = HibouParsingError::pest_error_span(&error);
+        let message = error.variant.message().to_string();
+        return HibouParsingError::MatchError(message).located_at( self.current_location(offset,len) );
+    }
+
+    pub fn add_definition(&mut self, name : &str, def_span : &Span) -> Result<(),HibouParsingError> {
+        if self.unparsed.contains_key(name) {
+            return Err( HibouParsingError::OtherDefinitionError(format!("interaction '{:}' is defined more than once", name)) );
+        }
+        let source = self.sources.last().unwrap();
+        let definition = HifUnparsedDefinition{source:source.text.clone(),
+            start:def_span.start(),
+            end:def_span.end(),
+            file_path:source.file_path.clone()};
+        self.unparsed.insert( name.to_string(), definition );
+        return Ok(());
+    }
+
+    // returns false if the file had already been visited
+    pub fn visit_file(&mut self, file_path : PathBuf) -> bool {
+        return self.visited_files.insert(file_path);
+    }
+
+    pub fn get_definition_names(&self) -> Vec<String> {
+        let mut names : Vec<String> = self.unparsed.keys().cloned().collect();
+        names.sort();
+        return names;
+    }
+
+    pub fn get_resolved(&self, name : &str) -> Option<Interaction> {
+        return self.resolved.get(name).cloned();
+    }
+
+    pub fn has_failed(&self, name : &str) -> bool {
+        return self.failed.contains(name);
+    }
+
+    // marks the start of the resolution of a given name
+    // returns the unparsed interaction associated to that name
+    pub fn start_resolution(&mut self, name : &str) -> Result<String,HibouParsingError> {
+        if self.resolving.iter().any(|n| n == name) {
+            let mut cycle = self.resolving.clone();
+            cycle.push( name.to_string() );
+            return Err( HibouParsingError::CyclicInteractionDefinitionError(cycle.join(" -> ")) );
+        }
+        match self.unparsed.get(name) {
+            None => {
+                return Err( HibouParsingError::MissingInteractionDefinitionError(name.to_string()) );
+            },
+            Some( definition ) => {
+                let unparsed_int = definition.source[definition.start..definition.end].to_string();
+                let def_source = HifSource{text:definition.source.clone(),
+                    base_offset:definition.start,
+                    file_path:definition.file_path.clone()};
+                self.resolving.push( name.to_string() );
+                self.sources.push( def_source );
+                return Ok( unparsed_int );
+            }
+        }
+    }
+
+    pub fn end_resolution(&mut self, name : &str, interaction : Interaction) {
+        self.pop_resolution(name);
+        self.resolved.insert( name.to_string(), interaction );
+    }
+
+    pub fn abort_resolution(&mut self, name : &str) {
+        self.pop_resolution(name);
+        self.failed.insert( name.to_string() );
+    }
+
+    fn pop_resolution(&mut self, name : &str) {
+        let popped = self.resolving.pop();
+        assert_eq!(popped.as_deref(), Some(name));
+        self.sources.pop();
+    }
+
+    pub fn enter_operand(&mut self, steps : &[u8]) {
+        self.path.extend_from_slice(steps);
+    }
+
+    pub fn exit_operand(&mut self, steps : &[u8]) {
+        self.path.truncate(self.path.len() - steps.len());
+    }
+
+    pub fn record_reference_use(&mut self, name : &str) {
+        if self.resolving.is_empty() {
+            self.reference_uses.push( (self.path.clone(), name.to_string()) );
+        }
+    }
+
+    pub fn get_reference_uses_num(&self) -> usize {
+        return self.reference_uses.len();
+    }
+
+    // the uses recorded from the given index on, which lie within a sub-interaction at the current path,
+    // are replaced by one copy for each copy of that sub-interaction, with the given steps leading to it
+    pub fn replicate_reference_uses(&mut self, from : usize, copies_steps : &[Vec<u8>]) {
+        let prefix_len = self.path.len();
+        let replicated : Vec<(Vec<u8>,String)> = self.reference_uses.split_off(from);
+        for steps in copies_steps {
+            for (path,name) in &replicated {
+                let mut copy_path = path[..prefix_len].to_vec();
+                copy_path.extend_from_slice(steps);
+                copy_path.extend_from_slice(&path[prefix_len..]);
+                self.reference_uses.push( (copy_path,name.clone()) );
+            }
+        }
+    }
+
+    pub fn into_reference_uses(self) -> Vec<(Position,String)> {
+        return self.reference_uses.into_iter().map(|(path,name)| {
+            let position = path.iter().rev().fold(Position::Epsilon(None), |pos,step| {
+                if *step == 1 {
+                    Position::Left(Box::new(pos))
+                } else {
+                    Position::Right(Box::new(pos))
+                }
+            });
+            (position,name)
+        }).collect();
+    }
+
+}
@@ -111,5 +111,5 @@ fn trace_action_from_text(gen_ctx : &GeneralContext,
         }
     }
     // ***
-    return Ok( TraceAction{lf_id:got_lf_id,act_kind,ms_id:got_ms_id} );
+    return Ok( TraceAction::new(got_lf_id,act_kind,got_ms_id,vec![]) );
 }
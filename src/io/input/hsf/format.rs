@@ -0,0 +1,100 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use pest::iterators::Pair;
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::hsf::parser::{HsfParser,Rule};
+
+use crate::io::input::comments::{CommentCursor, comments_as_inline_prefix};
+use crate::io::input::error::HibouParsingError;
+
+
+// each section is written on a single line
+pub fn format_hsf_string(hsf_string : &str, keep_comments : bool) -> Result<String,HibouParsingError> {
+    match HsfParser::parse(Rule::HSF_PEST_FILE, hsf_string) {
+        Err(e) => {
+            return Err( HibouParsingError::from_pest_error(e, hsf_string) );
+        },
+        Ok( ref mut got_pairs ) => {
+            let mut cursor = CommentCursor::new(hsf_string, keep_comments);
+            let signature_pair = got_pairs.next().unwrap();
+            let mut lines : Vec<String> = vec![];
+            for section_pair in signature_pair.into_inner() {
+                lines.extend( cursor.take_until(section_pair.as_span().start()) );
+                let section_end = section_pair.as_span().end();
+                let section_name = match section_pair.as_rule() {
+                    Rule::HIBOU_SIG_MS_DECL => {
+                        "@message"
+                    },
+                    Rule::HIBOU_SIG_LF_DECL => {
+                        "@lifeline"
+                    },
+                    Rule::HIBOU_SIG_GT_DECL => {
+                        "@gate"
+                    },
+                    _ => {
+                        panic!("what rule then ? : {:?}", section_pair.as_rule() );
+                    }
+                };
+                let mut items : Vec<String> = vec![];
+                for item_pair in section_pair.into_inner() {
+                    let prefix = comments_as_inline_prefix(&cursor.take_through(&item_pair));
+                    items.push( format!("{}{}", prefix, format_signature_item(item_pair)) );
+                }
+                let suffix = cursor.take_until(section_end);
+                if suffix.is_empty() {
+                    lines.push( format!("{}{{{}}}", section_name, items.join(";")) );
+                } else {
+                    lines.push( format!("{}{{{}; {}}}", section_name, items.join(";"), suffix.join(" ")) );
+                }
+            }
+            lines.extend( cursor.take_until(hsf_string.len()) );
+            return Ok( format!("{}\n", lines.join("\n")) );
+        }
+    }
+}
+
+fn format_signature_item(item_pair : Pair<Rule>) -> String {
+    match item_pair.as_rule() {
+        Rule::HIBOU_LABEL => {
+            return item_pair.as_str().to_string();
+        },
+        Rule::HIBOU_SIG_MS => {
+            let mut contents = item_pair.into_inner();
+            let ms_name = contents.next().unwrap().as_str();
+            let arg_types : Vec<&str> = contents.map(|p| p.as_str()).collect();
+            if arg_types.is_empty() {
+                return ms_name.to_string();
+            } else {
+                return format!("{}({})", ms_name, arg_types.join(","));
+            }
+        },
+        Rule::HIBOU_SIG_LF_FAMILY => {
+            let mut contents = item_pair.into_inner();
+            let family_name = contents.next().unwrap().as_str();
+            let min = contents.next().unwrap().as_str();
+            let max = contents.next().unwrap().as_str();
+            return format!("{}[{}..{}]", family_name, min, max);
+        },
+        _ => {
+            panic!("what rule then ? : {:?}", item_pair.as_rule() );
+        }
+    }
+}
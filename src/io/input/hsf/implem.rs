@@ -19,6 +19,7 @@ limitations under the License.
 
 use pest::iterators::Pair;
 use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::value::ArgumentType;
 use crate::io::input::error::HibouParsingError;
 
 #[allow(unused_imports)]
@@ -41,7 +42,7 @@ pub fn parse_hsf_string(hsf_string : String) -> Result<GeneralContext,HibouParsi
             }
         },
         Err(e) => {
-            return Err( HibouParsingError::MatchError(e.to_string()) );
+            return Err( HibouParsingError::from_pest_error(e, &hsf_string) );
         }
     }
 }
@@ -56,26 +57,30 @@ fn parse_signature(signature_pair : Pair<Rule>) -> Result<GeneralContext,HibouPa
     let mut contents = signature_pair.into_inner();
     // ***
     let mut gen_ctx = GeneralContext::new();
+    let mut errors : Vec<HibouParsingError> = Vec::new();
     // ***
     while let Some(current_pair) = contents.next() {
         match current_pair.as_rule() {
             Rule::HIBOU_SIG_MS_DECL => {
                 if got_section_messages {
-                    return Err( HibouParsingError::HsfSetupError("several '@message' sections declared".to_string()));
+                    errors.push( HibouParsingError::HsfSetupError("several '@message' sections declared".to_string()).locate(&current_pair.as_span()) );
+                    continue;
                 }
                 got_section_messages = true;
                 parse_message_decl(current_pair,&mut gen_ctx);
             },
             Rule::HIBOU_SIG_LF_DECL => {
                 if got_section_lifelines {
-                    return Err( HibouParsingError::HsfSetupError("several '@lifeline' sections declared".to_string()));
+                    errors.push( HibouParsingError::HsfSetupError("several '@lifeline' sections declared".to_string()).locate(&current_pair.as_span()) );
+                    continue;
                 }
                 got_section_lifelines = true;
-                parse_lifeline_decl(current_pair,&mut gen_ctx);
+                errors.extend( parse_lifeline_decl(current_pair,&mut gen_ctx) );
             },
             Rule::HIBOU_SIG_GT_DECL => {
                 if got_section_gates {
-                    return Err( HibouParsingError::HsfSetupError("several '@gate' sections declared".to_string()));
+                    errors.push( HibouParsingError::HsfSetupError("several '@gate' sections declared".to_string()).locate(&current_pair.as_span()) );
+                    continue;
                 }
                 got_section_gates = true;
                 parse_gate_decl(current_pair,&mut gen_ctx);
@@ -86,22 +91,102 @@ fn parse_signature(signature_pair : Pair<Rule>) -> Result<GeneralContext,HibouPa
         }
     }
     // ***
+    if !errors.is_empty() {
+        return Err( HibouParsingError::from_errors(errors) );
+    }
     return Ok(gen_ctx);
 }
 
 
 fn parse_message_decl(ms_decl_pair : Pair<Rule>, gen_ctx : &mut GeneralContext ) {
     for ms_pair in ms_decl_pair.into_inner() {
-        let ms_name : String = ms_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
-        gen_ctx.add_msg(ms_name);
+        let mut ms_contents = ms_pair.into_inner();
+        let ms_name : String = ms_contents.next().unwrap().as_str().to_string();
+        let ms_id = gen_ctx.add_msg(ms_name);
+        let mut arg_types : Vec<ArgumentType> = Vec::new();
+        for arg_pair in ms_contents {
+            match arg_pair.as_rule() {
+                Rule::HIBOU_SIG_MS_ARG_INT => {
+                    arg_types.push(ArgumentType::Integer);
+                },
+                Rule::HIBOU_SIG_MS_ARG_STR => {
+                    arg_types.push(ArgumentType::String);
+                },
+                Rule::HIBOU_SIG_MS_ARG_BOOL => {
+                    arg_types.push(ArgumentType::Boolean);
+                },
+                _ => {
+                    panic!("what rule then ? : {:?}", arg_pair.as_rule() );
+                }
+            }
+        }
+        gen_ctx.set_ms_arg_types(ms_id, arg_types);
     }
 }
 
-fn parse_lifeline_decl(lf_decl_pair : Pair<Rule>, gen_ctx : &mut GeneralContext ) {
+// returns the errors found in independent lifeline declarations
+fn parse_lifeline_decl(lf_decl_pair : Pair<Rule>, gen_ctx : &mut GeneralContext ) -> Vec<HibouParsingError> {
+    let mut errors : Vec<HibouParsingError> = Vec::new();
     for lf_pair in lf_decl_pair.into_inner() {
-        let lf_name : String = lf_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
-        gen_ctx.add_lf(lf_name);
+        let lf_span = lf_pair.as_span();
+        match lf_pair.as_rule() {
+            Rule::HIBOU_LABEL => {
+                let lf_name : String = lf_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
+                if gen_ctx.get_lf_family(&lf_name).is_some() {
+                    errors.push( HibouParsingError::HsfSetupError(format!("'{}' is declared both as a lifeline and as a lifeline family", lf_name)).locate(&lf_span) );
+                } else {
+                    gen_ctx.add_lf(lf_name);
+                }
+            },
+            Rule::HIBOU_SIG_LF_FAMILY => {
+                match parse_lifeline_family(lf_pair,gen_ctx) {
+                    Err(e) => {
+                        errors.push( e.locate(&lf_span) );
+                    },
+                    Ok(_) => {}
+                }
+            },
+            _ => {
+                panic!("what rule then ? : {:?}", lf_pair.as_rule() );
+            }
+        }
     }
+    return errors;
+}
+
+// a family "client[1..3]" declares lifelines "client_1", "client_2" and "client_3"
+fn parse_lifeline_family(family_pair : Pair<Rule>, gen_ctx : &mut GeneralContext ) -> Result<(),HibouParsingError> {
+    let mut contents = family_pair.into_inner();
+    let family_name : String = contents.next().unwrap().as_str().to_string();
+    let min_str = contents.next().unwrap().as_str();
+    let max_str = contents.next().unwrap().as_str();
+    let min : u32;
+    let max : u32;
+    match (min_str.parse::<u32>(), max_str.parse::<u32>()) {
+        (Ok(got_min),Ok(got_max)) => {
+            min = got_min;
+            max = got_max;
+        },
+        _ => {
+            return Err( HibouParsingError::HsfSetupError(format!("invalid bounds for lifeline family '{}'", family_name)));
+        }
+    }
+    if min > max {
+        return Err( HibouParsingError::HsfSetupError(format!("empty range for lifeline family '{}' : {}..{}", family_name, min, max)));
+    }
+    if gen_ctx.get_lf_family(&family_name).is_some() || gen_ctx.get_lf_id(&family_name).is_some() {
+        return Err( HibouParsingError::HsfSetupError(format!("'{}' is declared several times", family_name)));
+    }
+    let mut members : Vec<usize> = Vec::new();
+    for idx in min..=max {
+        let lf_name = format!("{}_{}", family_name, idx);
+        if gen_ctx.get_lf_id(&lf_name).is_some() {
+            return Err( HibouParsingError::HsfSetupError(format!("lifeline '{}' of family '{}' is already declared", lf_name, family_name)));
+        }
+        members.push( gen_ctx.add_lf(lf_name) );
+    }
+    gen_ctx.add_lf_family(family_name, members);
+    return Ok(());
 }
 
 fn parse_gate_decl(gt_decl_pair : Pair<Rule>, gen_ctx : &mut GeneralContext ) {
@@ -109,4 +194,21 @@ fn parse_gate_decl(gt_decl_pair : Pair<Rule>, gen_ctx : &mut GeneralContext ) {
         let gt_name : String = gt_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
         gen_ctx.add_gt(gt_name);
     }
-}
\ No newline at end of file
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lifeline_families_declare_numbered_lifelines() {
+        let gen_ctx = parse_hsf_string("@message{req}\n@lifeline{server;client[1..3]}".to_string()).unwrap();
+        let members = gen_ctx.get_lf_family("client").unwrap();
+        let names : Vec<String> = members.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+        assert_eq!(names, vec!["client_1".to_string(),"client_2".to_string(),"client_3".to_string()]);
+        assert!(parse_hsf_string("@message{req}\n@lifeline{client[3..1]}".to_string()).is_err());
+        assert!(parse_hsf_string("@message{req}\n@lifeline{client_1;client[1..3]}".to_string()).is_err());
+    }
+}
@@ -19,20 +19,31 @@ limitations under the License.
 use std::fs;
 use std::path::Path;
 use crate::core::general_context::GeneralContext;
-use crate::io::file_extensions::HIBOU_SIGNATURE_FILE_EXTENSION;
+use crate::io::file_extensions::{HIBOU_JSON_FILE_EXTENSION, HIBOU_SIGNATURE_FILE_EXTENSION};
 use crate::io::input::error::HibouParsingError;
 
 use crate::io::input::hsf::implem::parse_hsf_string;
+use crate::io::input::json::interface::parse_json_context_file;
 
 pub fn parse_hsf_file(file_path : &str) -> Result<GeneralContext,HibouParsingError> {
     let path_object = Path::new(file_path);
     let file_extension : &str = path_object.extension().unwrap().to_str().unwrap();
+    if file_extension == HIBOU_JSON_FILE_EXTENSION {
+        return parse_json_context_file(file_path);
+    }
     if file_extension != HIBOU_SIGNATURE_FILE_EXTENSION {
         return Err( HibouParsingError::FileFormatError(file_extension.to_string(),HIBOU_SIGNATURE_FILE_EXTENSION.to_string()));
     }
     match fs::read_to_string(file_path) {
         Ok( unparsed_hsf_str ) => {
-            return parse_hsf_string(unparsed_hsf_str);
+            match parse_hsf_string(unparsed_hsf_str) {
+                Err(e) => {
+                    return Err( e.with_file_path(file_path) );
+                },
+                Ok( gen_ctx ) => {
+                    return Ok( gen_ctx );
+                }
+            }
         },
         Err(e) => {
             return Err( HibouParsingError::FileError(e.to_string()) );
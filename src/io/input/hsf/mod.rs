@@ -17,4 +17,5 @@ limitations under the License.
 
 mod parser;
 mod implem;
-pub mod interface;
\ No newline at end of file
+pub mod interface;
+pub mod format;
@@ -0,0 +1,119 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use pest::iterators::Pair;
+
+#[allow(unused_imports)]
+use pest::Parser;
+#[allow(unused_imports)]
+use crate::io::input::htf::parser::{HtfParser,Rule};
+
+use crate::io::input::comments::{CommentCursor, comments_as_inline_prefix};
+use crate::io::input::error::HibouParsingError;
+
+
+// the layout is that of the trace writers : one canal per line
+pub fn format_htf_string(htf_string : &str, keep_comments : bool) -> Result<String,HibouParsingError> {
+    match HtfParser::parse(Rule::HTF_PEST_FILE, htf_string) {
+        Err(e) => {
+            return Err( HibouParsingError::from_pest_error(e, htf_string) );
+        },
+        Ok( ref mut got_pairs ) => {
+            let mut cursor = CommentCursor::new(htf_string, keep_comments);
+            let content_pair = got_pairs.next().unwrap().into_inner().next().unwrap();
+            let mut lines : Vec<String> = vec![];
+            match content_pair.as_rule() {
+                Rule::TRACE_SEQUENCE => {
+                    lines.push( format_trace_sequence(content_pair, &mut cursor) );
+                },
+                Rule::MULTI_TRACE => {
+                    let multi_trace_end = content_pair.as_span().end();
+                    let mut canals : Vec<String> = vec![];
+                    for canal_pair in content_pair.into_inner() {
+                        let mut canal_lines = cursor.take_until(canal_pair.as_span().start());
+                        let mut contents = canal_pair.into_inner();
+                        let lifelines_pair = contents.next().unwrap();
+                        let lifelines = match lifelines_pair.as_rule() {
+                            Rule::CANAL_LIFELINES_spec => {
+                                let lf_names : Vec<&str> = lifelines_pair.into_inner().map(|p| p.as_str()).collect();
+                                lf_names.join(",")
+                            },
+                            _ => {
+                                lifelines_pair.as_str().to_string()
+                            }
+                        };
+                        let sequence = format_trace_sequence(contents.next().unwrap(), &mut cursor);
+                        if sequence.is_empty() {
+                            canal_lines.push( format!("[{}]", lifelines) );
+                        } else {
+                            canal_lines.push( format!("[{}] {}", lifelines, sequence) );
+                        }
+                        canals.push( canal_lines.join("\n") );
+                    }
+                    let mut trailing = cursor.take_until(multi_trace_end);
+                    lines.push( "{".to_string() );
+                    if !canals.is_empty() {
+                        lines.push( canals.join(";\n") );
+                    }
+                    lines.append( &mut trailing );
+                    lines.push( "}".to_string() );
+                },
+                _ => {
+                    panic!("what rule then ? : {:?}", content_pair.as_rule() );
+                }
+            }
+            lines.extend( cursor.take_until(htf_string.len()) );
+            return Ok( format!("{}\n", lines.join("\n")) );
+        }
+    }
+}
+
+fn format_trace_sequence(sequence_pair : Pair<Rule>, cursor : &mut CommentCursor) -> String {
+    let mut elements : Vec<String> = vec![];
+    for element_pair in sequence_pair.into_inner() {
+        let prefix = comments_as_inline_prefix(&cursor.take_through(&element_pair));
+        match element_pair.as_rule() {
+            Rule::TRACE_ACTION => {
+                elements.push( format!("{}{}", prefix, format_trace_action(element_pair)) );
+            },
+            Rule::TRACE_ACTION_SET => {
+                let actions : Vec<String> = element_pair.into_inner().map(|p| format_trace_action(p)).collect();
+                elements.push( format!("{}{{{}}}", prefix, actions.join(",")) );
+            },
+            _ => {
+                panic!("what rule then ? : {:?}", element_pair.as_rule() );
+            }
+        }
+    }
+    return elements.join(".");
+}
+
+fn format_trace_action(action_pair : Pair<Rule>) -> String {
+    let mut action = "".to_string();
+    for part in action_pair.into_inner() {
+        match part.as_rule() {
+            Rule::TRACE_MESSAGE_ARGS => {
+                let args : Vec<&str> = part.into_inner().map(|p| p.as_str()).collect();
+                action.push_str( &format!("({})", args.join(",")) );
+            },
+            _ => {
+                action.push_str( part.as_str() );
+            }
+        }
+    }
+    return action;
+}
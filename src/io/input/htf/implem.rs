@@ -39,7 +39,7 @@ pub fn multitrace_from_text(gen_ctx : &GeneralContext,
                             multitrace_str : &String) -> Result<(CoLocalizations,MultiTrace),HibouParsingError> {
     match HtfParser::parse(Rule::HTF_PEST_FILE, multitrace_str) {
         Err(e) => {
-            return Err( HibouParsingError::MatchError(e.to_string()) );
+            return Err( HibouParsingError::from_pest_error(e, multitrace_str) );
         },
         Ok( ref mut htf_pair ) => {
             let mut content = htf_pair.next().unwrap().into_inner();
@@ -62,6 +62,7 @@ pub fn multitrace_from_text(gen_ctx : &GeneralContext,
                     let mut unavailable_lifelines : BTreeSet<usize> = btreeset!{};
                     let mut multi_trace : MultiTrace = vec![];
                     let mut colocs : Vec<BTreeSet<usize>> = vec![];
+                    let mut errors : Vec<HibouParsingError> = vec![];
                     for canal_trace_pair in first_pair.into_inner() {
                         match trace_canal_from_pair(gen_ctx,
                                                     canal_trace_pair,
@@ -69,13 +70,16 @@ pub fn multitrace_from_text(gen_ctx : &GeneralContext,
                                                     &mut multi_trace,
                                                     &mut unavailable_lifelines) {
                             Err(e) => {
-                                return Err(e);
+                                errors.push(e);
                             },
                             Ok( () ) => {
                                 // do nothing
                             }
                         }
                     }
+                    if !errors.is_empty() {
+                        return Err( HibouParsingError::from_errors(errors) );
+                    }
                     complete_canals_up_to_defined_lifelines(gen_ctx, &mut colocs, &mut multi_trace );
                     return Ok( (CoLocalizations::new(colocs),multi_trace) );
                 },
@@ -88,7 +92,7 @@ pub fn multitrace_from_text(gen_ctx : &GeneralContext,
 }
 
 
-fn complete_canals_up_to_defined_lifelines(gen_ctx : &GeneralContext,
+pub fn complete_canals_up_to_defined_lifelines(gen_ctx : &GeneralContext,
                                            colocs : &mut Vec<BTreeSet<usize>>,
                                            multi_trace : &mut MultiTrace) {
     let mut rem_lifelines : BTreeSet<usize> = gen_ctx.get_all_lfs_ids();
@@ -28,7 +28,8 @@ use crate::core::execution::trace::trace::TraceAction;
 
 use crate::io::input::error::HibouParsingError;
 use crate::io::input::htf::implem::multitrace_from_text;
-use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::file_extensions::{HIBOU_JSON_FILE_EXTENSION, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::input::json::interface::parse_json_multi_trace_file;
 use crate::io::input::htf::trace::trace_element_from_pair;
 
 #[allow(unused_imports)]
@@ -41,13 +42,23 @@ pub fn parse_htf_file(gen_ctx : &GeneralContext,
                       file_path : &str) -> Result<(CoLocalizations,MultiTrace),HibouParsingError> {
     let path_object = Path::new(file_path);
     let file_extension : &str = path_object.extension().unwrap().to_str().unwrap();
+    if file_extension == HIBOU_JSON_FILE_EXTENSION {
+        return parse_json_multi_trace_file(gen_ctx,file_path);
+    }
     if file_extension != HIBOU_TRACE_FILE_EXTENSION {
         return Err( HibouParsingError::FileFormatError(file_extension.to_string(),HIBOU_TRACE_FILE_EXTENSION.to_string()));
     }
     // ***
     match fs::read_to_string(file_path) {
         Ok( unparsed_htf_str ) => {
-            return multitrace_from_text(gen_ctx,&unparsed_htf_str);
+            match multitrace_from_text(gen_ctx,&unparsed_htf_str) {
+                Err(e) => {
+                    return Err( e.with_file_path(file_path) );
+                },
+                Ok( got ) => {
+                    return Ok( got );
+                }
+            }
         },
         Err(e) => {
             return Err( HibouParsingError::FileError(e.to_string()) );
@@ -64,7 +75,7 @@ pub fn multi_action_from_text(gen_ctx : &GeneralContext,
                             multiact_str : &String) -> Result<BTreeSet<TraceAction>,HibouParsingError> {
     match HtfParser::parse(Rule::TRACE_SEQUENCE_elt, multiact_str) {
         Err(e) => {
-            return Err( HibouParsingError::MatchError(e.to_string()) );
+            return Err( HibouParsingError::from_pest_error(e, multiact_str) );
         },
         Ok( ref mut content ) => {
             let trace_elt_pair = content.next().unwrap();
@@ -72,3 +83,83 @@ pub fn multi_action_from_text(gen_ctx : &GeneralContext,
         }
     }
 }
+
+// co-localizations given as lifelines separated by "," and canals separated by ";" e.g. "l1,l2;l3"
+// lifelines which are not mentioned are each given a canal of their own
+pub fn co_localizations_from_text(gen_ctx : &GeneralContext,
+                                  colocs_str : &str) -> Result<CoLocalizations,HibouParsingError> {
+    let mut canals : Vec<BTreeSet<usize>> = vec![];
+    let mut covered : BTreeSet<usize> = btreeset!{};
+    for canal_str in colocs_str.split(';') {
+        let mut canal : BTreeSet<usize> = btreeset!{};
+        for lf_name in canal_str.split(',').map(|name| name.trim()).filter(|name| !name.is_empty()) {
+            match gen_ctx.get_lf_id(lf_name) {
+                None => {
+                    return Err( HibouParsingError::MissingLifelineDeclarationError(lf_name.to_string()) );
+                },
+                Some( lf_id ) => {
+                    if !covered.insert(lf_id) {
+                        return Err( HibouParsingError::NonDisjointTraceComponents );
+                    }
+                    canal.insert(lf_id);
+                }
+            }
+        }
+        if !canal.is_empty() {
+            canals.push(canal);
+        }
+    }
+    for lf_id in 0..gen_ctx.get_lf_num() {
+        if !covered.contains(&lf_id) {
+            canals.push(btreeset!{lf_id});
+        }
+    }
+    return Ok( CoLocalizations::new(canals) );
+}
+
+// a multi-action possibly preceded by the lifelines of its canal e.g. "[l1,l2] l1!m1"
+// returns the id of the canal on which it occurs
+pub fn tagged_multi_action_from_text(gen_ctx : &GeneralContext,
+                                     co_localizations : &CoLocalizations,
+                                     tagged_str : &str) -> Result<(usize,BTreeSet<TraceAction>),HibouParsingError> {
+    let tagged_str = tagged_str.trim();
+    let (tag,multiact_str) = match tagged_str.strip_prefix('[') {
+        None => {
+            (None,tagged_str)
+        },
+        Some( after_bracket ) => {
+            match after_bracket.split_once(']') {
+                None => {
+                    return Err( HibouParsingError::IllDefinedTraceComponents(format!("unclosed canal in '{}'", tagged_str)) );
+                },
+                Some( (tag,rem) ) => {
+                    (Some(tag),rem.trim())
+                }
+            }
+        }
+    };
+    let multi_action = multi_action_from_text(gen_ctx,&multiact_str.to_string())?;
+    let lf_ids : BTreeSet<usize> = multi_action.iter().map(|act| act.lf_id).collect();
+    let coloc_ids = co_localizations.get_coloc_ids_from_lf_ids(&lf_ids);
+    if coloc_ids.len() != 1 {
+        return Err( HibouParsingError::IllDefinedTraceComponents(format!("'{}' occurs on several canals", multiact_str)) );
+    }
+    let coloc_id = *coloc_ids.iter().next().unwrap();
+    if let Some(tag) = tag {
+        let mut tag_lf_ids : BTreeSet<usize> = btreeset!{};
+        for lf_name in tag.split(',').map(|name| name.trim()).filter(|name| !name.is_empty()) {
+            match gen_ctx.get_lf_id(lf_name) {
+                None => {
+                    return Err( HibouParsingError::MissingLifelineDeclarationError(lf_name.to_string()) );
+                },
+                Some( lf_id ) => {
+                    tag_lf_ids.insert(lf_id);
+                }
+            }
+        }
+        if &tag_lf_ids != co_localizations.get_coloc_lfs_ids(coloc_id) {
+            return Err( HibouParsingError::IllDefinedTraceComponents(format!("'{}' does not occur on canal [{}]", multiact_str, tag)) );
+        }
+    }
+    return Ok( (coloc_id,multi_action) );
+}
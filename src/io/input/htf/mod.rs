@@ -17,5 +17,6 @@ limitations under the License.
 mod parser;
 mod trace;
 mod multi_trace;
-mod implem;
+pub mod implem;
 pub mod interface;
+pub mod format;
@@ -75,17 +75,21 @@ pub fn trace_canal_from_pair(gen_ctx : &GeneralContext,
         },
         Rule::CANAL_LIFELINES_spec => {
             let mut lifelines : BTreeSet<usize> = btreeset!{};
+            let mut errors : Vec<HibouParsingError> = vec![];
             for trace_lf_pair in canal_lfs_pair.into_inner() {
                 let lf_name : String  = trace_lf_pair.as_str().chars().filter(|c| !c.is_whitespace()).collect();
                 match gen_ctx.get_lf_id(&lf_name) {
                     None => {
-                        return Err( HibouParsingError::MissingLifelineDeclarationError(lf_name));
+                        errors.push( HibouParsingError::MissingLifelineDeclarationError(lf_name).locate(&trace_lf_pair.as_span()) );
                     },
                     Some( lf_id ) => {
                         lifelines.insert(lf_id);
                     }
                 }
             }
+            if !errors.is_empty() {
+                return Err( HibouParsingError::from_errors(errors) );
+            }
             // ***
             match trace_sequence_from_pair(gen_ctx,trace_sequence_pair,unavailable_lifelines,&mut lifelines, false) {
                 Err(e) => {
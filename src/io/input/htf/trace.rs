@@ -22,7 +22,9 @@ use pest::iterators::Pair;
 use crate::core::general_context::GeneralContext;
 use crate::core::execution::trace::multitrace::Trace;
 use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::language::syntax::value::ValuePattern;
 use crate::io::input::error::HibouParsingError;
+use crate::io::input::value::{check_arguments_against_types, parse_value_pattern};
 
 
 #[allow(unused_imports)]
@@ -38,16 +40,20 @@ pub fn trace_sequence_from_pair(gen_ctx : &GeneralContext,
                                 lifelines : &mut BTreeSet<usize>,
                                 add_lfs : bool) -> Result<Trace,HibouParsingError> {
     let mut trace : Trace = vec![];
+    let mut errors : Vec<HibouParsingError> = vec![];
     for trace_elt_pair in trace_sequence_pair.into_inner() {
         match trace_element_from_pair(gen_ctx,trace_elt_pair,unavailable_lifelines,lifelines,add_lfs) {
             Err(e) => {
-                return Err(e);
+                errors.push(e);
             }
             Ok(trace_elt) => {
                 trace.push(trace_elt);
             }
         }
     }
+    if !errors.is_empty() {
+        return Err( HibouParsingError::from_errors(errors) );
+    }
     return Ok( trace );
 }
 
@@ -87,14 +93,20 @@ fn get_trace_multi_action(gen_ctx : &GeneralContext,
                           lifelines : &mut BTreeSet<usize>,
                           add_lfs : bool) -> Result<BTreeSet<TraceAction>,HibouParsingError> {
     let mut multi_action = btreeset!{};
+    let mut errors : Vec<HibouParsingError> = vec![];
     for action_pair in multi_act_pair.into_inner() {
         match get_trace_action(gen_ctx,action_pair,unavailable_lifelines,lifelines,add_lfs) {
-            Err(e) => {return Err(e);},
+            Err(e) => {
+                errors.push(e);
+            },
             Ok( action ) => {
                 multi_action.insert(action);
             }
         }
     }
+    if !errors.is_empty() {
+        return Err( HibouParsingError::from_errors(errors) );
+    }
     return Ok(multi_action);
 }
 
@@ -103,13 +115,14 @@ fn get_trace_action(gen_ctx : &GeneralContext,
                     unavailable_lifelines : &BTreeSet<usize>,
                     lifelines : &mut BTreeSet<usize>,
                     add_lfs : bool)-> Result<TraceAction,HibouParsingError>  {
+    let action_span = action_pair.as_span();
     match trace_action_from_text(gen_ctx,action_pair) {
         Err(e) => {
-            return Err(e);
+            return Err( e.locate(&action_span) );
         },
         Ok( action ) => {
             if unavailable_lifelines.contains(&action.lf_id) {
-                return Err( HibouParsingError::NonDisjointTraceComponents );
+                return Err( HibouParsingError::NonDisjointTraceComponents.locate(&action_span) );
             } else {
                 if add_lfs {
                     lifelines.insert( action.lf_id);
@@ -117,7 +130,7 @@ fn get_trace_action(gen_ctx : &GeneralContext,
                     if !lifelines.contains( &action.lf_id ) {
                         return Err( HibouParsingError::IllDefinedTraceComponents(format!("lifeline of action {:?} not in predefined co-localisation {:?}",
                                                                                          action,
-                                                                                         lifelines)) );
+                                                                                         lifelines)).locate(&action_span) );
                     }
                 }
             }
@@ -171,5 +184,28 @@ fn trace_action_from_text(gen_ctx : &GeneralContext,
         }
     }
     // ***
-    return Ok( TraceAction{lf_id:got_lf_id,act_kind,ms_id:got_ms_id} );
+    let mut args : Vec<ValuePattern> = Vec::new();
+    if let Some(args_pair) = contents.next() {
+        for arg_pair in args_pair.into_inner() {
+            match parse_value_pattern(arg_pair.as_str()) {
+                Err(e) => {
+                    return Err( e.locate(&arg_pair.as_span()) );
+                },
+                Ok( arg ) => {
+                    args.push(arg);
+                }
+            }
+        }
+    }
+    // values carried by multi-traces must be consistent with the signature
+    match check_arguments_against_types(&ms_name,
+                                        &args,
+                                        &gen_ctx.get_ms_arg_types(got_ms_id).unwrap()) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok(_) => {}
+    }
+    // ***
+    return Ok( TraceAction::new(got_lf_id,act_kind,got_ms_id,args) );
 }
@@ -0,0 +1,176 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use serde_json::Value;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::value::ArgumentType;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::json::fields::{get_array_field, get_str_array_field, get_str_field};
+
+
+fn argument_type_from_json(type_name : &str) -> Result<ArgumentType,HibouParsingError> {
+    match type_name {
+        "int" => {
+            return Ok( ArgumentType::Integer );
+        },
+        "str" => {
+            return Ok( ArgumentType::String );
+        },
+        "bool" => {
+            return Ok( ArgumentType::Boolean );
+        },
+        _ => {
+            return Err( HibouParsingError::JsonError(format!("unknown argument type '{}' (expected 'int', 'str' or 'bool')", type_name)) );
+        }
+    }
+}
+
+fn check_not_declared(already_declared : bool, name : &str) -> Result<(),HibouParsingError> {
+    if already_declared {
+        return Err( HibouParsingError::HsfSetupError(format!("'{}' is declared several times", name)) );
+    }
+    return Ok(());
+}
+
+pub fn general_context_from_json(context_json : &Value) -> Result<GeneralContext,HibouParsingError> {
+    let mut gen_ctx = GeneralContext::new();
+    // ***
+    match get_str_array_field(context_json, "lifelines", true) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( lf_names ) => {
+            for lf_name in lf_names {
+                match check_not_declared(gen_ctx.get_lf_id(lf_name).is_some(), lf_name) {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok(_) => {
+                        gen_ctx.add_lf(lf_name.to_string());
+                    }
+                }
+            }
+        }
+    }
+    // ***
+    match get_array_field(context_json, "messages", true) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( messages ) => {
+            for message in messages {
+                let ms_name : &str;
+                match get_str_field(message, "name") {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok( got_name ) => {
+                        ms_name = got_name;
+                    }
+                }
+                let mut arg_types : Vec<ArgumentType> = vec![];
+                match get_str_array_field(message, "args", true) {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok( type_names ) => {
+                        for type_name in type_names {
+                            match argument_type_from_json(type_name) {
+                                Err(e) => {
+                                    return Err(e);
+                                },
+                                Ok( arg_type ) => {
+                                    arg_types.push(arg_type);
+                                }
+                            }
+                        }
+                    }
+                }
+                match check_not_declared(gen_ctx.get_ms_id(ms_name).is_some(), ms_name) {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok(_) => {
+                        let ms_id = gen_ctx.add_msg(ms_name.to_string());
+                        gen_ctx.set_ms_arg_types(ms_id, arg_types);
+                    }
+                }
+            }
+        }
+    }
+    // ***
+    match get_str_array_field(context_json, "gates", true) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( gt_names ) => {
+            for gt_name in gt_names {
+                match check_not_declared(gen_ctx.get_gt_id(gt_name).is_some(), gt_name) {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok(_) => {
+                        gen_ctx.add_gt(gt_name.to_string());
+                    }
+                }
+            }
+        }
+    }
+    // ***
+    match get_array_field(context_json, "lifeline_families", true) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( families ) => {
+            for family in families {
+                let family_name : &str;
+                match get_str_field(family, "name") {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok( got_name ) => {
+                        family_name = got_name;
+                    }
+                }
+                if gen_ctx.get_lf_family(family_name).is_some() || gen_ctx.get_lf_id(family_name).is_some() {
+                    return Err( HibouParsingError::HsfSetupError(format!("'{}' is declared several times", family_name)) );
+                }
+                let mut members : Vec<usize> = vec![];
+                match get_str_array_field(family, "members", false) {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok( member_names ) => {
+                        for lf_name in member_names {
+                            match gen_ctx.get_lf_id(lf_name) {
+                                None => {
+                                    return Err( HibouParsingError::MissingLifelineDeclarationError(lf_name.to_string()) );
+                                },
+                                Some( lf_id ) => {
+                                    members.push(lf_id);
+                                }
+                            }
+                        }
+                    }
+                }
+                gen_ctx.add_lf_family(family_name.to_string(), members);
+            }
+        }
+    }
+    // ***
+    return Ok( gen_ctx );
+}
@@ -0,0 +1,165 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use serde_json::Value;
+
+use crate::core::language::syntax::value::ValuePattern;
+use crate::io::input::error::HibouParsingError;
+
+
+pub fn get_field<'a>(object : &'a Value, field : &str) -> Result<&'a Value,HibouParsingError> {
+    match object.get(field) {
+        None => {
+            return Err( HibouParsingError::JsonError(format!("missing field '{}' in {}", field, object)) );
+        },
+        Some( got_value ) => {
+            return Ok( got_value );
+        }
+    }
+}
+
+pub fn get_str_field<'a>(object : &'a Value, field : &str) -> Result<&'a str,HibouParsingError> {
+    match get_field(object, field) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( got_value ) => {
+            match got_value.as_str() {
+                None => {
+                    return Err( HibouParsingError::JsonError(format!("field '{}' must be a string in {}", field, object)) );
+                },
+                Some( got_str ) => {
+                    return Ok( got_str );
+                }
+            }
+        }
+    }
+}
+
+pub fn get_u32_field(object : &Value, field : &str) -> Result<u32,HibouParsingError> {
+    match get_field(object, field) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( got_value ) => {
+            match got_value.as_u64() {
+                Some( got_int ) if got_int <= u32::MAX as u64 => {
+                    return Ok( got_int as u32 );
+                },
+                _ => {
+                    return Err( HibouParsingError::JsonError(format!("field '{}' must be a non-negative integer in {}", field, object)) );
+                }
+            }
+        }
+    }
+}
+
+// absent optional arrays are considered empty
+pub fn get_array_field<'a>(object : &'a Value, field : &str, optional : bool) -> Result<Vec<&'a Value>,HibouParsingError> {
+    match object.get(field) {
+        None if optional => {
+            return Ok( vec![] );
+        },
+        _ => {}
+    }
+    match get_field(object, field) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( got_value ) => {
+            match got_value.as_array() {
+                None => {
+                    return Err( HibouParsingError::JsonError(format!("field '{}' must be an array in {}", field, object)) );
+                },
+                Some( got_array ) => {
+                    return Ok( got_array.iter().collect() );
+                }
+            }
+        }
+    }
+}
+
+pub fn get_str_array_field<'a>(object : &'a Value, field : &str, optional : bool) -> Result<Vec<&'a str>,HibouParsingError> {
+    match get_array_field(object, field, optional) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( got_array ) => {
+            let mut strs = vec![];
+            for item in got_array {
+                match item.as_str() {
+                    None => {
+                        return Err( HibouParsingError::JsonError(format!("field '{}' must be an array of strings in {}", field, object)) );
+                    },
+                    Some( got_str ) => {
+                        strs.push( got_str );
+                    }
+                }
+            }
+            return Ok( strs );
+        }
+    }
+}
+
+// wildcards are represented by null
+pub fn value_pattern_from_json(value : &Value) -> Result<ValuePattern,HibouParsingError> {
+    match value {
+        Value::Null => {
+            return Ok( ValuePattern::Any );
+        },
+        Value::Bool( got_bool ) => {
+            return Ok( ValuePattern::Boolean(*got_bool) );
+        },
+        Value::String( got_str ) => {
+            return Ok( ValuePattern::String(got_str.clone()) );
+        },
+        Value::Number( got_num ) => {
+            match got_num.as_i64() {
+                None => {
+                    return Err( HibouParsingError::JsonError(format!("argument value {} is not an integer", got_num)) );
+                },
+                Some( got_int ) => {
+                    return Ok( ValuePattern::Integer(got_int) );
+                }
+            }
+        },
+        _ => {
+            return Err( HibouParsingError::JsonError(format!("argument value {} is neither null, a boolean, a string nor an integer", value)) );
+        }
+    }
+}
+
+pub fn get_args_field(object : &Value) -> Result<Vec<ValuePattern>,HibouParsingError> {
+    match get_array_field(object, "args", true) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( got_array ) => {
+            let mut args = vec![];
+            for item in got_array {
+                match value_pattern_from_json(item) {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok( arg ) => {
+                        args.push( arg );
+                    }
+                }
+            }
+            return Ok( args );
+        }
+    }
+}
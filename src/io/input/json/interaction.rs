@@ -0,0 +1,451 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use serde_json::Value;
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction, EmissionTargetRef, ReceptionAction};
+use crate::core::language::syntax::interaction::{Interaction, LoopKind};
+use crate::core::language::syntax::value::ValuePattern;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::json::fields::{get_args_field, get_array_field, get_field, get_str_array_field, get_str_field, get_u32_field};
+use crate::io::input::json::multitrace::{lifeline_from_json, message_from_json, trace_action_from_json};
+use crate::io::input::value::check_arguments_against_types;
+
+
+fn loop_kind_from_json(interaction_json : &Value) -> Result<LoopKind,HibouParsingError> {
+    match get_str_field(interaction_json, "loop_kind") {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( "S" ) => {
+            return Ok( LoopKind::SStrictSeq );
+        },
+        Ok( "H" ) => {
+            return Ok( LoopKind::HHeadFirstWS );
+        },
+        Ok( "W" ) => {
+            return Ok( LoopKind::WWeakSeq );
+        },
+        Ok( "P" ) => {
+            return Ok( LoopKind::PInterleaving );
+        },
+        Ok( other ) => {
+            return Err( HibouParsingError::JsonError(format!("unknown loop kind '{}' (expected 'S', 'H', 'W' or 'P')", other)) );
+        }
+    }
+}
+
+// the synchronicity is optional and communications are asynchronous by default
+fn synchronicity_from_json(interaction_json : &Value) -> Result<CommunicationSynchronicity,HibouParsingError> {
+    if interaction_json.get("synchronicity").is_none() {
+        return Ok( CommunicationSynchronicity::Asynchronous );
+    }
+    match get_str_field(interaction_json, "synchronicity") {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( "asynch" ) => {
+            return Ok( CommunicationSynchronicity::Asynchronous );
+        },
+        Ok( "synch" ) => {
+            return Ok( CommunicationSynchronicity::Synchronous );
+        },
+        Ok( other ) => {
+            return Err( HibouParsingError::JsonError(format!("unknown synchronicity '{}' (expected 'asynch' or 'synch')", other)) );
+        }
+    }
+}
+
+// when no arguments are given, any values are accepted
+fn message_and_args_from_json(gen_ctx : &GeneralContext, interaction_json : &Value) -> Result<(usize,Vec<ValuePattern>),HibouParsingError> {
+    let ms_id : usize;
+    match get_str_field(interaction_json, "message").and_then(|ms_name| message_from_json(gen_ctx, ms_name)) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( got_ms_id ) => {
+            ms_id = got_ms_id;
+        }
+    }
+    match get_args_field(interaction_json) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( args ) => {
+            if !args.is_empty() {
+                match check_arguments_against_types(&gen_ctx.get_ms_name(ms_id).unwrap(),
+                                                    &args,
+                                                    &gen_ctx.get_ms_arg_types(ms_id).unwrap()) {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok(_) => {}
+                }
+            }
+            return Ok( (ms_id,args) );
+        }
+    }
+}
+
+fn emission_targets_from_json(gen_ctx : &GeneralContext, interaction_json : &Value) -> Result<Vec<EmissionTargetRef>,HibouParsingError> {
+    let mut targets : Vec<EmissionTargetRef> = vec![];
+    match get_array_field(interaction_json, "targets", true) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( targets_json ) => {
+            for target_json in targets_json {
+                let target_ref : EmissionTargetRef;
+                if target_json.get("lifeline").is_some() {
+                    match get_str_field(target_json, "lifeline").and_then(|lf_name| lifeline_from_json(gen_ctx, lf_name)) {
+                        Err(e) => {
+                            return Err(e);
+                        },
+                        Ok( lf_id ) => {
+                            target_ref = EmissionTargetRef::Lifeline(lf_id);
+                        }
+                    }
+                } else {
+                    match get_str_field(target_json, "gate") {
+                        Err(e) => {
+                            return Err(e);
+                        },
+                        Ok( gt_name ) => {
+                            match gen_ctx.get_gt_id(gt_name) {
+                                None => {
+                                    return Err( HibouParsingError::MissingGateDeclarationError(gt_name.to_string()) );
+                                },
+                                Some( gt_id ) => {
+                                    target_ref = EmissionTargetRef::Gate(gt_id);
+                                }
+                            }
+                        }
+                    }
+                }
+                if targets.contains(&target_ref) {
+                    return Err( HibouParsingError::EmissionDefinitionError( format!("duplicate target {}", target_json) ) );
+                }
+                targets.push(target_ref);
+            }
+        }
+    }
+    return Ok( targets );
+}
+
+fn lifelines_from_json(gen_ctx : &GeneralContext, interaction_json : &Value, field : &str) -> Result<Vec<usize>,HibouParsingError> {
+    let mut lf_ids : Vec<usize> = vec![];
+    match get_str_array_field(interaction_json, field, false) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( lf_names ) => {
+            for lf_name in lf_names {
+                match lifeline_from_json(gen_ctx, lf_name) {
+                    Err(e) => {
+                        return Err(e);
+                    },
+                    Ok( lf_id ) => {
+                        if !lf_ids.contains(&lf_id) {
+                            lf_ids.push(lf_id);
+                        }
+                    }
+                }
+            }
+        }
+    }
+    lf_ids.sort();
+    return Ok( lf_ids );
+}
+
+fn sub_interaction_from_json(gen_ctx : &GeneralContext, interaction_json : &Value, field : &str) -> Result<Box<Interaction>,HibouParsingError> {
+    match get_field(interaction_json, field).and_then(|sub_json| interaction_from_json(gen_ctx, sub_json)) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( sub_int ) => {
+            return Ok( Box::new(sub_int) );
+        }
+    }
+}
+
+fn left_and_right_from_json(gen_ctx : &GeneralContext, interaction_json : &Value) -> Result<(Box<Interaction>,Box<Interaction>),HibouParsingError> {
+    let mut errors : Vec<HibouParsingError> = vec![];
+    let left = sub_interaction_from_json(gen_ctx, interaction_json, "left");
+    let right = sub_interaction_from_json(gen_ctx, interaction_json, "right");
+    match (left,right) {
+        (Ok( i1 ), Ok( i2 )) => {
+            return Ok( (i1,i2) );
+        },
+        (left,right) => {
+            if let Err(e) = left {
+                errors.push(e);
+            }
+            if let Err(e) = right {
+                errors.push(e);
+            }
+            return Err( HibouParsingError::from_errors(errors) );
+        }
+    }
+}
+
+pub fn interaction_from_json(gen_ctx : &GeneralContext, interaction_json : &Value) -> Result<Interaction,HibouParsingError> {
+    let kind : &str;
+    match get_str_field(interaction_json, "kind") {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( got_kind ) => {
+            kind = got_kind;
+        }
+    }
+    match kind {
+        "empty" => {
+            return Ok( Interaction::Empty );
+        },
+        "emission" => {
+            let origin_lf_id : usize;
+            match get_str_field(interaction_json, "origin").and_then(|lf_name| lifeline_from_json(gen_ctx, lf_name)) {
+                Err(e) => {
+                    return Err(e);
+                },
+                Ok( lf_id ) => {
+                    origin_lf_id = lf_id;
+                }
+            }
+            match (synchronicity_from_json(interaction_json),
+                   message_and_args_from_json(gen_ctx, interaction_json),
+                   emission_targets_from_json(gen_ctx, interaction_json)) {
+                (Ok( synchronicity ), Ok( (ms_id,args) ), Ok( targets )) => {
+                    return Ok( Interaction::Emission(EmissionAction::new(origin_lf_id,ms_id,synchronicity,targets,args)) );
+                },
+                (Err(e),_,_) | (_,Err(e),_) | (_,_,Err(e)) => {
+                    return Err(e);
+                }
+            }
+        },
+        "reception" => {
+            let origin_gt_id : Option<usize>;
+            match interaction_json.get("origin_gate") {
+                None | Some( Value::Null ) => {
+                    origin_gt_id = None;
+                },
+                Some( _ ) => {
+                    match get_str_field(interaction_json, "origin_gate") {
+                        Err(e) => {
+                            return Err(e);
+                        },
+                        Ok( gt_name ) => {
+                            match gen_ctx.get_gt_id(gt_name) {
+                                None => {
+                                    return Err( HibouParsingError::MissingGateDeclarationError(gt_name.to_string()) );
+                                },
+                                Some( gt_id ) => {
+                                    origin_gt_id = Some(gt_id);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            match (synchronicity_from_json(interaction_json),
+                   message_and_args_from_json(gen_ctx, interaction_json),
+                   lifelines_from_json(gen_ctx, interaction_json, "recipients")) {
+                (Ok( synchronicity ), Ok( (ms_id,args) ), Ok( recipients )) => {
+                    if recipients.is_empty() {
+                        return Err( HibouParsingError::EmissionDefinitionError( format!("reception without recipients : {}", interaction_json) ) );
+                    }
+                    return Ok( Interaction::Reception(ReceptionAction::new(origin_gt_id,ms_id,synchronicity,recipients,args)) );
+                },
+                (Err(e),_,_) | (_,Err(e),_) | (_,_,Err(e)) => {
+                    return Err(e);
+                }
+            }
+        },
+        "strict" | "seq" | "alt" | "par" | "disrupt" | "and" => {
+            match left_and_right_from_json(gen_ctx, interaction_json) {
+                Err(e) => {
+                    return Err(e);
+                },
+                Ok( (i1,i2) ) => {
+                    match kind {
+                        "strict" => {
+                            return Ok( Interaction::Strict(i1,i2) );
+                        },
+                        "seq" => {
+                            return Ok( Interaction::Seq(i1,i2) );
+                        },
+                        "alt" => {
+                            return Ok( Interaction::Alt(i1,i2) );
+                        },
+                        "par" => {
+                            return Ok( Interaction::Par(i1,i2) );
+                        },
+                        "disrupt" => {
+                            return Ok( Interaction::Disrupt(i1,i2) );
+                        },
+                        _ => {
+                            return Ok( Interaction::And(i1,i2) );
+                        }
+                    }
+                }
+            }
+        },
+        "coreg" => {
+            match (lifelines_from_json(gen_ctx, interaction_json, "lifelines"),
+                   left_and_right_from_json(gen_ctx, interaction_json)) {
+                (Ok( cr ), Ok( (i1,i2) )) => {
+                    return Ok( Interaction::CoReg(cr,i1,i2) );
+                },
+                (Err(e),_) | (_,Err(e)) => {
+                    return Err(e);
+                }
+            }
+        },
+        "sync" => {
+            let mut sync_acts : Vec<TraceAction> = vec![];
+            match get_array_field(interaction_json, "actions", false) {
+                Err(e) => {
+                    return Err(e);
+                },
+                Ok( actions_json ) => {
+                    for action_json in actions_json {
+                        match trace_action_from_json(gen_ctx, action_json, false) {
+                            Err(e) => {
+                                return Err(e);
+                            },
+                            Ok( action ) => {
+                                if !sync_acts.contains(&action) {
+                                    sync_acts.push(action);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            sync_acts.sort();
+            match left_and_right_from_json(gen_ctx, interaction_json) {
+                Err(e) => {
+                    return Err(e);
+                },
+                Ok( (i1,i2) ) => {
+                    return Ok( Interaction::Sync(sync_acts,i1,i2) );
+                }
+            }
+        },
+        "loop" => {
+            match (loop_kind_from_json(interaction_json),
+                   sub_interaction_from_json(gen_ctx, interaction_json, "body")) {
+                (Ok( loop_kind ), Ok( i1 )) => {
+                    return Ok( Interaction::Loop(loop_kind,i1) );
+                },
+                (Err(e),_) | (_,Err(e)) => {
+                    return Err(e);
+                }
+            }
+        },
+        "bounded_loop" => {
+            match (loop_kind_from_json(interaction_json),
+                   get_u32_field(interaction_json, "min"),
+                   get_u32_field(interaction_json, "max")) {
+                (Ok( loop_kind ), Ok( min ), Ok( max )) => {
+                    if max == 0 {
+                        return Err( HibouParsingError::OtherDefinitionError( "maximum number of loop iterations must be at least 1".to_string() ) );
+                    }
+                    if min > max {
+                        return Err( HibouParsingError::OtherDefinitionError( format!("minimum number of loop iterations {:} is greater than maximum {:}", min, max) ) );
+                    }
+                    match sub_interaction_from_json(gen_ctx, interaction_json, "body") {
+                        Err(e) => {
+                            return Err(e);
+                        },
+                        Ok( i1 ) => {
+                            return Ok( Interaction::BoundedLoop(loop_kind,min,max,i1) );
+                        }
+                    }
+                },
+                (Err(e),_,_) | (_,Err(e),_) | (_,_,Err(e)) => {
+                    return Err(e);
+                }
+            }
+        },
+        "neg" => {
+            match sub_interaction_from_json(gen_ctx, interaction_json, "body") {
+                Err(e) => {
+                    return Err(e);
+                },
+                Ok( i1 ) => {
+                    return Ok( Interaction::Neg(i1) );
+                }
+            }
+        },
+        "assert" => {
+            match sub_interaction_from_json(gen_ctx, interaction_json, "body") {
+                Err(e) => {
+                    return Err(e);
+                },
+                Ok( i1 ) => {
+                    return Ok( Interaction::Assert(i1) );
+                }
+            }
+        },
+        _ => {
+            return Err( HibouParsingError::JsonError(format!("unknown interaction kind '{}'", kind)) );
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction, EmissionTargetRef, ReceptionAction};
+    use crate::core::language::syntax::interaction::{Interaction, LoopKind};
+    use crate::core::language::syntax::value::{ArgumentType, ValuePattern};
+    use crate::io::input::json::interaction::interaction_from_json;
+    use crate::io::output::to_json::interaction::interaction_as_json;
+
+    #[test]
+    fn interaction_json_round_trip() {
+        let mut gen_ctx = GeneralContext::new();
+        let l1 = gen_ctx.add_lf("l1".to_string());
+        let l2 = gen_ctx.add_lf("l2".to_string());
+        let m1 = gen_ctx.add_msg("m1".to_string());
+        gen_ctx.set_ms_arg_types(m1, vec![ArgumentType::Integer,ArgumentType::String]);
+        let m2 = gen_ctx.add_msg("m2".to_string());
+        let g = gen_ctx.add_gt("g".to_string());
+        // ***
+        let emission = Interaction::Emission(EmissionAction::new(l1,m1,CommunicationSynchronicity::Synchronous,
+                                                                 vec![EmissionTargetRef::Lifeline(l2),EmissionTargetRef::Gate(g)],
+                                                                 vec![ValuePattern::Integer(-3),ValuePattern::Any]));
+        let reception = Interaction::Reception(ReceptionAction::new(Some(g),m2,CommunicationSynchronicity::Asynchronous,vec![l1,l2],vec![]));
+        let sync_act = TraceAction::new(l2,TraceActionKind::Reception,m2,vec![]);
+        let interaction = Interaction::Seq(
+            Box::new(Interaction::CoReg(vec![l1],Box::new(emission),Box::new(Interaction::Empty))),
+            Box::new(Interaction::Sync(vec![sync_act],
+                                       Box::new(Interaction::BoundedLoop(LoopKind::HHeadFirstWS,1,3,Box::new(reception))),
+                                       Box::new(Interaction::Neg(Box::new(Interaction::Loop(LoopKind::PInterleaving,Box::new(Interaction::Empty))))))));
+        // ***
+        let got_json = interaction_as_json(&gen_ctx, &interaction);
+        assert_eq!(interaction_from_json(&gen_ctx, &got_json).unwrap(), interaction);
+        // names are resolved against the signature
+        let mut wrong_json = got_json.clone();
+        wrong_json["left"]["left"]["origin"] = serde_json::json!("l3");
+        assert!(interaction_from_json(&gen_ctx, &wrong_json).is_err());
+    }
+}
@@ -0,0 +1,91 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::fs;
+
+use serde_json::Value;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::json::context::general_context_from_json;
+use crate::io::input::json::interaction::interaction_from_json;
+use crate::io::input::json::multitrace::multi_trace_from_json;
+
+
+pub fn read_json_file(file_path : &str) -> Result<Value,HibouParsingError> {
+    match fs::read_to_string(file_path) {
+        Err(e) => {
+            return Err( HibouParsingError::FileError(e.to_string()) );
+        },
+        Ok( unparsed_json_str ) => {
+            match serde_json::from_str(&unparsed_json_str) {
+                Err(e) => {
+                    return Err( HibouParsingError::JsonError(format!("in file '{}' : {}", file_path, e)) );
+                },
+                Ok( got_json ) => {
+                    return Ok( got_json );
+                }
+            }
+        }
+    }
+}
+
+pub fn with_json_file_path<T>(result : Result<T,HibouParsingError>, file_path : &str) -> Result<T,HibouParsingError> {
+    match result {
+        Err(e) => {
+            return Err( HibouParsingError::JsonError(format!("in file '{}' : {}", file_path, e)) );
+        },
+        Ok( got ) => {
+            return Ok( got );
+        }
+    }
+}
+
+pub fn parse_json_context_file(file_path : &str) -> Result<GeneralContext,HibouParsingError> {
+    match read_json_file(file_path) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( context_json ) => {
+            return with_json_file_path(general_context_from_json(&context_json), file_path);
+        }
+    }
+}
+
+pub fn parse_json_interaction_file(gen_ctx : &GeneralContext, file_path : &str) -> Result<Interaction,HibouParsingError> {
+    match read_json_file(file_path) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( interaction_json ) => {
+            return with_json_file_path(interaction_from_json(gen_ctx, &interaction_json), file_path);
+        }
+    }
+}
+
+pub fn parse_json_multi_trace_file(gen_ctx : &GeneralContext, file_path : &str) -> Result<(CoLocalizations,MultiTrace),HibouParsingError> {
+    match read_json_file(file_path) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( multi_trace_json ) => {
+            return with_json_file_path(multi_trace_from_json(gen_ctx, &multi_trace_json), file_path);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+// reading of the JSON representation documented in "readme/7_json.md"
+// the structure is checked here while names are resolved against the signature as in .hif and .htf files
+
+pub mod fields;
+pub mod context;
+pub mod interaction;
+pub mod multitrace;
+pub mod interface;
@@ -0,0 +1,213 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeSet;
+
+use serde_json::Value;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::{MultiTrace, Trace};
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::value::ValuePattern;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::htf::implem::complete_canals_up_to_defined_lifelines;
+use crate::io::input::json::fields::{get_args_field, get_array_field, get_str_array_field, get_str_field};
+use crate::io::input::value::check_arguments_against_types;
+
+
+pub fn lifeline_from_json(gen_ctx : &GeneralContext, lf_name : &str) -> Result<usize,HibouParsingError> {
+    match gen_ctx.get_lf_id(lf_name) {
+        None => {
+            return Err( HibouParsingError::MissingLifelineDeclarationError(lf_name.to_string()) );
+        },
+        Some( lf_id ) => {
+            return Ok( lf_id );
+        }
+    }
+}
+
+pub fn message_from_json(gen_ctx : &GeneralContext, ms_name : &str) -> Result<usize,HibouParsingError> {
+    match gen_ctx.get_ms_id(ms_name) {
+        None => {
+            return Err( HibouParsingError::MissingMessageDeclarationError(ms_name.to_string()) );
+        },
+        Some( ms_id ) => {
+            return Ok( ms_id );
+        }
+    }
+}
+
+// actions of multi-traces carry concrete values
+// while the actions of "sync" operators may carry wildcards or no arguments at all
+pub fn trace_action_from_json(gen_ctx : &GeneralContext,
+                              action_json : &Value,
+                              is_concrete : bool) -> Result<TraceAction,HibouParsingError> {
+    let lf_id : usize;
+    match get_str_field(action_json, "lifeline").and_then(|lf_name| lifeline_from_json(gen_ctx, lf_name)) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( got_lf_id ) => {
+            lf_id = got_lf_id;
+        }
+    }
+    let act_kind : TraceActionKind;
+    match get_str_field(action_json, "kind") {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( "emission" ) => {
+            act_kind = TraceActionKind::Emission;
+        },
+        Ok( "reception" ) => {
+            act_kind = TraceActionKind::Reception;
+        },
+        Ok( other ) => {
+            return Err( HibouParsingError::JsonError(format!("unknown action kind '{}' (expected 'emission' or 'reception')", other)) );
+        }
+    }
+    let ms_id : usize;
+    match get_str_field(action_json, "message").and_then(|ms_name| message_from_json(gen_ctx, ms_name)) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( got_ms_id ) => {
+            ms_id = got_ms_id;
+        }
+    }
+    let args : Vec<ValuePattern>;
+    match get_args_field(action_json) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( got_args ) => {
+            args = got_args;
+        }
+    }
+    if is_concrete && args.contains(&ValuePattern::Any) {
+        return Err( HibouParsingError::MessageArgumentError(
+            format!("multi-traces cannot carry wildcards : {}", action_json) ) );
+    }
+    if is_concrete || !args.is_empty() {
+        match check_arguments_against_types(&gen_ctx.get_ms_name(ms_id).unwrap(),
+                                            &args,
+                                            &gen_ctx.get_ms_arg_types(ms_id).unwrap()) {
+            Err(e) => {
+                return Err(e);
+            },
+            Ok(_) => {}
+        }
+    }
+    return Ok( TraceAction::new(lf_id,act_kind,ms_id,args) );
+}
+
+fn trace_from_json(gen_ctx : &GeneralContext,
+                   canal_json : &Value,
+                   lifelines : &BTreeSet<usize>) -> Result<Trace,HibouParsingError> {
+    let mut trace : Trace = vec![];
+    match get_array_field(canal_json, "trace", false) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( elements ) => {
+            for element in elements {
+                let actions : Vec<&Value>;
+                match element.as_array() {
+                    Some( got_actions ) if !got_actions.is_empty() => {
+                        actions = got_actions.iter().collect();
+                    },
+                    _ => {
+                        return Err( HibouParsingError::JsonError(format!("trace elements must be non-empty arrays of actions, got {}", element)) );
+                    }
+                }
+                let mut multi_action : BTreeSet<TraceAction> = BTreeSet::new();
+                for action_json in actions {
+                    match trace_action_from_json(gen_ctx, action_json, true) {
+                        Err(e) => {
+                            return Err(e);
+                        },
+                        Ok( action ) => {
+                            if !lifelines.contains(&action.lf_id) {
+                                return Err( HibouParsingError::IllDefinedTraceComponents(format!("lifeline of action {} not in co-localisation {:?}",
+                                                                                                 action_json,
+                                                                                                 lifelines)) );
+                            }
+                            multi_action.insert(action);
+                        }
+                    }
+                }
+                trace.push(multi_action);
+            }
+        }
+    }
+    return Ok( trace );
+}
+
+pub fn multi_trace_from_json(gen_ctx : &GeneralContext,
+                             multi_trace_json : &Value) -> Result<(CoLocalizations,MultiTrace),HibouParsingError> {
+    let mut unavailable_lifelines : BTreeSet<usize> = btreeset!{};
+    let mut multi_trace : MultiTrace = vec![];
+    let mut colocs : Vec<BTreeSet<usize>> = vec![];
+    let mut errors : Vec<HibouParsingError> = vec![];
+    match get_array_field(multi_trace_json, "canals", false) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( canals ) => {
+            for canal_json in canals {
+                let mut lifelines : BTreeSet<usize> = btreeset!{};
+                match get_str_array_field(canal_json, "lifelines", false) {
+                    Err(e) => {
+                        errors.push(e);
+                        continue;
+                    },
+                    Ok( lf_names ) => {
+                        for lf_name in lf_names {
+                            match lifeline_from_json(gen_ctx, lf_name) {
+                                Err(e) => {
+                                    errors.push(e);
+                                },
+                                Ok( lf_id ) => {
+                                    lifelines.insert(lf_id);
+                                }
+                            }
+                        }
+                    }
+                }
+                if !unavailable_lifelines.is_disjoint(&lifelines) {
+                    errors.push( HibouParsingError::NonDisjointTraceComponents );
+                    continue;
+                }
+                match trace_from_json(gen_ctx, canal_json, &lifelines) {
+                    Err(e) => {
+                        errors.push(e);
+                    },
+                    Ok( trace ) => {
+                        unavailable_lifelines.extend(lifelines.clone());
+                        colocs.push(lifelines);
+                        multi_trace.push(trace);
+                    }
+                }
+            }
+        }
+    }
+    if !errors.is_empty() {
+        return Err( HibouParsingError::from_errors(errors) );
+    }
+    complete_canals_up_to_defined_lifelines(gen_ctx, &mut colocs, &mut multi_trace);
+    return Ok( (CoLocalizations::new(colocs),multi_trace) );
+}
@@ -0,0 +1,98 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+
+use pest::Span;
+
+
+// location of an error in a parsed file
+#[derive(Debug, Clone, PartialEq)]
+pub struct SourceLocation {
+    pub file_path : Option<String>,
+    pub offset : usize, // in bytes, from the start of the parsed string
+    pub line : usize, // starting from 1
+    pub column : usize, // in characters, starting from 1
+    pub line_text : String,
+    pub underline_len : usize // in characters, limited to the end of the line
+}
+
+impl SourceLocation {
+
+    pub fn new(source : &str, offset : usize, len : usize) -> SourceLocation {
+        let offset = offset.min(source.len());
+        let before = &source[..offset];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map(|idx| idx + 1).unwrap_or(0);
+        let line_end = source[offset..].find('\n').map(|idx| offset + idx).unwrap_or(source.len());
+        let line_text = source[line_start..line_end].trim_end_matches('\r').to_string();
+        let column = source[line_start..offset].chars().count() + 1;
+        let remaining_on_line = line_text.chars().count().saturating_sub(column - 1);
+        let span_chars = source[offset..(offset+len).min(source.len())].chars().count();
+        let underline_len = span_chars.min(remaining_on_line).max(1);
+        return SourceLocation{file_path:None,offset,line,column,line_text,underline_len};
+    }
+
+    pub fn from_span(span : &Span) -> SourceLocation {
+        return SourceLocation::new(span.get_input(), span.start(), span.end() - span.start());
+    }
+
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line_num = self.line.to_string();
+        let gutter = " ".repeat(line_num.len());
+        match &self.file_path {
+            None => {
+                writeln!(f, "{}--> {}:{}", gutter, self.line, self.column)?;
+            },
+            Some( path ) => {
+                writeln!(f, "{}--> {}:{}:{}", gutter, path, self.line, self.column)?;
+            }
+        }
+        // keeps tabulations so that the carets are aligned with the snippet
+        let caret_shift : String = self.line_text.chars()
+            .take(self.column - 1)
+            .map(|c| if c == '\t' {'\t'} else {' '})
+            .collect();
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", line_num, self.line_text)?;
+        write!(f, "{} | {}{}", gutter, caret_shift, "^".repeat(self.underline_len))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::io::input::location::SourceLocation;
+
+    #[test]
+    fn location_line_and_column() {
+        let source = "seq(\n\tl1 -- m1 -> l3,\n\tl2 -- m2 ->|\n)";
+        let offset = source.find("l3").unwrap();
+        let location = SourceLocation::new(source, offset, 2);
+        assert_eq!(location.line, 2);
+        assert_eq!(location.column, 14);
+        assert_eq!(location.line_text, "\tl1 -- m1 -> l3,");
+        assert_eq!(location.underline_len, 2);
+        // the underlining stops at the end of the line
+        let location = SourceLocation::new(source, 0, source.len());
+        assert_eq!(location.line, 1);
+        assert_eq!(location.underline_len, 4);
+    }
+}
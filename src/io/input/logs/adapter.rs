@@ -0,0 +1,333 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap};
+
+use serde_json::Value;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::value::{ArgumentType, ValuePattern};
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::htf::implem::complete_canals_up_to_defined_lifelines;
+use crate::io::input::logs::mapping::{LogActionTemplate, LogFormat, LogLineSelector, LogMapping};
+use crate::io::input::logs::template::TextTemplate;
+use crate::io::input::value::check_arguments_against_types;
+
+
+#[derive(Debug)]
+pub enum LogLineIssue {
+    Unmapped, // no rule selects the line
+    Unresolved(HibouParsingError) // e.g. a lifeline or message which is not declared in the signature
+}
+
+pub struct LogAdaptation {
+    pub co_localizations : CoLocalizations,
+    pub multi_trace : MultiTrace,
+    pub mapped : usize,
+    pub ignored : usize,
+    pub issues : Vec<(usize,LogLineIssue)> // with the number of the line, starting from 1
+}
+
+// paths are keys and array indices separated by "."
+pub fn get_json_path_value(object : &Value, path : &str) -> Option<String> {
+    let mut current = object;
+    for key in path.split('.') {
+        let next = match current {
+            Value::Object(fields) => {
+                fields.get(key)
+            },
+            Value::Array(items) => {
+                key.parse::<usize>().ok().and_then(|idx| items.get(idx))
+            },
+            _ => {
+                None
+            }
+        };
+        match next {
+            None => {
+                return None;
+            },
+            Some( got ) => {
+                current = got;
+            }
+        }
+    }
+    match current {
+        Value::String(got_str) => {
+            return Some( got_str.clone() );
+        },
+        _ => {
+            return Some( current.to_string() );
+        }
+    }
+}
+
+fn render_template(template : &TextTemplate,
+                   get_value : &dyn Fn(&str) -> Option<String>) -> Result<String,HibouParsingError> {
+    match template.render(get_value) {
+        Err(name) => {
+            return Err( HibouParsingError::MatchError(format!("no value for placeholder '{}'", name)) );
+        },
+        Ok( rendered ) => {
+            return Ok( rendered );
+        }
+    }
+}
+
+fn argument_from_text(text : &str, arg_type : Option<&ArgumentType>) -> ValuePattern {
+    match arg_type {
+        Some( ArgumentType::Integer ) => {
+            if let Ok(got_int) = text.parse::<i64>() {
+                return ValuePattern::Integer(got_int);
+            }
+        },
+        Some( ArgumentType::Boolean ) => {
+            if let Ok(got_bool) = text.parse::<bool>() {
+                return ValuePattern::Boolean(got_bool);
+            }
+        },
+        _ => {}
+    }
+    // ill-typed values are reported when checked against the signature
+    return ValuePattern::String(text.to_string());
+}
+
+// returns the action together with the name of its canal
+fn action_from_template(gen_ctx : &GeneralContext,
+                        template : &LogActionTemplate,
+                        get_value : &dyn Fn(&str) -> Option<String>) -> Result<(TraceAction,String),HibouParsingError> {
+    let lf_name = render_template(&template.lifeline, get_value)?;
+    let lf_id = match gen_ctx.get_lf_id(&lf_name) {
+        None => {
+            return Err( HibouParsingError::MissingLifelineDeclarationError(lf_name) );
+        },
+        Some( got ) => {
+            got
+        }
+    };
+    let act_kind = match render_template(&template.kind, get_value)?.as_str() {
+        "emission" | "!" => {
+            TraceActionKind::Emission
+        },
+        "reception" | "?" => {
+            TraceActionKind::Reception
+        },
+        other => {
+            return Err( HibouParsingError::MatchError(format!("unknown action kind '{}' (expected 'emission' or 'reception')", other)) );
+        }
+    };
+    let ms_name = render_template(&template.message, get_value)?;
+    let ms_id = match gen_ctx.get_ms_id(&ms_name) {
+        None => {
+            return Err( HibouParsingError::MissingMessageDeclarationError(ms_name) );
+        },
+        Some( got ) => {
+            got
+        }
+    };
+    let arg_types = gen_ctx.get_ms_arg_types(ms_id).unwrap();
+    let mut args = vec![];
+    for (arg_idx,arg_template) in template.arguments.iter().enumerate() {
+        let arg_text = render_template(arg_template, get_value)?;
+        args.push( argument_from_text(&arg_text, arg_types.get(arg_idx)) );
+    }
+    check_arguments_against_types(&ms_name, &args, &arg_types)?;
+    let canal_name = match &template.canal {
+        None => {
+            lf_name
+        },
+        Some( canal_template ) => {
+            render_template(canal_template, get_value)?
+        }
+    };
+    return Ok( (TraceAction::new(lf_id,act_kind,ms_id,args),canal_name) );
+}
+
+// selects the first rule which applies to the line and maps it to an action if it is not ignored
+fn action_from_line(gen_ctx : &GeneralContext,
+                    mapping : &LogMapping,
+                    line : &str) -> Result<Option<Option<(TraceAction,String)>>,HibouParsingError> {
+    let json_line : Option<Value> = match mapping.format {
+        LogFormat::Text => {
+            None
+        },
+        LogFormat::JsonLines => {
+            match serde_json::from_str(line) {
+                Err(e) => {
+                    return Err( HibouParsingError::JsonError(e.to_string()) );
+                },
+                Ok( got ) => {
+                    Some( got )
+                }
+            }
+        }
+    };
+    for rule in &mapping.rules {
+        let values : HashMap<String,String> = match (&rule.selector,&json_line) {
+            (LogLineSelector::Pattern(pattern),_) => {
+                match pattern.captures(line) {
+                    None => {
+                        continue;
+                    },
+                    Some( captures ) => {
+                        // named groups which did not participate in the match have no value
+                        pattern.capture_names().flatten()
+                            .filter_map(|name| captures.name(name).map(|got| (name.to_string(),got.as_str().to_string())))
+                            .collect()
+                    }
+                }
+            },
+            (LogLineSelector::Conditions(conditions),Some(object)) => {
+                if !conditions.iter().all(|(path,expected)| get_json_path_value(object, path).as_ref() == Some(expected)) {
+                    continue;
+                }
+                HashMap::new()
+            },
+            (LogLineSelector::Conditions(_),None) => {
+                continue;
+            }
+        };
+        match &rule.action {
+            None => {
+                return Ok( Some(None) );
+            },
+            Some( template ) => {
+                let get_value = |name : &str| -> Option<String> {
+                    match &json_line {
+                        None => values.get(name).cloned(),
+                        Some( object ) => get_json_path_value(object, name)
+                    }
+                };
+                return action_from_template(gen_ctx, template, &get_value).map(|got| Some(Some(got)));
+            }
+        }
+    }
+    return Ok( None );
+}
+
+pub fn adapt_log(gen_ctx : &GeneralContext,
+                 mapping : &LogMapping,
+                 log_content : &str) -> LogAdaptation {
+    let mut canal_names : Vec<String> = vec![];
+    let mut colocs : Vec<BTreeSet<usize>> = vec![];
+    let mut multi_trace : MultiTrace = vec![];
+    let mut lf_canals : HashMap<usize,usize> = HashMap::new();
+    let mut mapped : usize = 0;
+    let mut ignored : usize = 0;
+    let mut issues = vec![];
+    for (line_idx,line) in log_content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match action_from_line(gen_ctx, mapping, line) {
+            Err(e) => {
+                issues.push( (line_idx + 1, LogLineIssue::Unresolved(e)) );
+            },
+            Ok( None ) => {
+                issues.push( (line_idx + 1, LogLineIssue::Unmapped) );
+            },
+            Ok( Some(None) ) => {
+                ignored += 1;
+            },
+            Ok( Some(Some((action,canal_name))) ) => {
+                let canal_id = match canal_names.iter().position(|name| name == &canal_name) {
+                    None => {
+                        canal_names.push(canal_name.clone());
+                        colocs.push(btreeset!{});
+                        multi_trace.push(vec![]);
+                        canal_names.len() - 1
+                    },
+                    Some( got ) => {
+                        got
+                    }
+                };
+                match lf_canals.get(&action.lf_id) {
+                    Some( other_canal_id ) if *other_canal_id != canal_id => {
+                        let error = HibouParsingError::IllDefinedTraceComponents(
+                            format!("lifeline '{}' occurs on canals '{}' and '{}'",
+                                    gen_ctx.get_lf_name(action.lf_id).unwrap(),
+                                    canal_names[*other_canal_id],
+                                    canal_name));
+                        issues.push( (line_idx + 1, LogLineIssue::Unresolved(error)) );
+                        continue;
+                    },
+                    _ => {}
+                }
+                lf_canals.insert(action.lf_id, canal_id);
+                colocs[canal_id].insert(action.lf_id);
+                multi_trace[canal_id].push(btreeset!{action});
+                mapped += 1;
+            }
+        }
+    }
+    // canals which have been named but on which no action could be mapped are discarded
+    let mut kept_colocs = vec![];
+    let mut kept_multi_trace = vec![];
+    for (coloc,trace) in colocs.into_iter().zip(multi_trace) {
+        if !coloc.is_empty() {
+            kept_colocs.push(coloc);
+            kept_multi_trace.push(trace);
+        }
+    }
+    complete_canals_up_to_defined_lifelines(gen_ctx, &mut kept_colocs, &mut kept_multi_trace);
+    return LogAdaptation{co_localizations:CoLocalizations::new(kept_colocs),
+                         multi_trace:kept_multi_trace,
+                         mapped,
+                         ignored,
+                         issues};
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::io::input::logs::mapping::log_mapping_from_json;
+    use super::*;
+
+    #[test]
+    fn text_lines_are_mapped_with_named_groups() {
+        let mut gen_ctx = GeneralContext::new();
+        gen_ctx.add_lf("client".to_string());
+        gen_ctx.add_lf("server".to_string());
+        gen_ctx.add_msg("req".to_string());
+        let mapping = log_mapping_from_json(&json!({
+            "format" : "text",
+            "rules" : [
+                {"pattern" : "heartbeat$", "ignore" : true},
+                {"pattern" : r"^\S+ \[(?P<host>\w+)\] (?P<lf>\w+) (?:SEND|EMIT) (?P<msg>\w+)", "lifeline" : "{lf}", "kind" : "!", "message" : "{msg}", "canal" : "{host}"},
+                {"pattern" : r"^\S+ \[(?P<host>\w+)\] (?P<lf>\w+) RECV (?P<msg>\w+)", "lifeline" : "{lf}", "kind" : "?", "message" : "{msg}", "canal" : "{host}"}
+            ]
+        })).unwrap();
+        let log = "12:00:01 [n1] client SEND req TO server\n12:00:02 heartbeat\n12:00:03 [n2] server RECV req\n12:00:04 [n2] server CRASH";
+        let adaptation = adapt_log(&gen_ctx, &mapping, log);
+        assert_eq!(adaptation.mapped, 2);
+        assert_eq!(adaptation.ignored, 1);
+        assert_eq!(adaptation.issues.len(), 1);
+        assert_eq!(adaptation.issues[0].0, 4);
+        assert_eq!(adaptation.multi_trace[0], vec![btreeset!{TraceAction::new(0,TraceActionKind::Emission,0,vec![])}]);
+        // placeholders must refer to named groups
+        assert!(log_mapping_from_json(&json!({
+            "format" : "text",
+            "rules" : [ {"pattern" : "(?P<lf>\\w+) SEND", "lifeline" : "{lf}", "kind" : "!", "message" : "{msg}"} ]
+        })).is_err());
+    }
+}
@@ -0,0 +1,198 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use regex::Regex;
+use serde_json::Value;
+
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::json::fields::{get_array_field, get_field, get_str_field};
+use crate::io::input::json::interface::{read_json_file, with_json_file_path};
+use crate::io::input::logs::template::TextTemplate;
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum LogFormat {
+    Text,      // placeholders of templates refer to the named groups of the pattern of the rule
+    JsonLines  // placeholders of templates are paths in the JSON object of the line e.g. "{payload.messages.0.name}"
+}
+
+#[derive(Clone, Debug)]
+pub enum LogLineSelector {
+    Pattern(Regex), // selects the lines in which the regular expression finds a match
+    Conditions(Vec<(String,String)>) // the values at these paths must be equal to these strings
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct LogActionTemplate {
+    pub lifeline : TextTemplate,
+    pub kind : TextTemplate,
+    pub message : TextTemplate,
+    pub arguments : Vec<TextTemplate>,
+    pub canal : Option<TextTemplate> // by default each lifeline is on its own canal
+}
+
+#[derive(Clone, Debug)]
+pub struct LogMappingRule {
+    pub selector : LogLineSelector,
+    pub action : Option<LogActionTemplate> // lines selected by rules without action are ignored
+}
+
+/**
+ * How to turn the lines of a log into the actions of a multi-trace.
+ * The first rule which selects a line is applied.
+ **/
+#[derive(Clone, Debug)]
+pub struct LogMapping {
+    pub format : LogFormat,
+    pub rules : Vec<LogMappingRule>
+}
+
+fn template_field(rule_json : &Value, field : &str) -> Result<TextTemplate,HibouParsingError> {
+    return get_str_field(rule_json, field).and_then(TextTemplate::from_text);
+}
+
+fn selector_from_json(format : &LogFormat, rule_json : &Value) -> Result<LogLineSelector,HibouParsingError> {
+    match format {
+        LogFormat::Text => {
+            let pattern = get_str_field(rule_json, "pattern")?;
+            match Regex::new(pattern) {
+                Err(e) => {
+                    return Err( HibouParsingError::MatchError(format!("invalid pattern '{}' : {}", pattern, e)) );
+                },
+                Ok( regex ) => {
+                    return Ok( LogLineSelector::Pattern(regex) );
+                }
+            }
+        },
+        LogFormat::JsonLines => {
+            let mut conditions = vec![];
+            if let Some(where_json) = rule_json.get("where") {
+                match where_json.as_object() {
+                    None => {
+                        return Err( HibouParsingError::JsonError(format!("field 'where' must be an object in {}", rule_json)) );
+                    },
+                    Some( got_object ) => {
+                        for (path,expected) in got_object {
+                            match expected.as_str() {
+                                None => {
+                                    conditions.push( (path.clone(), expected.to_string()) );
+                                },
+                                Some( got_str ) => {
+                                    conditions.push( (path.clone(), got_str.to_string()) );
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            return Ok( LogLineSelector::Conditions(conditions) );
+        }
+    }
+}
+
+fn action_from_json(rule_json : &Value) -> Result<LogActionTemplate,HibouParsingError> {
+    let lifeline = template_field(rule_json, "lifeline")?;
+    let kind = template_field(rule_json, "kind")?;
+    let message = template_field(rule_json, "message")?;
+    let mut arguments = vec![];
+    for arg_json in get_array_field(rule_json, "arguments", true)? {
+        match arg_json.as_str() {
+            None => {
+                return Err( HibouParsingError::JsonError(format!("field 'arguments' must be an array of strings in {}", rule_json)) );
+            },
+            Some( got_str ) => {
+                arguments.push( TextTemplate::from_text(got_str)? );
+            }
+        }
+    }
+    let canal = match rule_json.get("canal") {
+        None => {
+            None
+        },
+        Some( _ ) => {
+            Some( template_field(rule_json, "canal")? )
+        }
+    };
+    return Ok( LogActionTemplate{lifeline,kind,message,arguments,canal} );
+}
+
+fn rule_from_json(format : &LogFormat, rule_json : &Value) -> Result<LogMappingRule,HibouParsingError> {
+    let selector = selector_from_json(format, rule_json)?;
+    let ignore = match rule_json.get("ignore") {
+        None => {
+            false
+        },
+        Some( Value::Bool(got_bool) ) => {
+            *got_bool
+        },
+        Some( _ ) => {
+            return Err( HibouParsingError::JsonError(format!("field 'ignore' must be a boolean in {}", rule_json)) );
+        }
+    };
+    if ignore {
+        return Ok( LogMappingRule{selector,action:None} );
+    }
+    let action = action_from_json(rule_json)?;
+    // placeholders must be named groups of the pattern of the rule
+    if let LogLineSelector::Pattern(pattern) = &selector {
+        let captured : Vec<&str> = pattern.capture_names().flatten().collect();
+        let mut templates = vec![&action.lifeline,&action.kind,&action.message];
+        templates.extend(action.arguments.iter());
+        templates.extend(action.canal.iter());
+        for template in templates {
+            for name in template.get_placeholders() {
+                if !captured.contains(&name.as_str()) {
+                    return Err( HibouParsingError::JsonError(format!("placeholder '{}' is not a named group of the pattern of {}", name, rule_json)) );
+                }
+            }
+        }
+    }
+    return Ok( LogMappingRule{selector,action:Some(action)} );
+}
+
+pub fn log_mapping_from_json(mapping_json : &Value) -> Result<LogMapping,HibouParsingError> {
+    let format = match get_field(mapping_json, "format").map(|got| got.as_str()) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( Some("text") ) => {
+            LogFormat::Text
+        },
+        Ok( Some("json") ) => {
+            LogFormat::JsonLines
+        },
+        Ok( _ ) => {
+            return Err( HibouParsingError::JsonError("field 'format' must be either 'text' or 'json'".to_string()) );
+        }
+    };
+    let mut rules = vec![];
+    for rule_json in get_array_field(mapping_json, "rules", false)? {
+        rules.push( rule_from_json(&format, rule_json)? );
+    }
+    return Ok( LogMapping{format,rules} );
+}
+
+pub fn parse_log_mapping_file(file_path : &str) -> Result<LogMapping,HibouParsingError> {
+    match read_json_file(file_path) {
+        Err(e) => {
+            return Err(e);
+        },
+        Ok( mapping_json ) => {
+            return with_json_file_path(log_mapping_from_json(&mapping_json), file_path);
+        }
+    }
+}
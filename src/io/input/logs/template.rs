@@ -0,0 +1,139 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::io::input::error::HibouParsingError;
+
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum TemplateSegment {
+    Literal(String),
+    Placeholder(String)
+}
+
+/**
+ * Text with "{name}" placeholders which is rendered by replacing the placeholders with their values.
+ * Literal braces are written "{{" and "}}".
+ **/
+#[derive(Clone, PartialEq, Debug)]
+pub struct TextTemplate {
+    pub segments : Vec<TemplateSegment>
+}
+
+impl TextTemplate {
+
+    pub fn from_text(text : &str) -> Result<TextTemplate,HibouParsingError> {
+        let mut segments : Vec<TemplateSegment> = vec![];
+        let mut literal = String::new();
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    literal.push('{');
+                },
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    literal.push('}');
+                },
+                '{' => {
+                    let mut name = String::new();
+                    loop {
+                        match chars.next() {
+                            None => {
+                                return Err( HibouParsingError::MatchError(format!("unclosed placeholder in template '{}'", text)) );
+                            },
+                            Some('}') => {
+                                break;
+                            },
+                            Some( other ) => {
+                                name.push(other);
+                            }
+                        }
+                    }
+                    let name = name.trim().to_string();
+                    if name.is_empty() {
+                        return Err( HibouParsingError::MatchError(format!("empty placeholder in template '{}'", text)) );
+                    }
+                    if !literal.is_empty() {
+                        segments.push( TemplateSegment::Literal(literal) );
+                        literal = String::new();
+                    }
+                    segments.push( TemplateSegment::Placeholder(name) );
+                },
+                '}' => {
+                    return Err( HibouParsingError::MatchError(format!("unopened placeholder in template '{}'", text)) );
+                },
+                _ => {
+                    literal.push(c);
+                }
+            }
+        }
+        if !literal.is_empty() {
+            segments.push( TemplateSegment::Literal(literal) );
+        }
+        return Ok( TextTemplate{segments} );
+    }
+
+    pub fn get_placeholders(&self) -> Vec<&String> {
+        return self.segments.iter().filter_map(|seg| match seg {
+            TemplateSegment::Placeholder(name) => Some(name),
+            _ => None
+        }).collect();
+    }
+
+    // returns the name of the first placeholder which has no value if any
+    pub fn render(&self, get_value : &dyn Fn(&str) -> Option<String>) -> Result<String,String> {
+        let mut rendered = String::new();
+        for segment in &self.segments {
+            match segment {
+                TemplateSegment::Literal(lit) => {
+                    rendered.push_str(lit);
+                },
+                TemplateSegment::Placeholder(name) => {
+                    match get_value(name) {
+                        None => {
+                            return Err( name.clone() );
+                        },
+                        Some( value ) => {
+                            rendered.push_str(&value);
+                        }
+                    }
+                }
+            }
+        }
+        return Ok( rendered );
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn placeholders_are_rendered() {
+        let template = TextTemplate::from_text("{{{lf}_{host}}}").unwrap();
+        assert_eq!(template.get_placeholders(), vec!["lf","host"]);
+        let rendered = template.render(&|name| Some(name.to_uppercase()));
+        assert_eq!(rendered, Ok("{LF_HOST}".to_string()));
+        assert_eq!(template.render(&|_| None), Err("lf".to_string()));
+        // ***
+        assert!(TextTemplate::from_text("{lf").is_err());
+        assert!(TextTemplate::from_text("lf}").is_err());
+    }
+
+}
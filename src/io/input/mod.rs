@@ -15,10 +15,15 @@ limitations under the License.
 */
 
 pub mod error;
+pub mod location;
+pub mod comments;
+pub mod value;
 pub mod hsf;
 pub mod hcf;
 pub mod htf;
 pub mod hif;
+pub mod json;
+pub mod logs;
 
 
 
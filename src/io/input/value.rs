@@ -0,0 +1,81 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::language::syntax::value::{ArgumentType, ValuePattern};
+use crate::io::input::error::HibouParsingError;
+
+
+// converts the text of a value (or wildcard) which has already been recognized by one of the pest grammars
+// the grammars do not bound the number of digits of integers, which may hence be out of range
+pub fn parse_value_pattern(value_text : &str) -> Result<ValuePattern,HibouParsingError> {
+    if value_text == "_" {
+        return Ok( ValuePattern::Any );
+    }
+    if value_text == "true" {
+        return Ok( ValuePattern::Boolean(true) );
+    }
+    if value_text == "false" {
+        return Ok( ValuePattern::Boolean(false) );
+    }
+    if value_text.starts_with('"') {
+        return Ok( ValuePattern::String( value_text[1..value_text.len()-1].to_string() ) );
+    }
+    match value_text.parse::<i64>() {
+        Err(_) => {
+            return Err( HibouParsingError::MessageArgumentError(
+                format!("integer value '{}' is out of range (from {} to {})", value_text, i64::MIN, i64::MAX) ) );
+        },
+        Ok( value ) => {
+            return Ok( ValuePattern::Integer(value) );
+        }
+    }
+}
+
+// checks the arguments of an action against the types declared for its message
+pub fn check_arguments_against_types(ms_name : &str,
+                                     args : &Vec<ValuePattern>,
+                                     arg_types : &Vec<ArgumentType>) -> Result<(),HibouParsingError> {
+    if args.len() != arg_types.len() {
+        return Err( HibouParsingError::MessageArgumentError(
+            format!("message '{}' expects {} argument(s) but got {}", ms_name, arg_types.len(), args.len()) ) );
+    }
+    for (arg,arg_type) in args.iter().zip(arg_types.iter()) {
+        if !arg.is_of_type(arg_type) {
+            return Err( HibouParsingError::MessageArgumentError(
+                format!("argument '{}' of message '{}' is not of type '{}'", arg, ms_name, arg_type) ) );
+        }
+    }
+    return Ok(());
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arguments_are_checked_against_declared_types() {
+        let args : Vec<ValuePattern> = vec!["404","\"not found\"","_"].into_iter().map(|text| parse_value_pattern(text).unwrap()).collect();
+        assert_eq!(args, vec![ValuePattern::Integer(404),ValuePattern::String("not found".to_string()),ValuePattern::Any]);
+        let arg_types = vec![ArgumentType::Integer,ArgumentType::String,ArgumentType::Boolean];
+        assert!(check_arguments_against_types("KO",&args,&arg_types).is_ok());
+        assert!(check_arguments_against_types("KO",&args[..2].to_vec(),&arg_types).is_err());
+        let wrong_types = vec![ArgumentType::String,ArgumentType::String,ArgumentType::Boolean];
+        assert!(check_arguments_against_types("KO",&args,&wrong_types).is_err());
+    }
+}
@@ -0,0 +1,68 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use itertools::Itertools;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_hfiles::trace::multi_trace::multi_trace_as_htf_encoding;
+use crate::io::output::to_hfiles::trace::trace_action::trace_actions_as_htf_encoding;
+use crate::process::ana::align::alignment::{AlignmentMoveKind, MultiTraceAlignment};
+
+
+// deleted actions are prefixed with "-" and inserted actions with "+"
+pub fn multi_trace_alignment_as_text(gen_ctx : &GeneralContext,
+                                     co_localizations : &CoLocalizations,
+                                     alignment : &MultiTraceAlignment) -> Vec<String> {
+    let mut lines = vec![format!("ALIGNMENT OF COST {}", alignment.cost)];
+    for (canal_id,canal_moves) in alignment.get_canal_alignments(co_localizations).iter().enumerate() {
+        let lf_names : Vec<String> = co_localizations.get_coloc_lfs_ids(canal_id).iter().sorted()
+            .map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap())
+            .collect();
+        let moves_texts : Vec<String> = canal_moves.iter().map(|align_move| {
+            let actions_text = trace_actions_as_htf_encoding(gen_ctx,&align_move.actions);
+            match align_move.kind {
+                AlignmentMoveKind::Synchronous => actions_text,
+                AlignmentMoveKind::Deletion => format!("-{}", actions_text),
+                AlignmentMoveKind::Insertion => format!("+{}", actions_text)
+            }
+        }).collect();
+        lines.push( format!("[{}] {}", lf_names.join(","), moves_texts.join(".")) );
+    }
+    lines.push( "repaired multi-trace :".to_string() );
+    let repaired = alignment.get_repaired_multi_trace(co_localizations);
+    lines.push( multi_trace_as_htf_encoding(gen_ctx,co_localizations,&repaired) );
+    return lines;
+}
+
+// without a bound, the search only fails if the interaction accepts no multi-trace at all
+pub fn alignment_search_result_as_text(gen_ctx : &GeneralContext,
+                                       co_localizations : &CoLocalizations,
+                                       alignment : &Option<MultiTraceAlignment>,
+                                       max_cost : Option<u32>) -> Vec<String> {
+    match (alignment,max_cost) {
+        (Some( got_alignment ),_) => {
+            return multi_trace_alignment_as_text(gen_ctx,co_localizations,got_alignment);
+        },
+        (None,None) => {
+            return vec!["no alignment exists".to_string()];
+        },
+        (None,Some( got_max_cost )) => {
+            return vec![format!("no alignment of cost at most {}", got_max_cost)];
+        }
+    }
+}
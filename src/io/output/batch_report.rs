@@ -0,0 +1,107 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::io::output::to_json::verdict::analysis_result_as_json;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+
+
+pub struct BatchAnalysisRecord {
+    pub file : String,
+    // verdict, node count and elapsed time or the error which prevented the analysis
+    pub outcome : Result<(AnalysisGlobalVerdict,u32,Duration),String>
+}
+
+impl BatchAnalysisRecord {
+    pub fn new(file : String,
+               outcome : Result<(AnalysisGlobalVerdict,u32,Duration),String>) -> BatchAnalysisRecord {
+        return BatchAnalysisRecord{file,outcome};
+    }
+
+    // errors are always below the threshold
+    pub fn is_below(&self, threshold : &AnalysisGlobalVerdict) -> bool {
+        match &self.outcome {
+            Err(_) => {
+                return true;
+            },
+            Ok( (verdict,_,_) ) => {
+                return verdict.is_below(threshold);
+            }
+        }
+    }
+}
+
+fn csv_field(text : &str) -> String {
+    if text.contains([',','"','\n','\r']) {
+        return format!("\"{}\"", text.replace('"',"\"\""));
+    } else {
+        return text.to_string();
+    }
+}
+
+pub fn batch_analysis_as_csv(records : &[BatchAnalysisRecord]) -> String {
+    let mut lines = vec!["file,verdict,inconc_reason,node_count,elapsed,error".to_string()];
+    for record in records {
+        match &record.outcome {
+            Err(e) => {
+                lines.push( format!("{},Error,,,,{}", csv_field(&record.file), csv_field(e)) );
+            },
+            Ok( (verdict,node_count,elapsed) ) => {
+                let reason = match verdict {
+                    AnalysisGlobalVerdict::Inconc(reason) => {
+                        reason.to_string()
+                    },
+                    _ => {
+                        "".to_string()
+                    }
+                };
+                lines.push( format!("{},{},{},{},{},",
+                                    csv_field(&record.file),
+                                    verdict.get_kind_string(),
+                                    reason,
+                                    node_count,
+                                    elapsed.as_secs_f64()) );
+            }
+        }
+    }
+    return lines.join("\n");
+}
+
+// each record is an analysis result with an additional "file" field, or has a "file" and an "error" field
+pub fn batch_analysis_as_json(records : &[BatchAnalysisRecord]) -> Value {
+    let mut items : Vec<Value> = vec![];
+    for record in records {
+        match &record.outcome {
+            Err(e) => {
+                items.push( json!({
+                    "file" : record.file,
+                    "error" : e
+                }) );
+            },
+            Ok( (verdict,node_count,elapsed) ) => {
+                let mut item = analysis_result_as_json(verdict,*node_count,elapsed);
+                item["file"] = json!(record.file);
+                items.push(item);
+            }
+        }
+    }
+    return Value::Array(items);
+}
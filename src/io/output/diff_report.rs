@@ -0,0 +1,114 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::diff::edit::InteractionEdit;
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::output::to_hfiles::interaction::interaction::{interaction_as_hif_encoding, loop_kind_as_text};
+use crate::io::output::to_hfiles::trace::trace_action::trace_actions_as_htf_encoding;
+use crate::io::textual_convention::*;
+
+
+fn diff_position_as_text(position : &Position) -> String {
+    let position = position.to_string();
+    if position.is_empty() {
+        return "root".to_string();
+    } else {
+        return position;
+    }
+}
+
+// the ".hif" encoding on a single line
+fn sub_interaction_as_text(gen_ctx : &GeneralContext, interaction : &Interaction) -> String {
+    return interaction_as_hif_encoding(gen_ctx,interaction).replace(['\n','\t'],"");
+}
+
+// operators are given without their operands
+fn node_label_as_text(gen_ctx : &GeneralContext, label : &Interaction) -> String {
+    match label {
+        Interaction::Strict(_,_) => {
+            return SYNTAX_STRICT.to_string();
+        },
+        Interaction::Seq(_,_) => {
+            return SYNTAX_SEQ.to_string();
+        },
+        Interaction::Alt(_,_) => {
+            return SYNTAX_ALT.to_string();
+        },
+        Interaction::Par(_,_) => {
+            return SYNTAX_PAR.to_string();
+        },
+        Interaction::And(_,_) => {
+            return SYNTAX_AND.to_string();
+        },
+        Interaction::Disrupt(_,_) => {
+            return SYNTAX_DISRUPT.to_string();
+        },
+        Interaction::CoReg(cr,_,_) => {
+            let cr_lfs : Vec<String> = cr.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+            return format!("{}({})", SYNTAX_COREG, cr_lfs.join(","));
+        },
+        Interaction::Sync(sync_acts,_,_) => {
+            let acts_as_set = sync_acts.iter().cloned().collect();
+            return format!("{}{}", SYNTAX_SYNC, trace_actions_as_htf_encoding(gen_ctx,&acts_as_set));
+        },
+        Interaction::Loop(lk,_) => {
+            return loop_kind_as_text(lk).to_string();
+        },
+        Interaction::BoundedLoop(lk,min,max,_) => {
+            return format!("{}[{},{}]", loop_kind_as_text(lk), min, max);
+        },
+        Interaction::Neg(_) => {
+            return SYNTAX_NEG.to_string();
+        },
+        Interaction::Assert(_) => {
+            return SYNTAX_ASSERT.to_string();
+        },
+        _ => {
+            return sub_interaction_as_text(gen_ctx,label);
+        }
+    }
+}
+
+pub fn interaction_edit_as_text(gen_ctx : &GeneralContext, edit : &InteractionEdit) -> String {
+    match edit {
+        InteractionEdit::DeleteNode(pos,label) => {
+            return format!("- at {} : remove {} (keeping its operands)", diff_position_as_text(pos), node_label_as_text(gen_ctx,label));
+        },
+        InteractionEdit::DeleteSubInteraction(pos,sub_int) => {
+            return format!("- at {} : remove {}", diff_position_as_text(pos), sub_interaction_as_text(gen_ctx,sub_int));
+        },
+        InteractionEdit::InsertNode(pos,label) => {
+            return format!("+ at {} : add {} (around existing operands)", diff_position_as_text(pos), node_label_as_text(gen_ctx,label));
+        },
+        InteractionEdit::InsertSubInteraction(pos,sub_int) => {
+            return format!("+ at {} : add {}", diff_position_as_text(pos), sub_interaction_as_text(gen_ctx,sub_int));
+        },
+        InteractionEdit::Relabel(left_pos,right_pos,left_label,right_label) => {
+            return format!("~ at {} -> {} : relabel {} into {}",
+                           diff_position_as_text(left_pos),
+                           diff_position_as_text(right_pos),
+                           node_label_as_text(gen_ctx,left_label),
+                           node_label_as_text(gen_ctx,right_label));
+        }
+    }
+}
+
+pub fn interaction_edits_as_text(gen_ctx : &GeneralContext, edits : &[InteractionEdit]) -> Vec<String> {
+    return edits.iter().map(|edit| interaction_edit_as_text(gen_ctx,edit)).collect();
+}
@@ -25,6 +25,7 @@ use image_colored_text::ttp::TextToPrint;
 use imageproc::drawing::draw_filled_rect_mut;
 use imageproc::rect::Rect;
 
+use crate::core::language::syntax::value::arguments_as_text;
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::action::{EmissionAction, EmissionTargetRef};
 use crate::io::output::draw_commons::font::{get_hibou_font, HIBOU_FONT_SCALE};
@@ -48,7 +49,7 @@ pub fn draw_emission( image : &mut RgbImage,
     // ***
     let msg_to_print : Vec<TextToPrint>;
     {
-        let msg_label = gen_ctx.get_ms_name(em_act.ms_id).unwrap();
+        let msg_label = format!("{}{}", gen_ctx.get_ms_name(em_act.ms_id).unwrap(), arguments_as_text(&em_act.args));
         msg_to_print = vec![TextToPrint::new(msg_label,Rgb(HC_Message))];
     }
     // ***
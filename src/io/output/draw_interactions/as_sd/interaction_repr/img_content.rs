@@ -24,6 +24,7 @@ use imageproc::drawing::draw_line_segment_mut;
 
 
 use crate::core::general_context::GeneralContext;
+use crate::core::language::involve::involves::InvolvesLifelines;
 use crate::core::language::syntax::interaction::{Interaction, LoopKind};
 use crate::core::language::syntax::util::get_recursive_frag::{get_recursive_strict_frags, get_recursive_par_frags, get_recursive_alt_frags, get_recursive_coreg_frags, get_recursive_sync_frags};
 use crate::io::output::draw_commons::font::{get_hibou_font, HIBOU_FONT_SCALE};
@@ -31,6 +32,7 @@ use crate::io::output::draw_commons::hibou_color_palette::HCP_Black;
 use crate::io::output::draw_commons::sd_drawing_conf::*;
 use crate::io::output::draw_interactions::as_sd::action_repr::emission::draw_emission;
 use crate::io::output::draw_interactions::as_sd::action_repr::reception::draw_reception;
+use crate::io::output::draw_interactions::as_sd::interaction_repr::interaction::InteractionDrawingMarks;
 use crate::io::output::draw_interactions::as_sd::util::dimensions_tools::get_y_pos_from_yshift;
 use crate::io::output::draw_interactions::as_sd::util::lf_coords::DrawingLifelineCoords;
 use crate::io::output::draw_traces::implem::trace_action::diagram_repr_trace_actions;
@@ -42,11 +44,32 @@ use crate::io::textual_convention::*;
 pub fn draw_interaction_rec(    image : &mut RgbImage,
                                 gen_ctx : &GeneralContext,
                                 interaction : &Interaction,
+                                marks : &InteractionDrawingMarks,
                                 lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                                 lf_num : usize,
                                 nest_shift : &mut u32,
                                 yshift : &mut u32)
                         -> [usize;2] { // returns left and right borders of the interaction
+    let start_y : u32 = *yshift;
+    let lr_bounds = draw_interaction_node(image,gen_ctx,interaction,marks,lf_x_widths,lf_num,nest_shift,yshift);
+    // highlighted sub-interactions are identified by their address within the drawn interaction
+    if let Some((_,color)) = marks.highlights.iter().find(|(sub_int,_)| std::ptr::eq(*sub_int,interaction)) {
+        draw_highlight_frame(image,*color,*nest_shift,lf_x_widths,lr_bounds[0],lr_bounds[1],start_y,*yshift);
+    }
+    return lr_bounds;
+}
+
+fn draw_interaction_node(    image : &mut RgbImage,
+                             gen_ctx : &GeneralContext,
+                             interaction : &Interaction,
+                             marks : &InteractionDrawingMarks,
+                             lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
+                             lf_num : usize,
+                             nest_shift : &mut u32,
+                             yshift : &mut u32) -> [usize;2] {
+    if let Some(ref_name) = marks.get_collapsed_name(interaction) {
+        return draw_interaction_use(image,interaction,ref_name,lf_x_widths,lf_num,*nest_shift,yshift);
+    }
     match interaction {
         &Interaction::Empty => {
             return [lf_num,0]; // because when going up we keep the minimum on the left and maximum on the right
@@ -62,33 +85,33 @@ pub fn draw_interaction_rec(    image : &mut RgbImage,
             return lr_bounds;
         },
         &Interaction::Seq(ref i1,ref i2) => {
-            let wr1 : [usize;2] = draw_interaction_rec(image, gen_ctx,i1, lf_x_widths,  lf_num,nest_shift, yshift);
+            let wr1 : [usize;2] = draw_interaction_rec(image, gen_ctx,i1,marks,lf_x_widths,  lf_num,nest_shift, yshift);
             *yshift = *yshift +1;
-            let wr2 : [usize;2] = draw_interaction_rec(image,  gen_ctx,i2, lf_x_widths,  lf_num,nest_shift, yshift);
+            let wr2 : [usize;2] = draw_interaction_rec(image, gen_ctx,i2,marks,lf_x_widths,  lf_num,nest_shift, yshift);
             return [ std::cmp::min(wr1[0],wr2[0]) , std::cmp::max(wr1[1],wr2[1]) ];
         },
         &Interaction::Strict(ref i1,ref i2) => {
             let mut frags = get_recursive_strict_frags(i1);
             frags.extend( get_recursive_strict_frags(i2) );
             let label = vec![TextToPrint::new(SYNTAX_STRICT.to_string(),Rgb(HCP_Black))];
-            return draw_n_ary_combined_fragment(image, gen_ctx,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
+            return draw_n_ary_combined_fragment(image,gen_ctx,marks,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
         },
         &Interaction::CoReg(ref cr, ref i1,ref i2) => {
             let mut frags = get_recursive_coreg_frags(cr, i1);
             frags.extend( get_recursive_coreg_frags(cr,i2) );
-            return draw_n_ary_coregion(image, gen_ctx,frags,cr,lf_x_widths, lf_num, nest_shift, yshift);
+            return draw_n_ary_coregion(image,gen_ctx,marks,frags,cr,lf_x_widths, lf_num, nest_shift, yshift);
         },
         &Interaction::Alt(ref i1,ref i2) => {
             let mut frags = get_recursive_alt_frags(i1);
             frags.extend( get_recursive_alt_frags(i2) );
             let label = vec![TextToPrint::new(SYNTAX_ALT.to_string(),Rgb(HCP_Black))];
-            return draw_n_ary_combined_fragment(image, gen_ctx,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
+            return draw_n_ary_combined_fragment(image,gen_ctx,marks,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
         },
         &Interaction::Par(ref i1,ref i2) => {
             let mut frags = get_recursive_par_frags(i1);
             frags.extend( get_recursive_par_frags(i2) );
             let label = vec![TextToPrint::new(SYNTAX_PAR.to_string(),Rgb(HCP_Black))];
-            return draw_n_ary_combined_fragment(image, gen_ctx,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
+            return draw_n_ary_combined_fragment(image,gen_ctx,marks,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
         },
         &Interaction::Sync(ref sync_acts, ref i1,ref i2) => {
             let mut frags = get_recursive_sync_frags(sync_acts,i1);
@@ -96,36 +119,77 @@ pub fn draw_interaction_rec(    image : &mut RgbImage,
             let mut label = vec![TextToPrint::new(SYNTAX_SYNC.to_string(),Rgb(HCP_Black))];
             let sync_acts_as_set = BTreeSet::from_iter(sync_acts.iter().cloned());
             label.append(&mut diagram_repr_trace_actions(&sync_acts_as_set,gen_ctx,true));
-            return draw_n_ary_combined_fragment(image, gen_ctx,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
+            return draw_n_ary_combined_fragment(image,gen_ctx,marks,frags,lf_x_widths, lf_num,label, nest_shift, yshift);
         },
         &Interaction::Loop(ref lkind, ref i1) => {
             match lkind {
                 LoopKind::SStrictSeq => {
                     let label = vec![TextToPrint::new(SYNTAX_LOOP_S.to_string(),Rgb(HCP_Black))];
-                    return draw_unary_combined_fragment(image,  gen_ctx,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
+                    return draw_unary_combined_fragment(image,gen_ctx,marks,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
                 },
                 LoopKind::HHeadFirstWS => {
                     let label = vec![TextToPrint::new(SYNTAX_LOOP_H.to_string(),Rgb(HCP_Black))];
-                    return draw_unary_combined_fragment(image,  gen_ctx,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
+                    return draw_unary_combined_fragment(image,gen_ctx,marks,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
                 },
                 LoopKind::WWeakSeq => {
                     let label = vec![TextToPrint::new(SYNTAX_LOOP_W.to_string(),Rgb(HCP_Black))];
-                    return draw_unary_combined_fragment(image,  gen_ctx,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
+                    return draw_unary_combined_fragment(image,gen_ctx,marks,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
                 },
                 LoopKind::PInterleaving => {
                     let label = vec![TextToPrint::new(SYNTAX_LOOP_P.to_string(),Rgb(HCP_Black))];
-                    return draw_unary_combined_fragment(image,  gen_ctx,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
+                    return draw_unary_combined_fragment(image,gen_ctx,marks,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
                 }
             }
         },
+        &Interaction::BoundedLoop(ref lkind, min, max, ref i1) => {
+            let loop_text = match lkind {
+                LoopKind::SStrictSeq => SYNTAX_LOOP_S,
+                LoopKind::HHeadFirstWS => SYNTAX_LOOP_H,
+                LoopKind::WWeakSeq => SYNTAX_LOOP_W,
+                LoopKind::PInterleaving => SYNTAX_LOOP_P
+            };
+            let label = vec![TextToPrint::new(format!("{}[{},{}]", loop_text, min, max),Rgb(HCP_Black))];
+            return draw_unary_combined_fragment(image,gen_ctx,marks,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
+        },
+        &Interaction::Neg(ref i1) => {
+            let label = vec![TextToPrint::new(SYNTAX_NEG.to_string(),Rgb(HCP_Black))];
+            return draw_unary_combined_fragment(image,gen_ctx,marks,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
+        },
+        &Interaction::Assert(ref i1) => {
+            let label = vec![TextToPrint::new(SYNTAX_ASSERT.to_string(),Rgb(HCP_Black))];
+            return draw_unary_combined_fragment(image,gen_ctx,marks,i1,lf_x_widths, lf_num,label, nest_shift, yshift);
+        },
+        &Interaction::Disrupt(ref i1, ref i2) => {
+            let label = vec![TextToPrint::new(SYNTAX_DISRUPT.to_string(),Rgb(HCP_Black))];
+            return draw_n_ary_combined_fragment(image,gen_ctx,marks,vec![i1,i2],lf_x_widths, lf_num,label, nest_shift, yshift);
+        },
         _ => {
             panic!("non-conform interaction");
         }
     }
 }
 
+fn draw_interaction_use(    image : &mut RgbImage,
+                            interaction : &Interaction,
+                            ref_name : &String,
+                            lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
+                            lf_num : usize,
+                            nest_shift : u32,
+                            yshift : &mut u32) -> [usize;2] {
+    let involved = interaction.involved_lifelines();
+    let lr_bounds : [usize;2] = [ *involved.iter().next().unwrap_or(&lf_num), *involved.iter().last().unwrap_or(&0) ];
+    let start_y : u32 = *yshift;
+    *yshift += 3;
+    let end_y : u32 = *yshift;
+    // draw frame
+    let label = vec![TextToPrint::new(format!("{} {}", SYNTAX_REF, ref_name),Rgb(HCP_Black))];
+    draw_combined_fragment_frame(image, label, nest_shift,lf_x_widths,lr_bounds[0],lr_bounds[1],vec![start_y,end_y]);
+    return lr_bounds;
+}
+
 fn draw_unary_combined_fragment(    image : &mut RgbImage,
                                     gen_ctx : &GeneralContext,
+                                    marks : &InteractionDrawingMarks,
                                     i1 : &Interaction,
                                     lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                                     lf_num : usize,
@@ -136,7 +200,7 @@ fn draw_unary_combined_fragment(    image : &mut RgbImage,
     *nest_shift += 1;
     let start_y : u32 = *yshift;
     *yshift += 3;
-    let lr_bounds : [usize;2] = draw_interaction_rec(image,  gen_ctx,i1, lf_x_widths,  lf_num,nest_shift, yshift);
+    let lr_bounds : [usize;2] = draw_interaction_rec(image, gen_ctx,i1,marks,lf_x_widths,  lf_num,nest_shift, yshift);
     *yshift += 1;
     let end_y : u32 = *yshift;
     *nest_shift -= 1;
@@ -148,6 +212,7 @@ fn draw_unary_combined_fragment(    image : &mut RgbImage,
 
 fn draw_n_ary_combined_fragment(  image : &mut RgbImage,
                                   gen_ctx : &GeneralContext,
+                                  marks : &InteractionDrawingMarks,
                                   sub_ints : Vec<&Interaction>,
                                   lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
                                   lf_num : usize,
@@ -164,7 +229,7 @@ fn draw_n_ary_combined_fragment(  image : &mut RgbImage,
     let mut max_lf_id : usize = 0;
     for my_int in sub_ints {
         *yshift += 1;
-        let lr_bounds = draw_interaction_rec(image,  gen_ctx,my_int, lf_x_widths,  lf_num,nest_shift, yshift);
+        let lr_bounds = draw_interaction_rec(image, gen_ctx,my_int,marks,lf_x_widths,  lf_num,nest_shift, yshift);
         min_lf_id = cmp::min( min_lf_id, lr_bounds[0]);
         max_lf_id = cmp::max( max_lf_id, lr_bounds[1]);
         *yshift += 1;
@@ -180,6 +245,7 @@ fn draw_n_ary_combined_fragment(  image : &mut RgbImage,
 
 fn draw_n_ary_coregion(  image : &mut RgbImage,
                                   gen_ctx : &GeneralContext,
+                                  marks : &InteractionDrawingMarks,
                                   sub_ints : Vec<&Interaction>,
                                   coreg_ids : &Vec<usize>,
                                   lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
@@ -196,7 +262,7 @@ fn draw_n_ary_coregion(  image : &mut RgbImage,
     let mut max_lf_id : usize = 0;
     for my_int in sub_ints {
         *yshift += 1;
-        let lr_bounds = draw_interaction_rec(image,  gen_ctx,my_int, lf_x_widths,  lf_num,nest_shift, yshift);
+        let lr_bounds = draw_interaction_rec(image, gen_ctx,my_int,marks,lf_x_widths,  lf_num,nest_shift, yshift);
         min_lf_id = cmp::min( min_lf_id, lr_bounds[0]);
         max_lf_id = cmp::max( max_lf_id, lr_bounds[1]);
         *yshift += 1;
@@ -210,6 +276,33 @@ fn draw_n_ary_coregion(  image : &mut RgbImage,
     return lr_bounds;
 }
 
+fn draw_highlight_frame(    image : &mut RgbImage,
+                            color : Rgb<u8>,
+                            nest_shift : u32,
+                            lf_x_widths : &HashMap<usize,DrawingLifelineCoords>,
+                            left_bound : usize,
+                            right_bound : usize,
+                            start_y : u32,
+                            end_y : u32) {
+    match (lf_x_widths.get(&left_bound), lf_x_widths.get(&right_bound)) {
+        (Some(left_lf_coords),Some(right_lf_coords)) => {
+            // drawn just outside of the frame of the fragment (if any) and twice as thick
+            let x_left : f32 = left_lf_coords.x_start + (nest_shift as f32)*FRAGMENT_PADDING - FRAGMENT_PADDING/2.0;
+            let x_right : f32 = (right_lf_coords.x_start + right_lf_coords.x_span_outer) - (nest_shift as f32)*FRAGMENT_PADDING + FRAGMENT_PADDING/2.0;
+            let y_start : f32 = get_y_pos_from_yshift(start_y) - FRAGMENT_PADDING/2.0;
+            let y_end : f32 = get_y_pos_from_yshift(end_y) + FRAGMENT_PADDING/2.0;
+            for shift in [0.0,1.0] {
+                let (x1,x2,y1,y2) = (x_left - shift, x_right + shift, y_start - shift, y_end + shift);
+                draw_line_segment_mut(image, (x1, y1), (x1, y2), color);
+                draw_line_segment_mut(image, (x2, y1), (x2, y2), color);
+                draw_line_segment_mut(image, (x1, y1), (x2, y1), color);
+                draw_line_segment_mut(image, (x1, y2), (x2, y2), color);
+            }
+        },
+        _ => {}
+    }
+}
+
 fn draw_combined_fragment_frame(    image : &mut RgbImage,
                                     label : Vec<TextToPrint>,
                                     nest_shift : u32,
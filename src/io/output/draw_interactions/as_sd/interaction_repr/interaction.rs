@@ -17,7 +17,7 @@ limitations under the License.
 
 use std::collections::HashMap;
 
-use image::RgbImage;
+use image::{Rgb, RgbImage};
 
 use crate::core::general_context::GeneralContext;
 use crate::core::language::involve::involves::InvolvesLifelines;
@@ -29,8 +29,34 @@ use crate::io::output::draw_interactions::as_sd::util::dimensions_tools::get_int
 use crate::io::output::draw_interactions::as_sd::util::lf_coords::DrawingLifelineCoords;
 
 
+// sub-interactions which are drawn in a particular manner
+// they are identified by their address and hence must be borrowed from the drawn interaction
+pub struct InteractionDrawingMarks<'a> {
+    // framed with the given colors
+    pub highlights : Vec<(&'a Interaction,Rgb<u8>)>,
+    // drawn collapsed as interaction uses with the given names
+    pub collapsed : Vec<(&'a Interaction,String)>
+}
+
+impl<'a> InteractionDrawingMarks<'a> {
+    pub fn new(highlights : Vec<(&'a Interaction,Rgb<u8>)>,
+               collapsed : Vec<(&'a Interaction,String)>) -> InteractionDrawingMarks<'a> {
+        return InteractionDrawingMarks{highlights,collapsed};
+    }
+
+    pub fn get_collapsed_name(&self, interaction : &Interaction) -> Option<&String> {
+        return self.collapsed.iter().find(|(sub_int,_)| std::ptr::eq(*sub_int,interaction)).map(|(_,name)| name);
+    }
+}
+
 pub fn make_interaction_image(gen_ctx : &GeneralContext,
                               interaction : &Interaction) -> RgbImage {
+    return make_interaction_image_with_marks(gen_ctx,interaction,&InteractionDrawingMarks::new(vec![],vec![]));
+}
+
+pub fn make_interaction_image_with_marks(gen_ctx : &GeneralContext,
+                                         interaction : &Interaction,
+                                         marks : &InteractionDrawingMarks) -> RgbImage {
     // ***
     let mut lf_x_widths : HashMap<usize,DrawingLifelineCoords> = HashMap::new();
     let mut current_x : f32 = MARGIN;
@@ -49,7 +75,7 @@ pub fn make_interaction_image(gen_ctx : &GeneralContext,
         }
     }
     // ***
-    let max_y_shift = get_interaction_max_yshift(interaction);
+    let max_y_shift = get_interaction_max_yshift(marks,interaction);
     let mut inner_height : f32 = (max_y_shift as f32)*VERTICAL_SIZE;
     // ***
     let img_width : f32 = current_x;
@@ -65,7 +91,7 @@ pub fn make_interaction_image(gen_ctx : &GeneralContext,
     // Draw Fragments
     let mut nest_shift : u32 = 1; // shift to display nested fragments
     let mut yshift : u32 = 3;
-    draw_interaction_rec(&mut image,  gen_ctx, interaction, &lf_x_widths, gen_ctx.get_lf_num(), &mut nest_shift, &mut yshift);
+    draw_interaction_rec(&mut image,  gen_ctx, interaction, marks, &lf_x_widths, gen_ctx.get_lf_num(), &mut nest_shift, &mut yshift);
     // ***
     return image;
 }
\ No newline at end of file
@@ -16,9 +16,20 @@ limitations under the License.
 
 
 use std::path::PathBuf;
+
+use image::{Rgb, RgbImage};
+use image::imageops::replace;
+use imageproc::drawing::{draw_filled_rect_mut, draw_line_segment_mut};
+use imageproc::rect::Rect;
+
 use crate::core::general_context::GeneralContext;
+use crate::core::language::diff::edit::InteractionEdit;
+use crate::core::language::position::position::Position;
+use crate::core::language::position::sub_interaction::get_sub_interaction_at_position;
 use crate::core::language::syntax::interaction::Interaction;
-use crate::io::output::draw_interactions::as_sd::interaction_repr::interaction::make_interaction_image;
+use crate::io::output::draw_commons::hibou_color_palette::{HCP_Black, HCP_LightGreen, HCP_LightOrange, HCP_LightRed, HCP_White};
+use crate::io::output::draw_commons::sd_drawing_conf::MARGIN;
+use crate::io::output::draw_interactions::as_sd::interaction_repr::interaction::{InteractionDrawingMarks, make_interaction_image, make_interaction_image_with_marks};
 
 
 pub fn draw_int_as_sd(gen_ctx : &GeneralContext,
@@ -32,5 +43,61 @@ pub fn draw_int_as_sd(gen_ctx : &GeneralContext,
     image.save(output_path.as_path());
 }
 
+// the sub-interactions at the positions of the references are drawn collapsed as interaction uses
+pub fn draw_int_with_references_as_sd(gen_ctx : &GeneralContext,
+                                      interaction : &Interaction,
+                                      references : &[(Position,String)],
+                                      parent_folder : &String,
+                                      output_file_name : &String) {
+    let collapsed : Vec<(&Interaction,String)> = references.iter()
+        .filter_map(|(pos,ref_name)| get_sub_interaction_at_position(interaction,pos).map(|sub_int| (sub_int,ref_name.clone())))
+        .collect();
+    let output_file_name = format!("{:}.png", output_file_name);
+    let output_path : PathBuf = [parent_folder, &output_file_name].iter().collect();
+    let image = make_interaction_image_with_marks(gen_ctx,interaction,&InteractionDrawingMarks::new(vec![],collapsed));
+    image.save(output_path.as_path());
+}
 
-
+// the left interaction is drawn on the left with removed fragments in red
+// and the right interaction on the right with added fragments in green
+// relabelled nodes are drawn in orange on both sides
+pub fn draw_int_diff_as_sd(gen_ctx : &GeneralContext,
+                           left_int : &Interaction,
+                           right_int : &Interaction,
+                           edits : &Vec<InteractionEdit>,
+                           parent_folder : &String,
+                           output_file_name : &String) {
+    let mut left_highlights : Vec<(&Interaction,Rgb<u8>)> = vec![];
+    let mut right_highlights : Vec<(&Interaction,Rgb<u8>)> = vec![];
+    for edit in edits {
+        let color = match edit {
+            InteractionEdit::DeleteNode(_,_) | InteractionEdit::DeleteSubInteraction(_,_) => Rgb(HCP_LightRed),
+            InteractionEdit::InsertNode(_,_) | InteractionEdit::InsertSubInteraction(_,_) => Rgb(HCP_LightGreen),
+            InteractionEdit::Relabel(_,_,_,_) => Rgb(HCP_LightOrange)
+        };
+        if let Some(sub_int) = edit.get_left_position().and_then(|pos| get_sub_interaction_at_position(left_int,pos)) {
+            left_highlights.push((sub_int,color));
+        }
+        if let Some(sub_int) = edit.get_right_position().and_then(|pos| get_sub_interaction_at_position(right_int,pos)) {
+            right_highlights.push((sub_int,color));
+        }
+    }
+    let left_image = make_interaction_image_with_marks(gen_ctx,left_int,&InteractionDrawingMarks::new(left_highlights,vec![]));
+    let right_image = make_interaction_image_with_marks(gen_ctx,right_int,&InteractionDrawingMarks::new(right_highlights,vec![]));
+    // ***
+    let separator_x = left_image.width() + (MARGIN as u32);
+    let img_width = separator_x + (MARGIN as u32) + right_image.width();
+    let img_height = std::cmp::max(left_image.height(),right_image.height());
+    let mut image = RgbImage::new(img_width,img_height);
+    draw_filled_rect_mut(&mut image, Rect::at(0,0).of_size(img_width,img_height), Rgb(HCP_White));
+    replace(&mut image, &left_image, 0, 0);
+    draw_line_segment_mut(&mut image,
+                          (separator_x as f32, 0.0),
+                          (separator_x as f32, img_height as f32),
+                          Rgb(HCP_Black));
+    replace(&mut image, &right_image, (separator_x + (MARGIN as u32)) as i64, 0);
+    // ***
+    let output_file_name = format!("{:}.png", output_file_name);
+    let output_path : PathBuf = [parent_folder, &output_file_name].iter().collect();
+    image.save(output_path.as_path());
+}
@@ -18,15 +18,20 @@ limitations under the License.
 use crate::core::language::syntax::interaction::Interaction;
 use crate::core::language::syntax::util::get_recursive_frag::*;
 use crate::io::output::draw_commons::sd_drawing_conf::*;
+use crate::io::output::draw_interactions::as_sd::interaction_repr::interaction::InteractionDrawingMarks;
 
-pub fn get_interaction_max_yshift(interaction : &Interaction) -> usize {
+pub fn get_interaction_max_yshift(marks : &InteractionDrawingMarks, interaction : &Interaction) -> usize {
     let mut cpt = 4;
-    cpt += get_interaction_depth(interaction);
+    cpt += get_interaction_depth(marks,interaction);
     return cpt;
 }
 
 
-fn get_interaction_depth(interaction : &Interaction ) -> usize {
+fn get_interaction_depth(marks : &InteractionDrawingMarks, interaction : &Interaction ) -> usize {
+    if marks.get_collapsed_name(interaction).is_some() {
+        // collapsed as an interaction use
+        return 3;
+    }
     match interaction {
         &Interaction::Empty => {
             return  0;
@@ -42,7 +47,7 @@ fn get_interaction_depth(interaction : &Interaction ) -> usize {
             frags.extend( get_recursive_strict_frags(i2) );
             let mut sum : usize = 2;
             for frag in frags {
-                sum = sum + get_interaction_depth(frag) + 2;
+                sum = sum + get_interaction_depth(marks,frag) + 2;
             }
             return sum;
         },
@@ -51,19 +56,19 @@ fn get_interaction_depth(interaction : &Interaction ) -> usize {
             frags.extend( get_recursive_coreg_frags(cr, i2) );
             let mut sum : usize = 0;
             for frag in frags {
-                sum = sum + get_interaction_depth(frag) + 2;
+                sum = sum + get_interaction_depth(marks,frag) + 2;
             }
             return sum;
         },
         &Interaction::Seq(ref i1, ref i2) => {
-            return get_interaction_depth(i1) + get_interaction_depth(i2) + 1;
+            return get_interaction_depth(marks,i1) + get_interaction_depth(marks,i2) + 1;
         },
         &Interaction::Alt(ref i1, ref i2) => {
             let mut frags = get_recursive_alt_frags(i1);
             frags.extend( get_recursive_alt_frags(i2) );
             let mut sum : usize = 2;
             for frag in frags {
-                sum = sum + get_interaction_depth(frag) + 2;
+                sum = sum + get_interaction_depth(marks,frag) + 2;
             }
             return sum;
         },
@@ -72,7 +77,7 @@ fn get_interaction_depth(interaction : &Interaction ) -> usize {
             frags.extend( get_recursive_par_frags(i2) );
             let mut sum : usize = 2;
             for frag in frags {
-                sum = sum + get_interaction_depth(frag) + 2;
+                sum = sum + get_interaction_depth(marks,frag) + 2;
             }
             return sum;
         },
@@ -81,15 +86,27 @@ fn get_interaction_depth(interaction : &Interaction ) -> usize {
             frags.extend( get_recursive_sync_frags(sync_acts, i2) );
             let mut sum : usize = 2;
             for frag in frags {
-                sum = sum + get_interaction_depth(frag) + 2;
+                sum = sum + get_interaction_depth(marks,frag) + 2;
             }
             return sum;
         },
         &Interaction::Loop(_, ref i1) => {
-            return get_interaction_depth(i1) + 4;
+            return get_interaction_depth(marks,i1) + 4;
+        },
+        &Interaction::BoundedLoop(_, _, _, ref i1) => {
+            return get_interaction_depth(marks,i1) + 4;
+        },
+        &Interaction::Neg(ref i1) => {
+            return get_interaction_depth(marks,i1) + 4;
+        },
+        &Interaction::Assert(ref i1) => {
+            return get_interaction_depth(marks,i1) + 4;
+        },
+        &Interaction::Disrupt(ref i1, ref i2) => {
+            return 6 + get_interaction_depth(marks,i1) + get_interaction_depth(marks,i2);
         },
         &Interaction::And(ref i1, ref i2) => {
-            return 6 + get_interaction_depth(i1) + get_interaction_depth(i2);
+            return 6 + get_interaction_depth(marks,i1) + get_interaction_depth(marks,i2);
         }
     }
 }
@@ -15,6 +15,7 @@ limitations under the License.
 */
 
 
+use crate::core::language::syntax::value::arguments_as_text;
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction, EmissionTargetRef};
 use crate::io::textual_convention::{SYNTAX_EMISSION, SYNTAX_EMISSION_SYNCHRONOUS};
@@ -23,7 +24,7 @@ use crate::io::textual_convention::{SYNTAX_EMISSION, SYNTAX_EMISSION_SYNCHRONOUS
 pub fn emission_as_gv_label(gen_ctx : &GeneralContext,
                     em_act : &EmissionAction) -> String {
     // ***
-    let ms_name = gen_ctx.get_ms_name(em_act.ms_id).unwrap();
+    let ms_name = format!("{}{}", gen_ctx.get_ms_name(em_act.ms_id).unwrap(), arguments_as_text(&em_act.args));
     let lf_name = gen_ctx.get_lf_name(em_act.origin_lf_id).unwrap();
     // ***
     let mut targ_names : Vec<String> = Vec::new();
@@ -15,6 +15,7 @@ limitations under the License.
 */
 
 
+use crate::core::language::syntax::value::arguments_as_text;
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::action::{CommunicationSynchronicity, ReceptionAction};
 use crate::io::textual_convention::{SYNTAX_RECEPTION, SYNTAX_RECEPTION_SYNCHRONOUS};
@@ -23,7 +24,7 @@ use crate::io::textual_convention::{SYNTAX_RECEPTION, SYNTAX_RECEPTION_SYNCHRONO
 pub fn reception_as_gv_label (gen_ctx : &GeneralContext,
                     rc_act : &ReceptionAction) -> String {
     // ***
-    let ms_name = gen_ctx.get_ms_name(rc_act.ms_id).unwrap();
+    let ms_name = format!("{}{}", gen_ctx.get_ms_name(rc_act.ms_id).unwrap(), arguments_as_text(&rc_act.args));
     // ***
     let mut targ_names : Vec<String> = Vec::new();
     for rcp_lf_id in &rc_act.recipients {
@@ -15,6 +15,7 @@ limitations under the License.
 */
 
 
+use crate::core::language::syntax::value::arguments_as_text;
 use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
 use crate::core::general_context::GeneralContext;
 use crate::io::textual_convention::{SYNTAX_EMISSION,SYNTAX_RECEPTION};
@@ -33,7 +34,7 @@ pub fn trace_action_as_gv_label (gen_ctx : &GeneralContext,
         }
     }
     // ***
-    let ms_name = gen_ctx.get_ms_name(tr_act.ms_id).unwrap();
+    let ms_name = format!("{}{}", gen_ctx.get_ms_name(tr_act.ms_id).unwrap(), arguments_as_text(&tr_act.args));
     // ***
     return format!("{}{}{}",lf_name,act_kind_label,ms_name);
 }
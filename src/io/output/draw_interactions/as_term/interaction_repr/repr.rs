@@ -27,7 +27,7 @@ use crate::core::language::position::position::Position;
 use crate::io::output::draw_interactions::as_term::action_repr::emission::{emission_as_gv_label};
 use crate::io::output::draw_interactions::as_term::action_repr::reception::{reception_as_gv_label};
 use crate::io::output::draw_interactions::as_term::action_repr::trace_action::trace_actions_as_gv_label;
-use crate::io::textual_convention::{SYNTAX_ALT, SYNTAX_COREG, SYNTAX_LOOP_H, SYNTAX_LOOP_P, SYNTAX_LOOP_S, SYNTAX_LOOP_W, SYNTAX_PAR, SYNTAX_SEQ, SYNTAX_STRICT, SYNTAX_SYNC, SYNTAX_AND};
+use crate::io::textual_convention::{SYNTAX_ALT, SYNTAX_COREG, SYNTAX_LOOP_H, SYNTAX_LOOP_P, SYNTAX_LOOP_S, SYNTAX_LOOP_W, SYNTAX_PAR, SYNTAX_SEQ, SYNTAX_STRICT, SYNTAX_SYNC, SYNTAX_AND, SYNTAX_NEG, SYNTAX_ASSERT, SYNTAX_DISRUPT};
 
 
 pub fn interaction_gv_repr(gen_ctx : &GeneralContext,
@@ -117,6 +117,24 @@ fn interaction_gv_repr_rec(gen_ctx : &GeneralContext,
                 gv_graph.add_edge( gv_edge );
             }
         },
+        &Interaction::BoundedLoop(ref lp_kind, min, max, ref i1) => {
+            let loop_text = match lp_kind {
+                &LoopKind::SStrictSeq => SYNTAX_LOOP_S,
+                &LoopKind::HHeadFirstWS => SYNTAX_LOOP_H,
+                &LoopKind::WWeakSeq => SYNTAX_LOOP_W,
+                &LoopKind::PInterleaving => SYNTAX_LOOP_P
+            };
+            repr_unary_operator(gen_ctx, i1, &format!("{}[{},{}]", loop_text, min, max), current_pos, gv_graph);
+        },
+        &Interaction::Neg(ref i1) => {
+            repr_unary_operator(gen_ctx, i1, SYNTAX_NEG, current_pos, gv_graph);
+        },
+        &Interaction::Assert(ref i1) => {
+            repr_unary_operator(gen_ctx, i1, SYNTAX_ASSERT, current_pos, gv_graph);
+        },
+        &Interaction::Disrupt(ref i1, ref i2) => {
+            repr_binary_operator(gen_ctx, i1, i2, SYNTAX_DISRUPT, current_pos, gv_graph);
+        },
         &Interaction::And(ref i1, ref i2) => {
             repr_binary_operator(gen_ctx, i1, i2, SYNTAX_AND, current_pos, gv_graph);
         }
@@ -124,6 +142,32 @@ fn interaction_gv_repr_rec(gen_ctx : &GeneralContext,
     return node_name;
 }
 
+fn repr_unary_operator(gen_ctx : &GeneralContext,
+                       i1 : &Interaction,
+                       operator_label : &str,
+                       current_pos : Position,
+                       gv_graph : &mut GraphVizDiGraph) {
+    let node_name = format!("p{}",current_pos.to_string());
+    // the parent node
+    {
+        let mut parent_node_gv_options : GraphvizNodeStyle = Vec::new();
+        parent_node_gv_options.push( GraphvizNodeStyleItem::Shape(GvNodeShape::PlainText) );
+        parent_node_gv_options.push( GraphvizNodeStyleItem::Label( operator_label.to_string() ) );
+        gv_graph.add_node( GraphVizNode::new(node_name.clone(), parent_node_gv_options) );
+    }
+    // then the left sub-interaction
+    {
+        let left_position = Position::Left(Box::new(current_pos));
+        let child_node_name = interaction_gv_repr_rec(gen_ctx,i1,left_position, gv_graph);
+        let gv_edge = GraphVizEdge::new(node_name,
+                                        None,
+                                        child_node_name,
+                                        None,
+                                        vec![ GraphvizEdgeStyleItem::Head( GvArrowHeadStyle::NoArrow )]);
+        gv_graph.add_edge(gv_edge);
+    }
+}
+
 fn repr_binary_operator(gen_ctx : &GeneralContext,
                         i1 : &Interaction,
                         i2 : &Interaction,
@@ -17,8 +17,10 @@ limitations under the License.
 
 
 use crate::core::general_context::GeneralContext;
+use crate::core::language::diff::edit::InteractionEdit;
+use crate::core::language::position::position::Position;
 use crate::core::language::syntax::interaction::Interaction;
-use crate::io::output::draw_interactions::as_sd::interface::draw_int_as_sd;
+use crate::io::output::draw_interactions::as_sd::interface::{draw_int_as_sd, draw_int_diff_as_sd, draw_int_with_references_as_sd};
 use crate::io::output::draw_interactions::as_term::interface::draw_int_as_term;
 
 
@@ -43,4 +45,23 @@ pub fn draw_interaction(gen_ctx : &GeneralContext,
             draw_int_as_term(gen_ctx,int,temp_folder,parent_folder,output_file_name);
         }
     }
-}
\ No newline at end of file
+}
+
+// as a sequence diagram in which the references to named sub-interactions (given by their positions) are collapsed
+pub fn draw_interaction_with_references(gen_ctx : &GeneralContext,
+                                        int : &Interaction,
+                                        references : &[(Position,String)],
+                                        parent_folder : &String,
+                                        output_file_name : &String) {
+    draw_int_with_references_as_sd(gen_ctx,int,references,parent_folder,output_file_name);
+}
+
+// both interactions side by side as sequence diagrams, with edited fragments highlighted
+pub fn draw_interaction_diff(gen_ctx : &GeneralContext,
+                             left_int : &Interaction,
+                             right_int : &Interaction,
+                             edits : &Vec<InteractionEdit>,
+                             parent_folder : &String,
+                             output_file_name : &String) {
+    draw_int_diff_as_sd(gen_ctx,left_int,right_int,edits,parent_folder,output_file_name);
+}
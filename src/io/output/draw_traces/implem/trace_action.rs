@@ -21,6 +21,7 @@ use image::Rgb;
 use image_colored_text::ttp::TextToPrint;
 use itertools::Itertools;
 
+use crate::core::language::syntax::value::arguments_as_text;
 use crate::core::general_context::GeneralContext;
 use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
 use crate::io::output::draw_commons::hibou_color_palette::{HC_Grammar_Symbol, HC_Lifeline, HC_Message};
@@ -45,7 +46,7 @@ pub fn diagram_repr_trace_action(action : &TraceAction, gen_ctx : &GeneralContex
     }
     // ***
     {
-        let ms_name = gen_ctx.get_ms_name(action.ms_id).unwrap();
+        let ms_name = format!("{}{}", gen_ctx.get_ms_name(action.ms_id).unwrap(), arguments_as_text(&action.args));
         to_print.push( TextToPrint::new(ms_name,Rgb(HC_Message)) );
     }
     // ***
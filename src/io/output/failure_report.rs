@@ -0,0 +1,108 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::semantics::frontier::FrontierElement;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_hfiles::interaction::interaction::interaction_as_hif_encoding;
+use crate::io::output::to_hfiles::trace::trace_action::trace_actions_as_htf_encoding;
+use crate::process::ana::loggers::diag::diagnosis::AnalysisFailureDiagnosis;
+
+
+fn frontier_element_as_text(gen_ctx : &GeneralContext, frt_elt : &FrontierElement) -> String {
+    let position = frt_elt.position.to_string();
+    let position = if position.is_empty() {
+        "root".to_string()
+    } else {
+        position
+    };
+    return format!("{} at {}", trace_actions_as_htf_encoding(gen_ctx,&frt_elt.target_actions), position);
+}
+
+fn canal_lifelines_as_text(gen_ctx : &GeneralContext, lf_ids : &BTreeSet<usize>) -> String {
+    let lf_names : Vec<String> = lf_ids.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+    return format!("[{}]", lf_names.join(","));
+}
+
+// canals and actions are numbered from 1 as they appear in the ".htf" file
+// positions refer to the interaction reached when the analysis went the furthest
+// there is a diagnosis for each of the distinct nodes which consumed the most actions
+pub fn analysis_failure_diagnoses_as_text(gen_ctx : &GeneralContext,
+                                          co_localizations : &CoLocalizations,
+                                          htf_file_path : &str,
+                                          diagnoses : &[AnalysisFailureDiagnosis]) -> Vec<String> {
+    let mut lines = vec![];
+    for (diag_idx,diagnosis) in diagnoses.iter().enumerate() {
+        if diag_idx > 0 {
+            lines.push( "".to_string() );
+        }
+        if diagnoses.len() == 1 {
+            lines.push( "FAILURE DIAGNOSIS".to_string() );
+        } else {
+            lines.push( format!("FAILURE DIAGNOSIS {} OF {}", diag_idx + 1, diagnoses.len()) );
+        }
+        lines.append( &mut analysis_failure_diagnosis_as_text(gen_ctx,co_localizations,htf_file_path,diagnosis) );
+    }
+    return lines;
+}
+
+fn analysis_failure_diagnosis_as_text(gen_ctx : &GeneralContext,
+                                      co_localizations : &CoLocalizations,
+                                      htf_file_path : &str,
+                                      diagnosis : &AnalysisFailureDiagnosis) -> Vec<String> {
+    let mut lines = vec![];
+    for canal in &diagnosis.canals {
+        lines.push( format!("canal {} {} : consumed {} out of {} actions",
+                            canal.canal_id + 1,
+                            canal_lifelines_as_text(gen_ctx, co_localizations.get_coloc_lfs_ids(canal.canal_id)),
+                            canal.consumed,
+                            canal.length) );
+        if let Some(head) = &canal.head {
+            let head_status = if canal.head_expected {
+                "not reached"
+            } else {
+                "unmatched"
+            };
+            lines.push( format!("    {} {} at action {} of canal {} in '{}'",
+                                head_status,
+                                trace_actions_as_htf_encoding(gen_ctx,head),
+                                canal.consumed + 1,
+                                canal.canal_id + 1,
+                                htf_file_path) );
+        }
+        for frt_elt in &canal.value_mismatches {
+            lines.push( format!("    values differ from expected {}", frontier_element_as_text(gen_ctx,frt_elt)) );
+        }
+    }
+    if diagnosis.pruned_by_local_analysis {
+        lines.push( "the deepest node was discarded by a local analysis".to_string() );
+    }
+    if diagnosis.expected.is_empty() {
+        lines.push( "expected no further action".to_string() );
+    } else {
+        lines.push( "expected one of".to_string() );
+        for frt_elt in &diagnosis.expected {
+            lines.push( format!("    {}", frontier_element_as_text(gen_ctx,frt_elt)) );
+        }
+    }
+    lines.push( "in residual interaction".to_string() );
+    lines.push( format!("    {}", interaction_as_hif_encoding(gen_ctx,&diagnosis.interaction).replace(['\n','\t'],"")) );
+    return lines;
+}
@@ -0,0 +1,65 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::lint::warning::{LintWarning, LintWarningKind};
+use crate::io::output::to_hfiles::trace::trace_action::trace_actions_as_htf_encoding;
+
+
+pub fn lint_warning_message(gen_ctx : &GeneralContext, warning : &LintWarning) -> String {
+    match &warning.kind {
+        LintWarningKind::UnusedLifeline(lf_id) => {
+            return format!("lifeline '{}' is declared but never used", gen_ctx.get_lf_name(*lf_id).unwrap());
+        },
+        LintWarningKind::UnusedMessage(ms_id) => {
+            return format!("message '{}' is declared but never used", gen_ctx.get_ms_name(*ms_id).unwrap());
+        },
+        LintWarningKind::UnusedGate(gt_id) => {
+            return format!("gate '{}' is declared but never used", gen_ctx.get_gt_name(*gt_id).unwrap());
+        },
+        LintWarningKind::UselessSyncActions(actions) => {
+            let as_set : BTreeSet<_> = actions.iter().cloned().collect();
+            return format!("synchronized actions {} never occur in either operand", trace_actions_as_htf_encoding(gen_ctx, &as_set));
+        },
+        LintWarningKind::EmptyAnd => {
+            return "operands of 'and' have no trace in common".to_string();
+        },
+        LintWarningKind::EmptyLoopBody => {
+            return "loop body can only express the empty trace".to_string();
+        },
+        LintWarningKind::IdenticalAltBranches(idx1,idx2) => {
+            return format!("branches {} and {} of 'alt' are syntactically identical", idx1 + 1, idx2 + 1);
+        }
+    }
+}
+
+fn lint_position_as_text(warning : &LintWarning) -> String {
+    let position = warning.position.to_string();
+    if position.is_empty() {
+        return "root".to_string();
+    } else {
+        return position;
+    }
+}
+
+pub fn lint_warnings_as_text(gen_ctx : &GeneralContext, warnings : &Vec<LintWarning>) -> Vec<String> {
+    return warnings.iter()
+        .map(|w| format!("warning [{}] at {} : {}", w.kind.get_identifier(), lint_position_as_text(w), lint_warning_message(gen_ctx, w)))
+        .collect();
+}
@@ -22,5 +22,12 @@ pub mod draw_traces;
 pub mod draw_transitions;
 
 pub mod to_hfiles;
+pub mod lint_report;
+pub mod diff_report;
+pub mod failure_report;
+pub mod witness_report;
+pub mod batch_report;
+pub mod alignment_report;
+pub mod to_json;
 
 
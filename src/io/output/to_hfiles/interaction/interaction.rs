@@ -22,9 +22,11 @@ use crate::core::language::syntax::util::get_recursive_frag::{
     get_recursive_par_frags,
     get_recursive_seq_frags,
     get_recursive_coreg_frags,
-    get_recursive_strict_frags};
+    get_recursive_strict_frags,
+    get_recursive_sync_frags};
 use crate::io::output::to_hfiles::interaction::model_action::{emission_as_hif_encoding, reception_as_hif_encoding};
-use crate::io::textual_convention::{SYNTAX_ALT, SYNTAX_COREG, SYNTAX_EMPTY, SYNTAX_LOOP_H, SYNTAX_LOOP_P, SYNTAX_LOOP_S, SYNTAX_LOOP_W, SYNTAX_PAR, SYNTAX_SEQ, SYNTAX_STRICT};
+use crate::io::output::to_hfiles::trace::trace_action::trace_actions_as_htf_encoding;
+use crate::io::textual_convention::{SYNTAX_ALT, SYNTAX_COREG, SYNTAX_EMPTY, SYNTAX_LOOP_H, SYNTAX_LOOP_P, SYNTAX_LOOP_S, SYNTAX_LOOP_W, SYNTAX_PAR, SYNTAX_SEQ, SYNTAX_STRICT, SYNTAX_NEG, SYNTAX_ASSERT, SYNTAX_DISRUPT, SYNTAX_SYNC, SYNTAX_AND};
 
 
 pub fn interaction_as_hif_encoding(gen_ctx : &GeneralContext,
@@ -40,7 +42,7 @@ fn op_as_hif_encoding(gen_ctx : &GeneralContext,
     return format!("{0}{1}(\n{2}\n{0})", "\t".repeat(depth), op_text, ints_strs.join(",\n"));
 }
 
-fn loop_kind_as_text(lk : &LoopKind) -> &'static str {
+pub fn loop_kind_as_text(lk : &LoopKind) -> &'static str {
     match lk {
         LoopKind::SStrictSeq => {
             return SYNTAX_LOOP_S;
@@ -97,10 +99,39 @@ fn interaction_as_hif_encoding_inner(gen_ctx : &GeneralContext,
             alt_frags.extend_from_slice(&mut get_recursive_alt_frags(i2));
             return op_as_hif_encoding(gen_ctx,depth,SYNTAX_ALT,alt_frags);
         },
+        &Interaction::Sync(ref sync_acts, ref i1, ref i2) => {
+            let mut sync_frags = get_recursive_sync_frags(sync_acts,i1);
+            sync_frags.extend_from_slice(&mut get_recursive_sync_frags(sync_acts,i2));
+            let acts_as_set = sync_acts.iter().cloned().collect();
+            let acts_as_text = trace_actions_as_htf_encoding(gen_ctx,&acts_as_set);
+            // a single synchronized action is given between parentheses
+            let operator_name = if sync_acts.len() == 1 {
+                format!("{:}({:})", SYNTAX_SYNC, acts_as_text)
+            } else {
+                format!("{:}{:}", SYNTAX_SYNC, acts_as_text)
+            };
+            return op_as_hif_encoding(gen_ctx,depth,&operator_name,sync_frags);
+        },
+        &Interaction::And(ref i1, ref i2) => {
+            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_AND,vec![i1,i2]);
+        },
         &Interaction::Loop(ref lk, ref i1) => {
             let i1_string = interaction_as_hif_encoding_inner(gen_ctx,depth+1,i1);
             return format!("{0}{1}(\n{2}\n{0})", "\t".repeat(depth), loop_kind_as_text(lk), i1_string);
         },
+        &Interaction::BoundedLoop(ref lk, min, max, ref i1) => {
+            let i1_string = interaction_as_hif_encoding_inner(gen_ctx,depth+1,i1);
+            return format!("{0}{1}[{2},{3}](\n{4}\n{0})", "\t".repeat(depth), loop_kind_as_text(lk), min, max, i1_string);
+        },
+        &Interaction::Neg(ref i1) => {
+            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_NEG,vec![i1]);
+        },
+        &Interaction::Assert(ref i1) => {
+            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_ASSERT,vec![i1]);
+        },
+        &Interaction::Disrupt(ref i1, ref i2) => {
+            return op_as_hif_encoding(gen_ctx,depth,SYNTAX_DISRUPT,vec![i1,i2]);
+        },
         _ => {
             panic!("non-conform interaction");
         }
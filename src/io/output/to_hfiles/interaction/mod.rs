@@ -15,7 +15,7 @@ limitations under the License.
 */
 
 mod model_action;
-mod interaction;
+pub mod interaction;
 pub mod to_hif;
 
 
@@ -16,6 +16,7 @@ limitations under the License.
 
 
 
+use crate::core::language::syntax::value::arguments_as_text;
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction, EmissionTargetRef, ReceptionAction};
 
@@ -45,7 +46,7 @@ pub fn emission_as_hif_encoding(gen_ctx : &GeneralContext,
     }
     // ***
     let lf_name = gen_ctx.get_lf_name(em_act.origin_lf_id).unwrap();
-    let ms_name = gen_ctx.get_ms_name(em_act.ms_id).unwrap();
+    let ms_name = format!("{}{}", gen_ctx.get_ms_name(em_act.ms_id).unwrap(), arguments_as_text(&em_act.args));
     let rcp_num = targ_names.len();
     if rcp_num == 0 {
         return format!("{} -- {}{} ->|", &lf_name, synch_key, &ms_name);
@@ -84,7 +85,7 @@ pub fn reception_as_hif_encoding(gen_ctx : &GeneralContext,
         }
     }
     // ***
-    let ms_name = gen_ctx.get_ms_name(rc_act.ms_id).unwrap();
+    let ms_name = format!("{}{}", gen_ctx.get_ms_name(rc_act.ms_id).unwrap(), arguments_as_text(&rc_act.args));
     let rcp_num = targ_names.len();
     if rcp_num == 1 {
         return format!("{}{}{} -> {}", gate_str, synch_key, &ms_name, targ_names.get(0).unwrap());
@@ -17,3 +17,4 @@ limitations under the License.
 
 pub mod trace;
 pub mod interaction;
+pub mod signature;
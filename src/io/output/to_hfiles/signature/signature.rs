@@ -0,0 +1,105 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use crate::core::general_context::GeneralContext;
+
+
+// a family is written as a range only if its members are exactly those which such a range declares
+fn lifeline_family_as_range(gen_ctx : &GeneralContext, family_name : &str, members : &[usize]) -> Option<String> {
+    let prefix = format!("{}_", family_name);
+    let mut indices : Vec<usize> = vec![];
+    for lf_id in members {
+        let lf_name = gen_ctx.get_lf_name(*lf_id).unwrap();
+        match lf_name.strip_prefix(&prefix).and_then(|idx| idx.parse::<usize>().ok()) {
+            None => {
+                return None;
+            },
+            Some( idx ) => {
+                indices.push(idx);
+            }
+        }
+    }
+    let min = *indices.first()?;
+    let max = *indices.last()?;
+    if indices.iter().cloned().eq(min..=max) {
+        return Some( format!("{}[{}..{}]", family_name, min, max) );
+    } else {
+        return None;
+    }
+}
+
+pub fn general_context_as_hsf_encoding(gen_ctx : &GeneralContext) -> String {
+    let mut sections : Vec<String> = vec![];
+    // ***
+    let mut messages : Vec<String> = vec![];
+    for ms_id in 0..gen_ctx.get_ms_num() {
+        let ms_name = gen_ctx.get_ms_name(ms_id).unwrap();
+        let arg_types = gen_ctx.get_ms_arg_types(ms_id).unwrap();
+        if arg_types.is_empty() {
+            messages.push(ms_name);
+        } else {
+            let types_as_text : Vec<String> = arg_types.iter().map(|t| t.to_string()).collect();
+            messages.push( format!("{}({})", ms_name, types_as_text.join(",")) );
+        }
+    }
+    if !messages.is_empty() {
+        sections.push( format!("@message{{{}}}", messages.join(";")) );
+    }
+    // ***
+    let mut lifelines : Vec<String> = vec![];
+    let mut in_family : Vec<usize> = vec![];
+    for (family_name,members) in gen_ctx.get_lf_families() {
+        if let Some(range) = lifeline_family_as_range(gen_ctx,family_name,members) {
+            lifelines.push(range);
+            in_family.extend(members.iter().cloned());
+        }
+    }
+    for lf_id in 0..gen_ctx.get_lf_num() {
+        if !in_family.contains(&lf_id) {
+            lifelines.push( gen_ctx.get_lf_name(lf_id).unwrap() );
+        }
+    }
+    if !lifelines.is_empty() {
+        sections.push( format!("@lifeline{{{}}}", lifelines.join(";")) );
+    }
+    // ***
+    let gates : Vec<String> = (0..gen_ctx.get_gt_num()).map(|gt_id| gen_ctx.get_gt_name(gt_id).unwrap()).collect();
+    if !gates.is_empty() {
+        sections.push( format!("@gate{{{}}}", gates.join(";")) );
+    }
+    return sections.join("\n");
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::syntax::value::ArgumentType;
+    use crate::util::test_fixtures::context_with;
+    use super::*;
+
+    #[test]
+    fn restricted_signatures_keep_complete_families() {
+        let mut gen_ctx = context_with(&["server","client_1","client_2","client_3"],&["req"]);
+        gen_ctx.add_lf_family("client".to_string(),vec![1,2,3]);
+        gen_ctx.set_ms_arg_types(0,vec![ArgumentType::Integer]);
+        let restricted = gen_ctx.restricted_to_lifelines(&btreeset!{0,1,2,3});
+        assert_eq!(general_context_as_hsf_encoding(&restricted), "@message{req(int)}\n@lifeline{client[1..3];server}");
+        let restricted = gen_ctx.restricted_to_lifelines(&btreeset!{0,2});
+        assert_eq!(general_context_as_hsf_encoding(&restricted), "@message{req(int)}\n@lifeline{server;client_2}");
+    }
+}
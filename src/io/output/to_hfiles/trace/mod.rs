@@ -16,6 +16,6 @@ limitations under the License.
 
 
 pub mod trace_action;
-mod multi_trace;
+pub mod multi_trace;
 pub mod to_htf;
 
@@ -24,6 +24,15 @@ use crate::core::general_context::GeneralContext;
 use crate::io::output::to_hfiles::trace::trace_action::trace_actions_as_htf_encoding;
 
 
+pub fn trace_as_htf_encoding(gen_ctx : &GeneralContext,
+                             trace : &Trace) -> String {
+    let mut trace_text_elements = vec![];
+    for actions in trace {
+        trace_text_elements.push( trace_actions_as_htf_encoding(gen_ctx, actions) );
+    }
+    return trace_text_elements.join(".");
+}
+
 pub fn multi_trace_as_htf_encoding(gen_ctx : &GeneralContext,
                            co_localizations : &CoLocalizations,
                            multi_trace : &MultiTrace) -> String {
@@ -39,11 +48,7 @@ pub fn multi_trace_as_htf_encoding(gen_ctx : &GeneralContext,
         }
         canal_string.push_str( &format!( "[{:}] ", lf_names.join(",")) );
         // ***
-        let mut trace_text_elements = vec![];
-        for actions in canal_trace {
-            trace_text_elements.push( trace_actions_as_htf_encoding(gen_ctx, actions) );
-        }
-        canal_string.push_str(&trace_text_elements.join(".") );
+        canal_string.push_str(&trace_as_htf_encoding(gen_ctx, canal_trace) );
         // ***
         canals_strings.push(canal_string);
     }
@@ -19,6 +19,7 @@ use std::collections::BTreeSet;
 
 use itertools::Itertools;
 
+use crate::core::language::syntax::value::arguments_as_text;
 use crate::core::general_context::GeneralContext;
 use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
 use crate::io::textual_convention::{SYNTAX_EMISSION, SYNTAX_RECEPTION};
@@ -38,7 +39,7 @@ pub fn trace_actions_as_htf_encoding(gen_ctx : &GeneralContext, actions : &BTree
 
 fn trace_action_as_htf_encoding(gen_ctx : &GeneralContext, action : &TraceAction) -> String {
     let lf_name = gen_ctx.get_lf_name(action.lf_id).unwrap();
-    let ms_name = gen_ctx.get_ms_name(action.ms_id).unwrap();
+    let ms_name = format!("{}{}", gen_ctx.get_ms_name(action.ms_id).unwrap(), arguments_as_text(&action.args));
     // ***
     match &action.act_kind {
         &TraceActionKind::Reception => {
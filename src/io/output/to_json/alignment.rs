@@ -0,0 +1,59 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use serde_json::{json, Value};
+use itertools::Itertools;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_json::multitrace::multi_trace_as_json;
+use crate::io::output::to_json::value::trace_action_as_json;
+use crate::process::ana::align::alignment::{AlignmentMoveKind, MultiTraceAlignment};
+
+
+pub fn multi_trace_alignment_as_json(gen_ctx : &GeneralContext,
+                                     co_localizations : &CoLocalizations,
+                                     alignment : &MultiTraceAlignment) -> Value {
+    let mut canals : Vec<Value> = vec![];
+    for (canal_id,canal_moves) in alignment.get_canal_alignments(co_localizations).iter().enumerate() {
+        let lifelines : Vec<String> = co_localizations.get_coloc_lfs_ids(canal_id).iter().sorted()
+            .map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap())
+            .collect();
+        let moves : Vec<Value> = canal_moves.iter().map(|align_move| {
+            let kind = match align_move.kind {
+                AlignmentMoveKind::Synchronous => "synchronous",
+                AlignmentMoveKind::Deletion => "deletion",
+                AlignmentMoveKind::Insertion => "insertion"
+            };
+            let actions : Vec<Value> = align_move.actions.iter().map(|a| trace_action_as_json(gen_ctx,a)).collect();
+            json!({
+                "kind" : kind,
+                "actions" : actions
+            })
+        }).collect();
+        canals.push( json!({
+            "lifelines" : lifelines,
+            "moves" : moves
+        }) );
+    }
+    let repaired = alignment.get_repaired_multi_trace(co_localizations);
+    return json!({
+        "cost" : alignment.cost,
+        "canals" : canals,
+        "repaired" : multi_trace_as_json(gen_ctx,co_localizations,&repaired)
+    });
+}
@@ -0,0 +1,47 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use serde_json::{json, Value};
+
+use crate::core::general_context::GeneralContext;
+
+
+pub fn general_context_as_json(gen_ctx : &GeneralContext) -> Value {
+    let lifelines : Vec<String> = (0..gen_ctx.get_lf_num()).map(|lf_id| gen_ctx.get_lf_name(lf_id).unwrap()).collect();
+    let mut messages : Vec<Value> = vec![];
+    for ms_id in 0..gen_ctx.get_ms_num() {
+        let arg_types : Vec<String> = gen_ctx.get_ms_arg_types(ms_id).unwrap().iter().map(|t| t.to_string()).collect();
+        messages.push( json!({
+            "name" : gen_ctx.get_ms_name(ms_id).unwrap(),
+            "args" : arg_types
+        }) );
+    }
+    let gates : Vec<String> = (0..gen_ctx.get_gt_num()).map(|gt_id| gen_ctx.get_gt_name(gt_id).unwrap()).collect();
+    let mut families : Vec<Value> = vec![];
+    for (family_name,members) in gen_ctx.get_lf_families() {
+        let member_names : Vec<String> = members.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+        families.push( json!({
+            "name" : family_name,
+            "members" : member_names
+        }) );
+    }
+    return json!({
+        "lifelines" : lifelines,
+        "messages" : messages,
+        "gates" : gates,
+        "lifeline_families" : families
+    });
+}
@@ -0,0 +1,78 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use serde_json::{json, Value};
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::semantics::frontier::FrontierElement;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_json::interaction::interaction_as_json;
+use crate::io::output::to_json::value::trace_action_as_json;
+use crate::process::ana::loggers::diag::diagnosis::AnalysisFailureDiagnosis;
+
+
+// the root position is encoded as an empty string
+fn frontier_element_as_json(gen_ctx : &GeneralContext, frt_elt : &FrontierElement) -> Value {
+    let actions : Vec<Value> = frt_elt.target_actions.iter().map(|a| trace_action_as_json(gen_ctx,a)).collect();
+    return json!({
+        "position" : frt_elt.position.to_string(),
+        "actions" : actions
+    });
+}
+
+pub fn analysis_failure_diagnoses_as_json(gen_ctx : &GeneralContext,
+                                          co_localizations : &CoLocalizations,
+                                          diagnoses : &[AnalysisFailureDiagnosis]) -> Value {
+    return Value::Array( diagnoses.iter().map(|d| analysis_failure_diagnosis_as_json(gen_ctx,co_localizations,d)).collect() );
+}
+
+// canal indices and action indices in canals start from 0
+fn analysis_failure_diagnosis_as_json(gen_ctx : &GeneralContext,
+                                          co_localizations : &CoLocalizations,
+                                          diagnosis : &AnalysisFailureDiagnosis) -> Value {
+    let mut canals : Vec<Value> = vec![];
+    for canal in &diagnosis.canals {
+        let lifelines : Vec<String> = co_localizations.get_coloc_lfs_ids(canal.canal_id)
+            .iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+        let head = match &canal.head {
+            None => {
+                Value::Null
+            },
+            Some( got_head ) => {
+                Value::Array( got_head.iter().map(|a| trace_action_as_json(gen_ctx,a)).collect() )
+            }
+        };
+        let value_mismatches : Vec<Value> = canal.value_mismatches.iter().map(|f| frontier_element_as_json(gen_ctx,f)).collect();
+        canals.push( json!({
+            "canal" : canal.canal_id,
+            "lifelines" : lifelines,
+            "consumed" : canal.consumed,
+            "length" : canal.length,
+            "head" : head,
+            "head_expected" : canal.head_expected,
+            "value_mismatches" : value_mismatches
+        }) );
+    }
+    let expected : Vec<Value> = diagnosis.expected.iter().map(|f| frontier_element_as_json(gen_ctx,f)).collect();
+    return json!({
+        "canals" : canals,
+        "expected" : expected,
+        "pruned_by_local_analysis" : diagnosis.pruned_by_local_analysis,
+        "interaction" : interaction_as_json(gen_ctx,&diagnosis.interaction)
+    });
+}
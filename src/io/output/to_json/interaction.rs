@@ -0,0 +1,171 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use serde_json::{json, Value};
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionTargetRef};
+use crate::core::language::syntax::interaction::{Interaction, LoopKind};
+use crate::io::output::to_json::value::{arguments_as_json, trace_action_as_json};
+
+
+pub fn loop_kind_as_json(loop_kind : &LoopKind) -> Value {
+    match loop_kind {
+        LoopKind::SStrictSeq => {
+            return json!("S");
+        },
+        LoopKind::HHeadFirstWS => {
+            return json!("H");
+        },
+        LoopKind::WWeakSeq => {
+            return json!("W");
+        },
+        LoopKind::PInterleaving => {
+            return json!("P");
+        }
+    }
+}
+
+fn synchronicity_as_json(synchronicity : &CommunicationSynchronicity) -> Value {
+    match synchronicity {
+        CommunicationSynchronicity::Asynchronous => {
+            return json!("asynch");
+        },
+        CommunicationSynchronicity::Synchronous => {
+            return json!("synch");
+        }
+    }
+}
+
+fn binary_as_json(gen_ctx : &GeneralContext, kind : &str, i1 : &Interaction, i2 : &Interaction) -> Value {
+    return json!({
+        "kind" : kind,
+        "left" : interaction_as_json(gen_ctx,i1),
+        "right" : interaction_as_json(gen_ctx,i2)
+    });
+}
+
+pub fn interaction_as_json(gen_ctx : &GeneralContext, interaction : &Interaction) -> Value {
+    match interaction {
+        Interaction::Empty => {
+            return json!({"kind" : "empty"});
+        },
+        Interaction::Emission(em_act) => {
+            let mut targets : Vec<Value> = vec![];
+            for target_ref in &em_act.targets {
+                match target_ref {
+                    EmissionTargetRef::Lifeline(lf_id) => {
+                        targets.push( json!({"lifeline" : gen_ctx.get_lf_name(*lf_id).unwrap()}) );
+                    },
+                    EmissionTargetRef::Gate(gt_id) => {
+                        targets.push( json!({"gate" : gen_ctx.get_gt_name(*gt_id).unwrap()}) );
+                    }
+                }
+            }
+            return json!({
+                "kind" : "emission",
+                "origin" : gen_ctx.get_lf_name(em_act.origin_lf_id).unwrap(),
+                "message" : gen_ctx.get_ms_name(em_act.ms_id).unwrap(),
+                "synchronicity" : synchronicity_as_json(&em_act.synchronicity),
+                "targets" : targets,
+                "args" : arguments_as_json(&em_act.args)
+            });
+        },
+        Interaction::Reception(rc_act) => {
+            let origin_gate = match rc_act.origin_gt_id {
+                None => {
+                    Value::Null
+                },
+                Some( gt_id ) => {
+                    json!(gen_ctx.get_gt_name(gt_id).unwrap())
+                }
+            };
+            let recipients : Vec<String> = rc_act.recipients.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+            return json!({
+                "kind" : "reception",
+                "origin_gate" : origin_gate,
+                "message" : gen_ctx.get_ms_name(rc_act.ms_id).unwrap(),
+                "synchronicity" : synchronicity_as_json(&rc_act.synchronicity),
+                "recipients" : recipients,
+                "args" : arguments_as_json(&rc_act.args)
+            });
+        },
+        Interaction::Strict(i1,i2) => {
+            return binary_as_json(gen_ctx,"strict",i1,i2);
+        },
+        Interaction::Seq(i1,i2) => {
+            return binary_as_json(gen_ctx,"seq",i1,i2);
+        },
+        Interaction::Alt(i1,i2) => {
+            return binary_as_json(gen_ctx,"alt",i1,i2);
+        },
+        Interaction::Par(i1,i2) => {
+            return binary_as_json(gen_ctx,"par",i1,i2);
+        },
+        Interaction::Disrupt(i1,i2) => {
+            return binary_as_json(gen_ctx,"disrupt",i1,i2);
+        },
+        Interaction::And(i1,i2) => {
+            return binary_as_json(gen_ctx,"and",i1,i2);
+        },
+        Interaction::CoReg(cr,i1,i2) => {
+            let lifelines : Vec<String> = cr.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+            return json!({
+                "kind" : "coreg",
+                "lifelines" : lifelines,
+                "left" : interaction_as_json(gen_ctx,i1),
+                "right" : interaction_as_json(gen_ctx,i2)
+            });
+        },
+        Interaction::Sync(sync_acts,i1,i2) => {
+            let actions : Vec<Value> = sync_acts.iter().map(|a| trace_action_as_json(gen_ctx,a)).collect();
+            return json!({
+                "kind" : "sync",
+                "actions" : actions,
+                "left" : interaction_as_json(gen_ctx,i1),
+                "right" : interaction_as_json(gen_ctx,i2)
+            });
+        },
+        Interaction::Loop(lkind,i1) => {
+            return json!({
+                "kind" : "loop",
+                "loop_kind" : loop_kind_as_json(lkind),
+                "body" : interaction_as_json(gen_ctx,i1)
+            });
+        },
+        Interaction::BoundedLoop(lkind,min,max,i1) => {
+            return json!({
+                "kind" : "bounded_loop",
+                "loop_kind" : loop_kind_as_json(lkind),
+                "min" : min,
+                "max" : max,
+                "body" : interaction_as_json(gen_ctx,i1)
+            });
+        },
+        Interaction::Neg(i1) => {
+            return json!({
+                "kind" : "neg",
+                "body" : interaction_as_json(gen_ctx,i1)
+            });
+        },
+        Interaction::Assert(i1) => {
+            return json!({
+                "kind" : "assert",
+                "body" : interaction_as_json(gen_ctx,i1)
+            });
+        }
+    }
+}
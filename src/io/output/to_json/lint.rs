@@ -0,0 +1,82 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use serde_json::{json, Value};
+
+use crate::core::general_context::GeneralContext;
+use crate::core::language::lint::warning::{LintWarning, LintWarningKind};
+use crate::io::output::lint_report::lint_warning_message;
+use crate::io::output::to_json::value::trace_action_as_json;
+
+
+// the root position is encoded as an empty string
+fn lint_warning_as_json(gen_ctx : &GeneralContext, warning : &LintWarning) -> Value {
+    let mut got = json!({
+        "kind" : warning.kind.get_identifier(),
+        "position" : warning.position.to_string(),
+        "description" : lint_warning_message(gen_ctx, warning)
+    });
+    match &warning.kind {
+        LintWarningKind::UnusedLifeline(lf_id) => {
+            got["lifeline"] = json!(gen_ctx.get_lf_name(*lf_id).unwrap());
+        },
+        LintWarningKind::UnusedMessage(ms_id) => {
+            got["message"] = json!(gen_ctx.get_ms_name(*ms_id).unwrap());
+        },
+        LintWarningKind::UnusedGate(gt_id) => {
+            got["gate"] = json!(gen_ctx.get_gt_name(*gt_id).unwrap());
+        },
+        LintWarningKind::UselessSyncActions(actions) => {
+            got["actions"] = Value::Array( actions.iter().map(|a| trace_action_as_json(gen_ctx,a)).collect() );
+        },
+        LintWarningKind::IdenticalAltBranches(idx1,idx2) => {
+            // branches are numbered from 1 as in the message
+            got["branches"] = json!([idx1 + 1, idx2 + 1]);
+        },
+        LintWarningKind::EmptyAnd | LintWarningKind::EmptyLoopBody => {}
+    }
+    return got;
+}
+
+pub fn lint_warnings_as_json(gen_ctx : &GeneralContext, warnings : &[LintWarning]) -> Value {
+    return Value::Array( warnings.iter().map(|w| lint_warning_as_json(gen_ctx,w)).collect() );
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::lint::lint::lint_interaction;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::util::test_fixtures::{context_with, message};
+    use super::*;
+
+    #[test]
+    fn warnings_refer_to_declarations_by_name() {
+        let gen_ctx = context_with(&["a","b","c"],&["m1","m2"]);
+        // alt(a -- m1 -> b, a -- m1 -> b) where "c" and "m2" are never used
+        let int = Interaction::Alt(Box::new(message(0,0,1)),Box::new(message(0,0,1)));
+        let got = lint_warnings_as_json(&gen_ctx, &lint_interaction(&gen_ctx, &int));
+        let kinds : Vec<&str> = got.as_array().unwrap().iter().map(|w| w["kind"].as_str().unwrap()).collect();
+        assert_eq!(kinds, vec!["unused_lifeline","unused_message","identical_alt_branches"]);
+        assert_eq!(got[0]["lifeline"], json!("c"));
+        assert_eq!(got[0]["position"], json!(""));
+        assert_eq!(got[1]["message"], json!("m2"));
+        assert_eq!(got[2]["branches"], json!([1,2]));
+        assert_eq!(got[2]["description"], json!("branches 1 and 2 of 'alt' are syntactically identical"));
+    }
+}
@@ -0,0 +1,50 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use serde_json::{json, Value};
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::{MultiTrace, Trace};
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_json::value::trace_action_as_json;
+
+
+// each element of a trace is a set of simultaneous actions
+pub fn trace_as_json(gen_ctx : &GeneralContext, trace : &Trace) -> Value {
+    let mut elements : Vec<Value> = vec![];
+    for multi_action in trace {
+        let actions : Vec<Value> = multi_action.iter().map(|a| trace_action_as_json(gen_ctx,a)).collect();
+        elements.push( Value::Array(actions) );
+    }
+    return Value::Array(elements);
+}
+
+pub fn multi_trace_as_json(gen_ctx : &GeneralContext,
+                           co_localizations : &CoLocalizations,
+                           multi_trace : &MultiTrace) -> Value {
+    let mut canals : Vec<Value> = vec![];
+    for (coloc_id,trace) in multi_trace.iter().enumerate() {
+        let lifelines : Vec<String> = co_localizations.locs_lf_ids.get(coloc_id).unwrap()
+            .iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+        canals.push( json!({
+            "lifelines" : lifelines,
+            "trace" : trace_as_json(gen_ctx,trace)
+        }) );
+    }
+    return json!({
+        "canals" : canals
+    });
+}
@@ -0,0 +1,61 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use serde_json::{json, Value};
+
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::value::ValuePattern;
+
+
+// wildcards are represented by null
+pub fn value_pattern_as_json(value : &ValuePattern) -> Value {
+    match value {
+        ValuePattern::Any => {
+            return Value::Null;
+        },
+        ValuePattern::Integer(got_int) => {
+            return json!(got_int);
+        },
+        ValuePattern::String(got_str) => {
+            return json!(got_str);
+        },
+        ValuePattern::Boolean(got_bool) => {
+            return json!(got_bool);
+        }
+    }
+}
+
+pub fn arguments_as_json(args : &Vec<ValuePattern>) -> Value {
+    return Value::Array( args.iter().map(|a| value_pattern_as_json(a)).collect() );
+}
+
+pub fn trace_action_as_json(gen_ctx : &GeneralContext, action : &TraceAction) -> Value {
+    let kind = match action.act_kind {
+        TraceActionKind::Emission => {
+            "emission"
+        },
+        TraceActionKind::Reception => {
+            "reception"
+        }
+    };
+    return json!({
+        "lifeline" : gen_ctx.get_lf_name(action.lf_id).unwrap(),
+        "kind" : kind,
+        "message" : gen_ctx.get_ms_name(action.ms_id).unwrap(),
+        "args" : arguments_as_json(&action.args)
+    });
+}
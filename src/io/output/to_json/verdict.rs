@@ -0,0 +1,59 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::ana::verdict::inconc::InconcReason;
+
+
+pub fn analysis_verdict_as_json(verdict : &AnalysisGlobalVerdict) -> Value {
+    match verdict {
+        AnalysisGlobalVerdict::Inconc(reason) => {
+            if let InconcReason::ResourceLimit(limit,reached) = reason {
+                return json!({
+                    "kind" : "Inconc",
+                    "reason" : "ResourceLimit",
+                    "explanation" : reason.get_explanation_string(),
+                    "limit" : limit.to_string(),
+                    "reached" : analysis_verdict_as_json(reached)
+                });
+            }
+            return json!({
+                "kind" : "Inconc",
+                "reason" : reason.to_string(),
+                "explanation" : reason.get_explanation_string()
+            });
+        },
+        _ => {
+            return json!({
+                "kind" : verdict.to_string()
+            });
+        }
+    }
+}
+
+pub fn analysis_result_as_json(verdict : &AnalysisGlobalVerdict,
+                               node_count : u32,
+                               elapsed_time : &Duration) -> Value {
+    return json!({
+        "verdict" : analysis_verdict_as_json(verdict),
+        "node_count" : node_count,
+        "elapsed" : elapsed_time.as_secs_f64()
+    });
+}
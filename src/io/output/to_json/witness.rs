@@ -0,0 +1,65 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use serde_json::{json, Value};
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::general_context::GeneralContext;
+use crate::io::output::to_json::interaction::interaction_as_json;
+use crate::io::output::to_json::value::trace_action_as_json;
+use crate::process::ana::loggers::witness::witness::{AnalysisWitness, WitnessStepKind};
+
+
+// positions refer to the interaction preceding each step, the root position is encoded as an empty string
+pub fn analysis_witness_as_json(gen_ctx : &GeneralContext,
+                                co_localizations : &CoLocalizations,
+                                witness : &AnalysisWitness) -> Value {
+    let mut steps : Vec<Value> = vec![];
+    for step in &witness.steps {
+        match &step.kind {
+            WitnessStepKind::EliminateNoLongerObserved(coloc_ids) => {
+                let mut lifelines : Vec<String> = vec![];
+                for coloc_id in coloc_ids {
+                    for lf_id in co_localizations.get_coloc_lfs_ids(*coloc_id) {
+                        lifelines.push( gen_ctx.get_lf_name(*lf_id).unwrap() );
+                    }
+                }
+                steps.push( json!({
+                    "kind" : "hide",
+                    "lifelines" : lifelines,
+                    "interaction" : interaction_as_json(gen_ctx,&step.interaction)
+                }) );
+            },
+            WitnessStepKind::Execute(position,actions,simulated) => {
+                let actions : Vec<Value> = actions.iter().map(|a| trace_action_as_json(gen_ctx,a)).collect();
+                steps.push( json!({
+                    "kind" : "execute",
+                    "position" : position.to_string(),
+                    "actions" : actions,
+                    "simulated" : simulated,
+                    "interaction" : interaction_as_json(gen_ctx,&step.interaction)
+                }) );
+            }
+        }
+    }
+    return json!({
+        "initial_interaction" : interaction_as_json(gen_ctx,&witness.initial_interaction),
+        "steps" : steps,
+        "scenario" : interaction_as_json(gen_ctx,&witness.get_scenario())
+    });
+}
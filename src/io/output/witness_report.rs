@@ -0,0 +1,67 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::output::to_hfiles::interaction::interaction::interaction_as_hif_encoding;
+use crate::io::output::to_hfiles::trace::trace_action::trace_actions_as_htf_encoding;
+use crate::process::ana::loggers::witness::witness::{AnalysisWitness, WitnessStepKind};
+
+
+// the ".hif" encoding on a single line
+fn witness_interaction_as_text(gen_ctx : &GeneralContext, witness_int : &Interaction) -> String {
+    return interaction_as_hif_encoding(gen_ctx,witness_int).replace(['\n','\t'],"");
+}
+
+// positions refer to the interaction preceding each step
+pub fn analysis_witness_as_text(gen_ctx : &GeneralContext,
+                                co_localizations : &CoLocalizations,
+                                witness : &AnalysisWitness) -> Vec<String> {
+    let mut lines = vec!["ACCEPTANCE WITNESS".to_string()];
+    lines.push( format!("initial interaction : {}", witness_interaction_as_text(gen_ctx,&witness.initial_interaction)) );
+    for (step_idx,step) in witness.steps.iter().enumerate() {
+        match &step.kind {
+            WitnessStepKind::EliminateNoLongerObserved(coloc_ids) => {
+                let mut lf_names : Vec<String> = vec![];
+                for coloc_id in coloc_ids {
+                    for lf_id in co_localizations.get_coloc_lfs_ids(*coloc_id) {
+                        lf_names.push( gen_ctx.get_lf_name(*lf_id).unwrap() );
+                    }
+                }
+                lines.push( format!("step {} : no longer observes [{}]", step_idx + 1, lf_names.join(",")) );
+            },
+            WitnessStepKind::Execute(position,actions,simulated) => {
+                let position = position.to_string();
+                let position = if position.is_empty() {
+                    "root".to_string()
+                } else {
+                    position
+                };
+                let verb = if *simulated {
+                    "simulates"
+                } else {
+                    "executes"
+                };
+                lines.push( format!("step {} : {} {} at {}", step_idx + 1, verb, trace_actions_as_htf_encoding(gen_ctx,actions), position) );
+            }
+        }
+        lines.push( format!("    {}", witness_interaction_as_text(gen_ctx,&step.interaction)) );
+    }
+    return lines;
+}
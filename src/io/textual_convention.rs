@@ -25,6 +25,11 @@ pub static SYNTAX_COREG: &'static str = "coreg";
 
 pub static SYNTAX_AND: &'static str = "and";
 
+pub static SYNTAX_NEG: &'static str = "neg";
+pub static SYNTAX_ASSERT: &'static str = "assert";
+pub static SYNTAX_DISRUPT: &'static str = "disrupt";
+pub static SYNTAX_REF: &'static str = "ref";
+
 pub static SYNTAX_LOOP_S: &'static str = "loopS";
 pub static SYNTAX_LOOP_H: &'static str = "loopH";
 pub static SYNTAX_LOOP_W: &'static str = "loopW";
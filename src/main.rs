@@ -39,6 +39,7 @@ pub mod util;
 pub mod trace_manip;
 pub mod nfa_translation;
 pub mod experiments;
+pub mod mining;
 
 // **********
 
@@ -49,5 +50,5 @@ use crate::io::output::draw_interactions::interface::{draw_interaction, Interact
 use crate::ui::hibou_cli::hibou_cli;
 
 fn main() {
-    hibou_cli();
+    std::process::exit(hibou_cli());
 }
@@ -0,0 +1,98 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::mining::log::{Activity, ActivityLog, get_log_activities};
+
+
+/**
+ * Directly-follows graph of an activity log :
+ * there is an edge from "a" to "b" if "b" immediately follows "a" in some sequence.
+ **/
+pub struct DirectlyFollowsGraph {
+    pub activities : BTreeSet<Activity>,
+    pub edges : BTreeMap<Activity,BTreeSet<Activity>>,
+    pub starts : BTreeSet<Activity>,
+    pub ends : BTreeSet<Activity>
+}
+
+impl DirectlyFollowsGraph {
+
+    pub fn from_log(log : &ActivityLog) -> DirectlyFollowsGraph {
+        let activities = get_log_activities(log);
+        let mut edges : BTreeMap<Activity,BTreeSet<Activity>> = activities.iter().map(|act| (act.clone(),btreeset!{})).collect();
+        let mut starts = btreeset!{};
+        let mut ends = btreeset!{};
+        for sequence in log {
+            if let Some(first) = sequence.first() {
+                starts.insert(first.clone());
+            }
+            if let Some(last) = sequence.last() {
+                ends.insert(last.clone());
+            }
+            for pair in sequence.windows(2) {
+                edges.get_mut(&pair[0]).unwrap().insert(pair[1].clone());
+            }
+        }
+        return DirectlyFollowsGraph{activities,edges,starts,ends};
+    }
+
+    pub fn has_edge(&self, from : &Activity, to : &Activity) -> bool {
+        return self.edges.get(from).unwrap().contains(to);
+    }
+
+    // activities which can be reached from "from" via at least one edge
+    pub fn get_reachable(&self, from : &Activity) -> BTreeSet<Activity> {
+        let mut reached : BTreeSet<Activity> = btreeset!{};
+        let mut to_visit : Vec<&Activity> = self.edges.get(from).unwrap().iter().collect();
+        while let Some(act) = to_visit.pop() {
+            if reached.insert(act.clone()) {
+                to_visit.extend(self.edges.get(act).unwrap().iter());
+            }
+        }
+        return reached;
+    }
+
+    // connected components of the activities among "among"
+    // when two activities are related if "are_related" holds in either direction
+    pub fn get_components(&self,
+                          among : &BTreeSet<Activity>,
+                          are_related : &dyn Fn(&Activity,&Activity) -> bool) -> Vec<BTreeSet<Activity>> {
+        let mut components : Vec<BTreeSet<Activity>> = vec![];
+        let mut assigned : BTreeSet<Activity> = btreeset!{};
+        for seed in among {
+            if assigned.contains(seed) {
+                continue;
+            }
+            let mut component = btreeset!{seed.clone()};
+            let mut to_visit = vec![seed.clone()];
+            while let Some(act) = to_visit.pop() {
+                for other in among {
+                    if !component.contains(other) && (are_related(&act,other) || are_related(other,&act)) {
+                        component.insert(other.clone());
+                        to_visit.push(other.clone());
+                    }
+                }
+            }
+            assigned.extend(component.iter().cloned());
+            components.push(component);
+        }
+        return components;
+    }
+
+}
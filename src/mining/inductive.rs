@@ -0,0 +1,299 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction, EmissionTargetRef, ReceptionAction};
+use crate::core::language::syntax::interaction::{Interaction, LoopKind};
+use crate::mining::dfg::DirectlyFollowsGraph;
+use crate::mining::log::{Activity, ActivityLog, get_log_activities, project_log};
+
+
+fn activity_as_interaction(activity : &Activity) -> Interaction {
+    match activity.origin_lf_id {
+        Some( origin_lf_id ) => {
+            let targets = activity.target_lf_ids.iter().map(|lf_id| EmissionTargetRef::Lifeline(*lf_id)).collect();
+            return Interaction::Emission(EmissionAction::new(origin_lf_id,activity.ms_id,CommunicationSynchronicity::Asynchronous,targets,vec![]));
+        },
+        None => {
+            let recipients = activity.target_lf_ids.iter().cloned().collect();
+            return Interaction::Reception(ReceptionAction::new(None,activity.ms_id,CommunicationSynchronicity::Asynchronous,recipients,vec![]));
+        }
+    }
+}
+
+// right-nested binary operators e.g. seq(i1,seq(i2,i3))
+fn fold_interactions(mut interactions : Vec<Interaction>,
+                     operator : &dyn Fn(Box<Interaction>,Box<Interaction>) -> Interaction) -> Interaction {
+    let mut folded = interactions.pop().unwrap();
+    while let Some(previous) = interactions.pop() {
+        folded = operator(Box::new(previous),Box::new(folded));
+    }
+    return folded;
+}
+
+// ***
+
+fn find_exclusive_choice_cut(dfg : &DirectlyFollowsGraph) -> Option<Vec<BTreeSet<Activity>>> {
+    let parts = dfg.get_components(&dfg.activities, &|a,b| dfg.has_edge(a,b));
+    if parts.len() >= 2 {
+        return Some( parts );
+    }
+    return None;
+}
+
+// groups of activities which are all reachable from the previous ones and none of which reaches a previous one
+fn find_sequence_cut(dfg : &DirectlyFollowsGraph) -> Option<Vec<BTreeSet<Activity>>> {
+    let reachable : Vec<(Activity,BTreeSet<Activity>)> = dfg.activities.iter().map(|act| (act.clone(),dfg.get_reachable(act))).collect();
+    let reaches = |a : &Activity, b : &Activity| -> bool {
+        reachable.iter().find(|(act,_)| act == a).unwrap().1.contains(b)
+    };
+    // activities which are in the same strongly connected component or which are not ordered must be in the same group
+    let groups = dfg.get_components(&dfg.activities, &|a,b| reaches(a,b) == reaches(b,a));
+    if groups.len() < 2 {
+        return None;
+    }
+    let group_reaches = |g1 : &BTreeSet<Activity>, g2 : &BTreeSet<Activity>| -> bool {
+        g1.iter().any(|a| g2.iter().any(|b| reaches(a,b)))
+    };
+    for (idx1,g1) in groups.iter().enumerate() {
+        for g2 in groups.iter().skip(idx1 + 1) {
+            if group_reaches(g1,g2) == group_reaches(g2,g1) {
+                return None;
+            }
+        }
+    }
+    // groups are ordered, the first one reaching all the others
+    let mut ordered : Vec<(usize,BTreeSet<Activity>)> = groups.iter()
+        .map(|g| (groups.iter().filter(|other| *other != g && group_reaches(g,other)).count(), g.clone()))
+        .collect();
+    ordered.sort_by(|(x,_),(y,_)| y.cmp(x));
+    return Some( ordered.into_iter().map(|(_,g)| g).collect() );
+}
+
+// groups of activities which directly follow each other in both directions
+// each group must contain both starting and ending activities
+fn find_parallel_cut(dfg : &DirectlyFollowsGraph) -> Option<Vec<BTreeSet<Activity>>> {
+    let components = dfg.get_components(&dfg.activities, &|a,b| !(dfg.has_edge(a,b) && dfg.has_edge(b,a)));
+    let (mut complete, incomplete) : (Vec<BTreeSet<Activity>>,Vec<BTreeSet<Activity>>) = components.into_iter()
+        .partition(|comp| !comp.is_disjoint(&dfg.starts) && !comp.is_disjoint(&dfg.ends));
+    if complete.len() < 2 {
+        return None;
+    }
+    for comp in incomplete {
+        complete[0].extend(comp);
+    }
+    return Some( complete );
+}
+
+// a body containing the starting and ending activities and a redo part which goes from ending activities back to starting activities
+fn find_loop_cut(dfg : &DirectlyFollowsGraph) -> Option<(BTreeSet<Activity>,BTreeSet<Activity>)> {
+    let mut body : BTreeSet<Activity> = dfg.starts.union(&dfg.ends).cloned().collect();
+    let others : BTreeSet<Activity> = dfg.activities.difference(&body).cloned().collect();
+    let mut redo : BTreeSet<Activity> = btreeset!{};
+    for comp in dfg.get_components(&others, &|a,b| dfg.has_edge(a,b)) {
+        let mut is_redo = true;
+        let mut enters_from_end = false;
+        let mut exits_to_start = false;
+        for act in &dfg.activities {
+            if comp.contains(act) {
+                continue;
+            }
+            for in_comp in &comp {
+                if dfg.has_edge(act,in_comp) {
+                    if dfg.ends.contains(act) {
+                        enters_from_end = true;
+                    } else {
+                        is_redo = false;
+                    }
+                }
+                if dfg.has_edge(in_comp,act) {
+                    if dfg.starts.contains(act) {
+                        exits_to_start = true;
+                    } else {
+                        is_redo = false;
+                    }
+                }
+            }
+        }
+        if is_redo && enters_from_end && exits_to_start {
+            redo.extend(comp);
+        } else {
+            body.extend(comp);
+        }
+    }
+    if redo.is_empty() {
+        return None;
+    }
+    return Some( (body,redo) );
+}
+
+// ***
+
+// the sequences of the log are split into consecutive segments on each group, in order
+fn split_log_in_sequence(log : &ActivityLog, groups : &Vec<BTreeSet<Activity>>) -> Option<Vec<ActivityLog>> {
+    let mut sub_logs : Vec<ActivityLog> = groups.iter().map(|_| vec![]).collect();
+    for sequence in log {
+        let mut segments : Vec<Vec<Activity>> = groups.iter().map(|_| vec![]).collect();
+        let mut current_group = 0;
+        for act in sequence {
+            let act_group = groups.iter().position(|g| g.contains(act)).unwrap();
+            if act_group < current_group {
+                return None;
+            }
+            current_group = act_group;
+            segments[act_group].push(act.clone());
+        }
+        for (group_idx,segment) in segments.into_iter().enumerate() {
+            sub_logs[group_idx].push(segment);
+        }
+    }
+    return Some( sub_logs );
+}
+
+// the sequences of the log alternate between segments in the body and segments in the redo part
+fn split_log_in_loop(log : &ActivityLog, body : &BTreeSet<Activity>) -> (ActivityLog,ActivityLog) {
+    let mut body_log = vec![];
+    let mut redo_log = vec![];
+    for sequence in log {
+        let mut segment : Vec<Activity> = vec![];
+        let mut in_body = true;
+        for act in sequence {
+            if body.contains(act) != in_body {
+                if in_body {
+                    body_log.push(segment);
+                } else {
+                    redo_log.push(segment);
+                }
+                segment = vec![];
+                in_body = !in_body;
+            }
+            segment.push(act.clone());
+        }
+        if in_body {
+            body_log.push(segment);
+        } else {
+            redo_log.push(segment);
+        }
+    }
+    return (body_log,redo_log);
+}
+
+// the sequences of the log are split wherever an ending activity is directly followed by a starting activity
+fn split_log_on_restarts(log : &ActivityLog, dfg : &DirectlyFollowsGraph) -> Option<ActivityLog> {
+    let mut pieces = vec![];
+    let mut has_restart = false;
+    for sequence in log {
+        let mut piece : Vec<Activity> = vec![];
+        for act in sequence {
+            if let Some(previous) = piece.last() {
+                if dfg.ends.contains(previous) && dfg.starts.contains(act) {
+                    pieces.push(piece);
+                    piece = vec![];
+                    has_restart = true;
+                }
+            }
+            piece.push(act.clone());
+        }
+        pieces.push(piece);
+    }
+    if has_restart {
+        return Some( pieces );
+    }
+    return None;
+}
+
+/**
+ * Inductive mining of an interaction from a log of sequences of activities.
+ * The log is recursively split according to exclusive choices, sequences, parallel blocks and loops
+ * which are detected from its directly-follows graph, in this order.
+ * When no such cut is found, sequences which restart from the beginning are interpreted as repetitions
+ * and, failing that, any sequence of the remaining activities is allowed.
+ * By construction, every sequence of the log is accepted by the mined interaction.
+ **/
+pub fn mine_interaction_from_log(log : &ActivityLog) -> Interaction {
+    let non_empty : ActivityLog = log.iter().filter(|seq| !seq.is_empty()).cloned().collect();
+    if non_empty.is_empty() {
+        return Interaction::Empty;
+    }
+    if non_empty.len() < log.len() {
+        return Interaction::Alt(Box::new(Interaction::Empty),Box::new(mine_interaction_from_log(&non_empty)));
+    }
+    let activities = get_log_activities(&non_empty);
+    if activities.len() == 1 {
+        let act_int = activity_as_interaction(activities.iter().next().unwrap());
+        if non_empty.iter().all(|seq| seq.len() == 1) {
+            return act_int;
+        }
+        return Interaction::Seq(Box::new(act_int.clone()),Box::new(Interaction::Loop(LoopKind::WWeakSeq,Box::new(act_int))));
+    }
+    // ***
+    let dfg = DirectlyFollowsGraph::from_log(&non_empty);
+    if let Some(parts) = find_exclusive_choice_cut(&dfg) {
+        let sub_ints = parts.iter().map(|part| {
+            let sub_log : ActivityLog = non_empty.iter().filter(|seq| part.contains(&seq[0])).cloned().collect();
+            mine_interaction_from_log(&sub_log)
+        }).collect();
+        return fold_interactions(sub_ints, &Interaction::Alt);
+    }
+    if let Some(groups) = find_sequence_cut(&dfg) {
+        if let Some(sub_logs) = split_log_in_sequence(&non_empty,&groups) {
+            let sub_ints = sub_logs.iter().map(mine_interaction_from_log).collect();
+            return fold_interactions(sub_ints, &Interaction::Seq);
+        }
+    }
+    if let Some(parts) = find_parallel_cut(&dfg) {
+        let sub_ints = parts.iter().map(|part| mine_interaction_from_log(&project_log(&non_empty,part))).collect();
+        return fold_interactions(sub_ints, &Interaction::Par);
+    }
+    if let Some( (body,_) ) = find_loop_cut(&dfg) {
+        let (body_log,redo_log) = split_log_in_loop(&non_empty,&body);
+        let body_int = mine_interaction_from_log(&body_log);
+        let redo_int = mine_interaction_from_log(&redo_log);
+        let iteration = Interaction::Seq(Box::new(redo_int),Box::new(body_int.clone()));
+        return Interaction::Seq(Box::new(body_int),Box::new(Interaction::Loop(LoopKind::WWeakSeq,Box::new(iteration))));
+    }
+    // ***
+    if let Some(pieces) = split_log_on_restarts(&non_empty,&dfg) {
+        let repeated = mine_interaction_from_log(&pieces);
+        return Interaction::Seq(Box::new(repeated.clone()),Box::new(Interaction::Loop(LoopKind::WWeakSeq,Box::new(repeated))));
+    }
+    let alternatives = activities.iter().map(activity_as_interaction).collect();
+    return Interaction::Loop(LoopKind::WWeakSeq,Box::new(fold_interactions(alternatives, &Interaction::Alt)));
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mine_sequence_then_choice() {
+        let a = Activity::new(0,Some(0),btreeset!{});
+        let b = Activity::new(0,None,btreeset!{1});
+        let c = Activity::new(1,Some(1),btreeset!{0});
+        let d = Activity::new(2,Some(1),btreeset!{});
+        let log : ActivityLog = vec![vec![a.clone(),b.clone(),c.clone()],
+                                     vec![a.clone(),b.clone(),d.clone()]];
+        let expected = Interaction::Seq(Box::new(activity_as_interaction(&a)),
+                                        Box::new(Interaction::Seq(Box::new(activity_as_interaction(&b)),
+                                                                  Box::new(Interaction::Alt(Box::new(activity_as_interaction(&c)),
+                                                                                            Box::new(activity_as_interaction(&d)))))));
+        assert_eq!(mine_interaction_from_log(&log), expected);
+    }
+}
@@ -0,0 +1,64 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::mining::inductive::mine_interaction_from_log;
+use crate::mining::log::ActivityLog;
+use crate::mining::messages::get_activity_sequences;
+
+
+/**
+ * Mines an interaction from a corpus of multi-traces.
+ * Emissions and receptions are paired into messages and each multi-trace is turned into sequences of messages
+ * which are ordered w.r.t. the canals, a message being emitted before it is received.
+ * The interaction is then mined from the log of all those sequences.
+ **/
+pub fn mine_interaction(corpus : &Vec<(CoLocalizations,MultiTrace)>) -> Result<Interaction,String> {
+    if corpus.is_empty() {
+        return Err( "no multi-trace to mine from".to_string() );
+    }
+    let mut log : ActivityLog = vec![];
+    for (_,multi_trace) in corpus {
+        log.append( &mut get_activity_sequences(multi_trace)? );
+    }
+    return Ok( mine_interaction_from_log(&log) );
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::execution::trace::trace::TraceActionKind;
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction};
+    use crate::util::test_fixtures::{action, message};
+    use super::*;
+
+    #[test]
+    fn messages_are_ordered_across_lifelines() {
+        // lifelines "a" (0) and "b" (1) on distinct canals, messages "m1" (0) and "m2" (1)
+        let colocs = CoLocalizations::get_discrete_partition(2);
+        let multi_trace : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0)],
+                                            vec![action(1,TraceActionKind::Reception,0),action(1,TraceActionKind::Emission,1)]];
+        let mined = mine_interaction(&vec![(colocs,multi_trace)]).unwrap();
+        // as "seq(a -- m1 -> b, b -- m2 ->|)"
+        let expected = Interaction::Seq(Box::new(message(0,0,1)),
+                                        Box::new(Interaction::Emission(EmissionAction::new(1,1,CommunicationSynchronicity::Asynchronous,
+                                                                                           vec![],vec![]))));
+        assert_eq!(mined, expected);
+    }
+}
@@ -0,0 +1,56 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::BTreeSet;
+
+
+/**
+ * Activities are the elements of the sequences which are mined.
+ * An activity is either a message, from its emitter to its receivers,
+ * or an emission (resp. reception) which could not be paired with any reception (resp. emission),
+ * in which case it is a message towards (resp. from) the environment.
+ * Arguments are not taken into account.
+ **/
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct Activity {
+    pub ms_id : usize,
+    // none for receptions from the environment
+    pub origin_lf_id : Option<usize>,
+    // empty for emissions towards the environment
+    pub target_lf_ids : BTreeSet<usize>
+}
+
+impl Activity {
+    pub fn new(ms_id : usize,
+               origin_lf_id : Option<usize>,
+               target_lf_ids : BTreeSet<usize>) -> Activity {
+        return Activity{ms_id,origin_lf_id,target_lf_ids};
+    }
+}
+
+// sequences of activities, in the manner of process mining
+pub type ActivityLog = Vec<Vec<Activity>>;
+
+pub fn get_log_activities(log : &ActivityLog) -> BTreeSet<Activity> {
+    return log.iter().flatten().cloned().collect();
+}
+
+// keeps, in each sequence, only the activities of a part
+pub fn project_log(log : &ActivityLog, part : &BTreeSet<Activity>) -> ActivityLog {
+    return log.iter()
+        .map(|sequence| sequence.iter().filter(|act| part.contains(act)).cloned().collect())
+        .collect();
+}
@@ -0,0 +1,235 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::execution::trace::trace::TraceActionKind;
+use crate::mining::log::Activity;
+
+
+// canal and index of an action in its canal
+type ActionPosition = (usize,usize);
+
+// an activity together with the positions of its actions in the multi-trace
+// the first position being that of the emission, if any
+struct ActivityOccurrence {
+    activity : Activity,
+    positions : Vec<ActionPosition>
+}
+
+impl ActivityOccurrence {
+    fn is_message(&self) -> bool {
+        return self.positions.len() > 1;
+    }
+}
+
+/**
+ * Emissions and receptions of a message are paired if the message is emitted by a single lifeline :
+ * the k-th emission is then paired with the k-th reception on each receiving lifeline.
+ * A reception which precedes the emission in a canal is not paired.
+ **/
+fn pair_emissions_and_receptions(multi_trace : &MultiTrace) -> Result<Vec<ActivityOccurrence>,String> {
+    // for each message, positions of its emissions and receptions on each lifeline
+    let mut emissions : BTreeMap<usize,BTreeMap<usize,Vec<ActionPosition>>> = BTreeMap::new();
+    let mut receptions : BTreeMap<usize,BTreeMap<usize,Vec<ActionPosition>>> = BTreeMap::new();
+    for (canal_id,trace) in multi_trace.iter().enumerate() {
+        for (idx,multi_action) in trace.iter().enumerate() {
+            if multi_action.len() > 1 {
+                return Err( "simultaneous actions cannot be mined".to_string() );
+            }
+            let action = multi_action.iter().next().unwrap();
+            let by_kind = match action.act_kind {
+                TraceActionKind::Emission => {
+                    &mut emissions
+                },
+                TraceActionKind::Reception => {
+                    &mut receptions
+                }
+            };
+            by_kind.entry(action.ms_id).or_default().entry(action.lf_id).or_default().push((canal_id,idx));
+        }
+    }
+    // ***
+    let mut occurrences = vec![];
+    let mut paired : BTreeSet<ActionPosition> = BTreeSet::new();
+    for (ms_id,emitters) in &emissions {
+        if emitters.len() != 1 {
+            continue;
+        }
+        let (em_lf_id,em_positions) = emitters.iter().next().unwrap();
+        for (k,em_pos) in em_positions.iter().enumerate() {
+            let mut activity = Activity::new(*ms_id,Some(*em_lf_id),btreeset!{});
+            let mut positions = vec![*em_pos];
+            if let Some(receivers) = receptions.get(ms_id) {
+                for (rc_lf_id,rc_positions) in receivers {
+                    if let Some(rc_pos) = rc_positions.get(k) {
+                        if rc_pos.0 == em_pos.0 && rc_pos.1 < em_pos.1 {
+                            continue;
+                        }
+                        activity.target_lf_ids.insert(*rc_lf_id);
+                        positions.push(*rc_pos);
+                    }
+                }
+            }
+            if positions.len() > 1 {
+                paired.extend(positions.iter().cloned());
+                occurrences.push( ActivityOccurrence{activity,positions} );
+            }
+        }
+    }
+    // ***
+    for (ms_id,by_lifeline) in &emissions {
+        for (lf_id,positions) in by_lifeline {
+            for pos in positions.iter().filter(|pos| !paired.contains(pos)) {
+                occurrences.push( ActivityOccurrence{activity:Activity::new(*ms_id,Some(*lf_id),btreeset!{}),positions:vec![*pos]} );
+            }
+        }
+    }
+    for (ms_id,by_lifeline) in &receptions {
+        for (lf_id,positions) in by_lifeline {
+            for pos in positions.iter().filter(|pos| !paired.contains(pos)) {
+                occurrences.push( ActivityOccurrence{activity:Activity::new(*ms_id,None,btreeset!{*lf_id}),positions:vec![*pos]} );
+            }
+        }
+    }
+    return Ok( occurrences );
+}
+
+// the order of the actions in each canal orders the occurrences
+fn get_successors(multi_trace : &MultiTrace, occurrences : &[ActivityOccurrence]) -> Vec<BTreeSet<usize>> {
+    let mut occ_at : BTreeMap<ActionPosition,usize> = BTreeMap::new();
+    for (occ_id,occ) in occurrences.iter().enumerate() {
+        for pos in &occ.positions {
+            occ_at.insert(*pos,occ_id);
+        }
+    }
+    let mut successors : Vec<BTreeSet<usize>> = occurrences.iter().map(|_| btreeset!{}).collect();
+    for (canal_id,trace) in multi_trace.iter().enumerate() {
+        for idx in 1..trace.len() {
+            let previous = *occ_at.get(&(canal_id,idx-1)).unwrap();
+            let next = *occ_at.get(&(canal_id,idx)).unwrap();
+            if previous != next {
+                successors[previous].insert(next);
+            }
+        }
+    }
+    return successors;
+}
+
+// topological sort in which, among the occurrences which may come next,
+// that with the smallest (or largest) first position is chosen
+// returns None if the occurrences are not acyclicly ordered
+fn linearize(occurrences : &[ActivityOccurrence],
+             successors : &[BTreeSet<usize>],
+             smallest_first : bool) -> Option<Vec<Activity>> {
+    let mut predecessors_num : Vec<usize> = occurrences.iter().map(|_| 0).collect();
+    for succs in successors {
+        for succ in succs {
+            predecessors_num[*succ] += 1;
+        }
+    }
+    let mut ready : BTreeSet<(ActionPosition,usize)> = occurrences.iter().enumerate()
+        .filter(|(occ_id,_)| predecessors_num[*occ_id] == 0)
+        .map(|(occ_id,occ)| (occ.positions[0],occ_id))
+        .collect();
+    let mut sequence = vec![];
+    loop {
+        let next = if smallest_first { ready.pop_first() } else { ready.pop_last() };
+        match next {
+            None => {
+                break;
+            },
+            Some( (_,occ_id) ) => {
+                sequence.push( occurrences[occ_id].activity.clone() );
+                for succ in &successors[occ_id] {
+                    predecessors_num[*succ] -= 1;
+                    if predecessors_num[*succ] == 0 {
+                        ready.insert( (occurrences[*succ].positions[0],*succ) );
+                    }
+                }
+            }
+        }
+    }
+    if sequence.len() < occurrences.len() {
+        return None;
+    }
+    return Some( sequence );
+}
+
+/**
+ * Orders the activities of a multi-trace, messages being ordered w.r.t. the actions
+ * which precede or follow their emission or receptions on the canals.
+ * Crossing messages (e.g. which are received in another order than that in which they are emitted)
+ * cannot all be ordered in this manner. Such messages are split into unpaired emissions and receptions
+ * until the activities are ordered.
+ * Two sequences are returned (or a single one if they are the same),
+ * obtained by giving priority to the first and to the last canals, so that activities
+ * occurring on distinct canals may be observed in both orders.
+ **/
+pub fn get_activity_sequences(multi_trace : &MultiTrace) -> Result<Vec<Vec<Activity>>,String> {
+    let mut occurrences = pair_emissions_and_receptions(multi_trace)?;
+    loop {
+        let successors = get_successors(multi_trace, &occurrences);
+        match linearize(&occurrences,&successors,true) {
+            Some( first_sequence ) => {
+                let last_sequence = linearize(&occurrences,&successors,false).unwrap();
+                if last_sequence == first_sequence {
+                    return Ok( vec![first_sequence] );
+                }
+                return Ok( vec![first_sequence,last_sequence] );
+            },
+            None => {
+                // there is a cycle and it involves a message
+                let unordered = unordered_message(&occurrences,&successors);
+                let message = occurrences.remove(unordered);
+                occurrences.append( &mut split_message(message) );
+            }
+        }
+    }
+}
+
+// a message which belongs to a cycle of the order
+fn unordered_message(occurrences : &[ActivityOccurrence], successors : &[BTreeSet<usize>]) -> usize {
+    // occurrences which can be reached from themselves
+    for (occ_id,occ) in occurrences.iter().enumerate() {
+        if !occ.is_message() {
+            continue;
+        }
+        let mut visited : BTreeSet<usize> = btreeset!{};
+        let mut to_visit : Vec<usize> = successors[occ_id].iter().cloned().collect();
+        while let Some(current) = to_visit.pop() {
+            if current == occ_id {
+                return occ_id;
+            }
+            if visited.insert(current) {
+                to_visit.extend(successors[current].iter().cloned());
+            }
+        }
+    }
+    panic!("cycle without messages");
+}
+
+fn split_message(message : ActivityOccurrence) -> Vec<ActivityOccurrence> {
+    let origin_lf_id = message.activity.origin_lf_id.unwrap();
+    let mut split = vec![ ActivityOccurrence{activity:Activity::new(message.activity.ms_id,Some(origin_lf_id),btreeset!{}),
+                                             positions:vec![message.positions[0]]} ];
+    for (rc_lf_id,rc_pos) in message.activity.target_lf_ids.iter().zip(message.positions.iter().skip(1)) {
+        split.push( ActivityOccurrence{activity:Activity::new(message.activity.ms_id,None,btreeset!{*rc_lf_id}),
+                                       positions:vec![*rc_pos]} );
+    }
+    return split;
+}
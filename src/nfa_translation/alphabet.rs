@@ -23,8 +23,8 @@ pub fn get_alphabet_from_gen_ctx(gen_ctx : &GeneralContext) -> Vec<BTreeSet<Trac
     let mut alphabet = vec![];
     for lf in 0..gen_ctx.get_lf_num() {
         for ms in 0..gen_ctx.get_ms_num() {
-            alphabet.push(btreeset!{TraceAction::new(lf,TraceActionKind::Emission,ms)});
-            alphabet.push(btreeset!{TraceAction::new(lf,TraceActionKind::Reception,ms)});
+            alphabet.push(btreeset!{TraceAction::new(lf,TraceActionKind::Emission,ms,vec![])});
+            alphabet.push(btreeset!{TraceAction::new(lf,TraceActionKind::Reception,ms,vec![])});
         }
     }
     alphabet
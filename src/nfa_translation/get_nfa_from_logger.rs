@@ -42,8 +42,23 @@ pub fn get_nfa_from_interaction_exploration(gen_ctx : &GeneralContext,
                                             int : &Interaction,
                                             base_alphabet : Vec<BTreeSet<TraceAction>>)
             -> (AutNFA<usize>,Duration) {
-
     let usize_alphabet : HashSet<usize> = (0..base_alphabet.len()).collect();
+    let (mut nfa,_,elapsed_get_nfa) = get_nfa_and_alphabet_from_interaction_exploration(gen_ctx,int,base_alphabet);
+    nfa.alphabet = usize_alphabet;
+    return (nfa, elapsed_get_nfa);
+}
+
+// letters are indices in the returned alphabet, which extends the base alphabet
+// with the (multi-)actions that were encountered during the exploration and did not belong to it
+// so that several interactions can be translated over the same alphabet by chaining calls
+// ***
+// only valid behaviors are translated i.e. those of the positive part of the interaction
+// the loop depth filter only concerns unbounded loops given that bounded ones do not count as loop instances
+pub fn get_nfa_and_alphabet_from_interaction_exploration(gen_ctx : &GeneralContext,
+                                                         int : &Interaction,
+                                                         base_alphabet : Vec<BTreeSet<TraceAction>>)
+            -> (AutNFA<usize>,Vec<BTreeSet<TraceAction>>,Duration) {
+    let int = int.get_positive_part();
     let max_loop_depth = int.total_loop_num()*2;
     let nfa_logger = GenericNFAITLogger::new(
         ActionNFAITPrinter::new(base_alphabet,
@@ -78,9 +93,9 @@ pub fn get_nfa_from_interaction_exploration(gen_ctx : &GeneralContext,
         raw_logger.as_any().downcast_ref::<GenericNFAITLogger<ExplorationConfig,usize,ActionNFAITPrinter>>().unwrap();
     // ***
     let mut nfa = nfa_logger.get_nfait().to_nfa();
-    let printer = nfa_logger.builder_printer.clone();
+    let alphabet = nfa_logger.builder_printer.index_to_action_map.clone();
     // ***
-    nfa.alphabet = usize_alphabet;
+    nfa.alphabet = (0..alphabet.len()).collect();
     // ***
-    return (nfa, elapsed_get_nfa);
+    return (nfa, alphabet, elapsed_get_nfa);
 }
\ No newline at end of file
@@ -0,0 +1,208 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::time::Duration;
+
+use autour_core::dfa::dfa::AutDFA;
+use autour_core::traits::translate::AutTranslatable;
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::nfa_translation::alphabet::get_alphabet_from_gen_ctx;
+use crate::nfa_translation::get_nfa_from_logger::get_nfa_and_alphabet_from_interaction_exploration;
+
+
+// the alphabet obtained from the first exploration is reused for the second one
+// so that both DFAs use the same letters
+pub fn get_dfas_over_common_alphabet(gen_ctx : &GeneralContext,
+                                     int1 : &Interaction,
+                                     int2 : &Interaction)
+            -> (AutDFA<usize>,AutDFA<usize>,Vec<BTreeSet<TraceAction>>,Duration) {
+    let (mut nfa1,alphabet,elapsed1) = get_nfa_and_alphabet_from_interaction_exploration(gen_ctx,int1,get_alphabet_from_gen_ctx(gen_ctx));
+    let (nfa2,alphabet,elapsed2) = get_nfa_and_alphabet_from_interaction_exploration(gen_ctx,int2,alphabet);
+    nfa1.alphabet = nfa2.alphabet.clone();
+    return (nfa1.to_dfa(),nfa2.to_dfa(),alphabet,elapsed1 + elapsed2);
+}
+
+
+// one of the DFAs may have no transition for a given letter, in which case its run is stuck (None)
+fn dfa_step(dfa : &AutDFA<usize>, state : Option<usize>, letter : usize) -> Option<usize> {
+    match state {
+        None => {
+            return None;
+        },
+        Some( got_state ) => {
+            return dfa.transitions[got_state].get(&letter).cloned();
+        }
+    }
+}
+
+fn dfa_accepts(dfa : &AutDFA<usize>, state : Option<usize>) -> bool {
+    match state {
+        None => {
+            return false;
+        },
+        Some( got_state ) => {
+            return dfa.finals.contains(&got_state);
+        }
+    }
+}
+
+// breadth-first search in the product of two DFAs over the same alphabet
+// returns a shortest word that is accepted by exactly one of them
+// (restricted to words accepted by the left DFA if "left_only" is set)
+// together with whether or not it is accepted by the left DFA
+pub fn find_shortest_distinguishing_word(left : &AutDFA<usize>,
+                                         right : &AutDFA<usize>,
+                                         left_only : bool) -> Option<(Vec<usize>,bool)> {
+    let mut letters : Vec<usize> = left.alphabet.union(&right.alphabet).cloned().collect();
+    letters.sort();
+    // ***
+    let init = (Some(left.initial),Some(right.initial));
+    let mut predecessors : HashMap<(Option<usize>,Option<usize>),Option<((Option<usize>,Option<usize>),usize)>> = HashMap::new();
+    predecessors.insert(init, None);
+    let mut queue = VecDeque::new();
+    queue.push_back(init);
+    while let Some(pair) = queue.pop_front() {
+        let left_accepts = dfa_accepts(left, pair.0);
+        let right_accepts = dfa_accepts(right, pair.1);
+        if left_accepts != right_accepts && (left_accepts || !left_only) {
+            let mut word = vec![];
+            let mut current = pair;
+            while let Some( (previous,letter) ) = predecessors.get(&current).unwrap() {
+                word.push(*letter);
+                current = *previous;
+            }
+            word.reverse();
+            return Some( (word,left_accepts) );
+        }
+        for letter in &letters {
+            let next = (dfa_step(left, pair.0, *letter),dfa_step(right, pair.1, *letter));
+            if next == (None,None) {
+                // no word may be accepted from there on
+                continue;
+            }
+            if !predecessors.contains_key(&next) {
+                predecessors.insert(next, Some((pair,*letter)));
+                queue.push_back(next);
+            }
+        }
+    }
+    return None;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::general_context::GeneralContext;
+    use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction};
+    use crate::core::language::syntax::interaction::{Interaction, LoopKind};
+    use crate::nfa_translation::language_comparison::{find_shortest_distinguishing_word, get_dfas_over_common_alphabet};
+    use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
+    use crate::util::test_fixtures::{context_with, emission, message};
+
+    #[test]
+    fn seq_is_included_in_par_but_not_conversely() {
+        let mut gen_ctx = GeneralContext::new();
+        let l1 = gen_ctx.add_lf("l1".to_string());
+        let l2 = gen_ctx.add_lf("l2".to_string());
+        let m1 = gen_ctx.add_msg("m1".to_string());
+        let m2 = gen_ctx.add_msg("m2".to_string());
+        let i_seq = Interaction::Seq(Box::new(message(l1,m1,l2)),Box::new(message(l2,m2,l1)));
+        let i_par = Interaction::Par(Box::new(message(l1,m1,l2)),Box::new(message(l2,m2,l1)));
+        // ***
+        let (dfa_seq,dfa_par,_,_) = get_dfas_over_common_alphabet(&gen_ctx,&i_seq,&i_par);
+        assert_eq!(find_shortest_distinguishing_word(&dfa_seq,&dfa_par,true), None);
+        let (word,left_accepts) = find_shortest_distinguishing_word(&dfa_par,&dfa_seq,true).unwrap();
+        assert!(left_accepts);
+        // l1!m1 and l2!m2 must both occur before the first reception
+        assert_eq!(word.len(), 4);
+        assert!(find_shortest_distinguishing_word(&dfa_seq,&dfa_par,false).is_some());
+    }
+
+    #[test]
+    fn bounded_loops_are_not_cut_off() {
+        let gen_ctx = context_with(&["a","b"],&["m1","m2"]);
+        // as "a -- m1 ->|"
+        let emission = Interaction::Emission(EmissionAction::new(0,0,CommunicationSynchronicity::Asynchronous,vec![],vec![]));
+        let unbounded = Interaction::Loop(LoopKind::SStrictSeq,Box::new(emission.clone()));
+        let bounded = Interaction::BoundedLoop(LoopKind::SStrictSeq,0,3,Box::new(emission.clone()));
+        // "loopS[0,3](a -- m1 ->|)" accepts at most three emissions
+        let (dfa_unbounded,dfa_bounded,_,_) = get_dfas_over_common_alphabet(&gen_ctx,&unbounded,&bounded);
+        assert_eq!(find_shortest_distinguishing_word(&dfa_bounded,&dfa_unbounded,true), None);
+        let (word,_) = find_shortest_distinguishing_word(&dfa_unbounded,&dfa_bounded,true).unwrap();
+        assert_eq!(word.len(), 4);
+        // with a body of two messages, all the five iterations are accepted
+        let body = Interaction::Strict(Box::new(message(0,0,1)),Box::new(message(0,1,1)));
+        let bounded_five = Interaction::BoundedLoop(LoopKind::SStrictSeq,0,5,Box::new(body.clone()));
+        let mut unfolded = Interaction::Empty;
+        for _ in 0..5 {
+            unfolded = Interaction::Alt(Box::new(Interaction::Empty),
+                                        Box::new(Interaction::Strict(Box::new(body.clone()),Box::new(unfolded))));
+        }
+        let (dfa_bounded_five,dfa_unfolded,_,_) = get_dfas_over_common_alphabet(&gen_ctx,&bounded_five,&unfolded);
+        assert_eq!(find_shortest_distinguishing_word(&dfa_bounded_five,&dfa_unfolded,false), None);
+    }
+
+    #[test]
+    fn larger_bounded_loop_does_not_refine_smaller_one() {
+        let gen_ctx = context_with(&["a","b"],&["m1","m2"]);
+        let emission = Interaction::Emission(EmissionAction::new(0,0,CommunicationSynchronicity::Asynchronous,vec![],vec![]));
+        let abstract_int = Interaction::BoundedLoop(LoopKind::SStrictSeq,0,2,Box::new(emission.clone()));
+        let detailed_int = Interaction::BoundedLoop(LoopKind::SStrictSeq,0,3,Box::new(emission.clone()));
+        // as in the "refines" command, we look for a trace of the detailed interaction which the abstract one rejects
+        let (dfa_detailed,dfa_abstract,_,_) = get_dfas_over_common_alphabet(&gen_ctx,&detailed_int,&abstract_int);
+        let (word,_) = find_shortest_distinguishing_word(&dfa_detailed,&dfa_abstract,true).unwrap();
+        assert_eq!(word.len(), 3);
+        // conversely the smaller bound does refine the larger one
+        assert_eq!(find_shortest_distinguishing_word(&dfa_abstract,&dfa_detailed,true), None);
+    }
+
+    #[test]
+    fn negative_fragments_add_no_valid_behavior() {
+        let gen_ctx = context_with(&["a","b"],&["m1","m2"]);
+        let i_msg = message(0,0,1);
+        let i_with_neg = Interaction::Seq(Box::new(message(0,0,1)),
+                                          Box::new(Interaction::Neg(Box::new(message(0,1,1)))));
+        let (dfa_msg,dfa_with_neg,_,_) = get_dfas_over_common_alphabet(&gen_ctx,&i_msg,&i_with_neg);
+        assert_eq!(find_shortest_distinguishing_word(&dfa_msg,&dfa_with_neg,false), None);
+        // "assert(i)" has the valid behaviors of "i"
+        let i_with_assert = Interaction::Assert(Box::new(message(0,0,1)));
+        let (dfa_msg,dfa_with_assert,_,_) = get_dfas_over_common_alphabet(&gen_ctx,&i_msg,&i_with_assert);
+        assert_eq!(find_shortest_distinguishing_word(&dfa_msg,&dfa_with_assert,false), None);
+    }
+
+    #[test]
+    fn hidden_interruptions_still_cut_off_the_interrupted_interaction() {
+        let gen_ctx = context_with(&["a","b","c"],&["m1","m2","m3"]);
+        // seq(a -- m1 -> b, a -- m2 -> b)
+        let abstract_int = Interaction::Seq(Box::new(message(0,0,1)),Box::new(message(0,1,1)));
+        // disrupt(seq(a -- m1 -> b, a -- m2 -> b), c -- m3 ->|)
+        let detailed_int = Interaction::Disrupt(Box::new(abstract_int.clone()),Box::new(emission(2,2)));
+        // as in the "refines" command, with lifeline "c" hidden
+        let hidden = btreeset!{2};
+        let (dfa_detailed,dfa_abstract,_,_) = get_dfas_over_common_alphabet(&gen_ctx,
+                                                                            &detailed_int.eliminate_lifelines(&hidden),
+                                                                            &abstract_int.eliminate_lifelines(&hidden));
+        // the empty trace is accepted once the interruption is hidden
+        let (word,_) = find_shortest_distinguishing_word(&dfa_detailed,&dfa_abstract,true).unwrap();
+        assert!(word.is_empty());
+        assert_eq!(find_shortest_distinguishing_word(&dfa_abstract,&dfa_detailed,true), None);
+    }
+}
@@ -19,5 +19,6 @@ pub mod get_nfa_from_logger;
 pub mod compositional;
 pub mod experiments;
 pub mod canonize;
+pub mod language_comparison;
 pub mod experiments2;
 
@@ -18,6 +18,7 @@ use std::collections::{BTreeSet, HashSet};
 use std::fs::File;
 use std::io::Write;
 
+use crate::core::language::syntax::value::arguments_as_text;
 use crate::core::general_context::GeneralContext;
 use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
 use crate::core::language::syntax::interaction::Interaction;
@@ -55,7 +56,7 @@ fn to_plant_uml_ap_rec(output_file : &mut File,
             return (last_state,state_count);
         },
         &Interaction::Emission(ref em_act) => {
-            let ms_name = gen_ctx.get_ms_name(em_act.ms_id).unwrap();
+            let ms_name = format!("{}{}", gen_ctx.get_ms_name(em_act.ms_id).unwrap(), arguments_as_text(&em_act.args));
             match em_act.targets.len() {
                 0 => {
                     let transition_label = format!("!{}",ms_name);
@@ -70,7 +71,7 @@ fn to_plant_uml_ap_rec(output_file : &mut File,
             }
         },
         &Interaction::Reception(ref rc_act) => {
-            let ms_name = gen_ctx.get_ms_name(rc_act.ms_id).unwrap();
+            let ms_name = format!("{}{}", gen_ctx.get_ms_name(rc_act.ms_id).unwrap(), arguments_as_text(&rc_act.args));
             let transition_label = format!("?{}",ms_name);
             let new_state_label = format!("lf{}_s{}",lf_id,state_count);
             let last_state_label = get_last_label(lf_id, last_state);
@@ -169,6 +170,18 @@ fn to_plant_uml_ap_rec(output_file : &mut File,
         &Interaction::CoReg(_,_,_) => {
             panic!("translation towards puml-ap does not implement co-regions");
         },
+        &Interaction::BoundedLoop(_,_,_,_) => {
+            panic!("translation towards puml-ap does not implement bounded loops");
+        },
+        &Interaction::Neg(_) => {
+            panic!("translation towards puml-ap does not implement negative fragments");
+        },
+        &Interaction::Assert(_) => {
+            panic!("translation towards puml-ap does not implement assertions");
+        },
+        &Interaction::Disrupt(_,_) => {
+            panic!("translation towards puml-ap does not implement interruptions");
+        },
         &Interaction::Sync(_,_,_) => {
             panic!("translation towards puml-ap does not implement sync");
         },
@@ -17,6 +17,7 @@ limitations under the License.
 use std::fs::File;
 use std::io::Write;
 
+use crate::core::language::syntax::value::arguments_as_text;
 use crate::core::general_context::GeneralContext;
 use crate::core::language::syntax::action::*;
 use crate::core::language::syntax::interaction::{Interaction, LoopKind};
@@ -41,7 +42,7 @@ fn to_plant_uml_sd_rec(output_file : &mut File,
     match interaction {
         &Interaction::Empty => {},
         &Interaction::Reception(ref rc_act) => {
-            let ms_name = gen_ctx.get_ms_name(rc_act.ms_id).unwrap();
+            let ms_name = format!("{}{}", gen_ctx.get_ms_name(rc_act.ms_id).unwrap(), arguments_as_text(&rc_act.args));
             match rc_act.recipients.len() {
                 1 => {
                     let lf_id = rc_act.recipients.get(0).unwrap();
@@ -54,7 +55,7 @@ fn to_plant_uml_sd_rec(output_file : &mut File,
             }
         },
         &Interaction::Emission(ref em_act) => {
-            let ms_name = gen_ctx.get_ms_name(em_act.ms_id).unwrap();
+            let ms_name = format!("{}{}", gen_ctx.get_ms_name(em_act.ms_id).unwrap(), arguments_as_text(&em_act.args));
             let lf_name = gen_ctx.get_lf_name(em_act.origin_lf_id).unwrap();
             match em_act.targets.len() {
                 0 => {
@@ -145,6 +146,28 @@ fn to_plant_uml_sd_rec(output_file : &mut File,
             to_plant_uml_sd_rec(output_file, i1, gen_ctx);
             output_file.write( "end\n".as_bytes() );
         },
+        &Interaction::BoundedLoop(_, min, max, ref i1) => {
+            output_file.write( format!("loop {}, {}\n", min, max).as_bytes() );
+            to_plant_uml_sd_rec(output_file, i1, gen_ctx);
+            output_file.write( "end\n".as_bytes() );
+        },
+        &Interaction::Neg(ref i1) => {
+            output_file.write( "group neg\n".as_bytes() );
+            to_plant_uml_sd_rec(output_file, i1, gen_ctx);
+            output_file.write( "end\n".as_bytes() );
+        },
+        &Interaction::Assert(ref i1) => {
+            output_file.write( "group assert\n".as_bytes() );
+            to_plant_uml_sd_rec(output_file, i1, gen_ctx);
+            output_file.write( "end\n".as_bytes() );
+        },
+        &Interaction::Disrupt(ref i1, ref i2) => {
+            output_file.write( "group disrupt\n".as_bytes() );
+            to_plant_uml_sd_rec(output_file, i1, gen_ctx);
+            output_file.write( "else\n".as_bytes() );
+            to_plant_uml_sd_rec(output_file, i2, gen_ctx);
+            output_file.write( "end\n".as_bytes() );
+        },
         &Interaction::CoReg(_,_,_) => {
             panic!("translation towards puml-sd does not implement co-regions");
         },
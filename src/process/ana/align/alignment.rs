@@ -0,0 +1,102 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::execution::trace::trace::TraceAction;
+
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AlignmentMoveKind {
+    Synchronous, // a multi-action of the multi-trace is executed by the interaction
+    Deletion,    // a multi-action of the multi-trace is removed
+    Insertion    // an action of the interaction is executed and inserted in the multi-trace
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct AlignmentMove {
+    pub kind : AlignmentMoveKind,
+    pub actions : BTreeSet<TraceAction>
+}
+
+impl AlignmentMove {
+
+    pub fn new(kind : AlignmentMoveKind, actions : BTreeSet<TraceAction>) -> AlignmentMove {
+        return AlignmentMove{kind,actions};
+    }
+
+    // each inserted or deleted action costs one
+    pub fn get_cost(&self) -> u32 {
+        match self.kind {
+            AlignmentMoveKind::Synchronous => {
+                return 0;
+            },
+            _ => {
+                return self.actions.len() as u32;
+            }
+        }
+    }
+
+}
+
+/**
+ * A minimal-cost sequence of moves turning a multi-trace into one which is accepted by an interaction.
+ * Its cost is the edit distance between the multi-trace and the language of the interaction,
+ * counting insertions and deletions of actions.
+ **/
+#[derive(Clone, PartialEq, Debug)]
+pub struct MultiTraceAlignment {
+    pub cost : u32,
+    pub moves : Vec<AlignmentMove>
+}
+
+impl MultiTraceAlignment {
+
+    pub fn new(cost : u32, moves : Vec<AlignmentMove>) -> MultiTraceAlignment {
+        return MultiTraceAlignment{cost,moves};
+    }
+
+    // the moves which concern each canal, with only the actions which occur on that canal
+    pub fn get_canal_alignments(&self, co_localizations : &CoLocalizations) -> Vec<Vec<AlignmentMove>> {
+        let mut canal_alignments : Vec<Vec<AlignmentMove>> = co_localizations.locs_lf_ids.iter().map(|_| vec![]).collect();
+        for align_move in &self.moves {
+            for (canal_id,canal_lfs) in co_localizations.locs_lf_ids.iter().enumerate() {
+                let on_canal : BTreeSet<TraceAction> = align_move.actions.iter()
+                    .filter(|act| canal_lfs.contains(&act.lf_id))
+                    .cloned()
+                    .collect();
+                if !on_canal.is_empty() {
+                    canal_alignments[canal_id].push( AlignmentMove::new(align_move.kind,on_canal) );
+                }
+            }
+        }
+        return canal_alignments;
+    }
+
+    // the multi-trace which is accepted by the interaction once deletions and insertions are applied
+    pub fn get_repaired_multi_trace(&self, co_localizations : &CoLocalizations) -> MultiTrace {
+        return self.get_canal_alignments(co_localizations).into_iter()
+            .map(|canal_moves| canal_moves.into_iter()
+                .filter(|align_move| align_move.kind != AlignmentMoveKind::Deletion)
+                .map(|align_move| align_move.actions)
+                .collect())
+            .collect();
+    }
+
+}
@@ -0,0 +1,186 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, BTreeSet, HashMap};
+use graph_process_manager_core::delegate::node::GenericNode;
+use graph_process_manager_core::handler::handler::AbstractProcessHandler;
+use graph_process_manager_core::queued_steps::step::GenericStep;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::frontier::global_frontier;
+use crate::core::execution::semantics::negative::is_position_within_negative_fragment;
+use crate::core::execution::trace::multitrace::{multi_trace_length, MultiTrace};
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::process::ana::align::alignment::{AlignmentMove, AlignmentMoveKind, MultiTraceAlignment};
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::handling::handler::AnalysisProcessHandler;
+use crate::process::ana::node::flags::MultiTraceAnalysisFlags;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::param::anakind::AnalysisKind;
+use crate::process::ana::param::param::AnalysisParameterization;
+use crate::process::ana::step::AnalysisStepKind;
+
+
+struct AlignmentSearchNode {
+    kind : AnalysisNodeKind,
+    predecessor : Option<(usize,AlignmentMove)>
+}
+
+fn get_consumed(node_kind : &AnalysisNodeKind) -> Vec<usize> {
+    return node_kind.flags.canals.iter().map(|canal| canal.consumed).collect();
+}
+
+fn get_next_moves(context : &AnalysisContext,
+                  param : &AnalysisParameterization,
+                  node_kind : &AnalysisNodeKind) -> Vec<(AnalysisNodeKind,AlignmentMove)> {
+    let mut next_moves = vec![];
+    // synchronous moves are those of an "accept" analysis
+    let parent = GenericNode::new(node_kind.clone(),hashset!{},0);
+    let steps = param.get_action_matches_in_analysis(false,false,context,&node_kind.interaction,&node_kind.flags);
+    for (idx,step_kind) in steps.into_iter().enumerate() {
+        let mut consumed_actions : BTreeSet<TraceAction> = btreeset!{};
+        if let AnalysisStepKind::Execute( _, consu_set, _ ) = &step_kind {
+            for canal_id in consu_set {
+                let canal_flags = node_kind.flags.canals.get(*canal_id).unwrap();
+                if let Some(head) = context.multi_trace.get(*canal_id).unwrap().get(canal_flags.consumed) {
+                    consumed_actions.extend(head.iter().cloned());
+                }
+            }
+        }
+        let step = GenericStep::new(0,(idx as u32) + 1,step_kind);
+        let new_kind = AnalysisProcessHandler::process_new_step(context,param,&parent,&step,0,0);
+        if !new_kind.forbidden {
+            next_moves.push( (new_kind,AlignmentMove::new(AlignmentMoveKind::Synchronous,consumed_actions)) );
+        }
+    }
+    // deletions of the multi-actions at the head of the canals
+    for (canal_id,canal_flags) in node_kind.flags.canals.iter().enumerate() {
+        if let Some(head) = context.multi_trace.get(canal_id).unwrap().get(canal_flags.consumed) {
+            let mut new_flags = node_kind.flags.clone();
+            new_flags.canals.get_mut(canal_id).unwrap().consumed += 1;
+            let new_kind = AnalysisNodeKind::new(node_kind.interaction.clone(),new_flags,0,false);
+            next_moves.push( (new_kind,AlignmentMove::new(AlignmentMoveKind::Deletion,head.clone())) );
+        }
+    }
+    // insertions of the actions which are immediately executable in the interaction
+    for frt_elt in global_frontier(&node_kind.interaction,true) {
+        if is_position_within_negative_fragment(&node_kind.interaction,&frt_elt.position) {
+            continue;
+        }
+        let exe_result = execute_interaction(&node_kind.interaction,&frt_elt.position,&frt_elt.target_lf_ids,false);
+        let new_kind = AnalysisNodeKind::new(exe_result.interaction,node_kind.flags.clone(),0,false);
+        next_moves.push( (new_kind,AlignmentMove::new(AlignmentMoveKind::Insertion,frt_elt.target_actions)) );
+    }
+    return next_moves;
+}
+
+/**
+ * Searches for a minimal-cost alignment in the manner of Dijkstra's algorithm
+ * in which analysis nodes are reached via synchronous moves, which are free,
+ * and via insertions and deletions of actions.
+ * Among nodes of equal cost, those which have consumed more of the multi-trace are explored first.
+ * Returns None if there is no alignment of cost at most "max_cost".
+ **/
+pub fn align_multi_trace(gen_ctx : GeneralContext,
+                         co_localizations : CoLocalizations,
+                         multi_trace : MultiTrace,
+                         interaction : Interaction,
+                         max_cost : Option<u32>) -> Option<MultiTraceAlignment> {
+    let canals_num = multi_trace.len();
+    let multi_trace_len = multi_trace_length(&multi_trace);
+    let context = AnalysisContext::new(gen_ctx,co_localizations,multi_trace,multi_trace_len);
+    let param = AnalysisParameterization::new(AnalysisKind::Accept,None,false);
+    // ***
+    let init_kind = AnalysisNodeKind::new(interaction,MultiTraceAnalysisFlags::new_init(canals_num,0,0),0,false);
+    let mut best_costs : HashMap<(Interaction,Vec<usize>),u32> = HashMap::new();
+    best_costs.insert( (init_kind.interaction.clone(),get_consumed(&init_kind)), 0 );
+    let mut nodes : Vec<AlignmentSearchNode> = vec![AlignmentSearchNode{kind:init_kind,predecessor:None}];
+    let mut queue : BinaryHeap<Reverse<(u32,Reverse<usize>,usize)>> = BinaryHeap::new();
+    queue.push( Reverse((0,Reverse(0),0)) );
+    // ***
+    while let Some( Reverse((cost,_,node_id)) ) = queue.pop() {
+        if let Some(max) = max_cost {
+            if cost > max {
+                return None;
+            }
+        }
+        let node_kind = nodes[node_id].kind.clone();
+        let consumed = get_consumed(&node_kind);
+        if best_costs.get(&(node_kind.interaction.clone(),consumed.clone())) != Some(&cost) {
+            // a cheaper path to this node has been found since it was queued
+            continue;
+        }
+        if node_kind.flags.is_multi_trace_empty(&context.multi_trace) && node_kind.interaction.express_empty() {
+            let mut moves = vec![];
+            let mut current = node_id;
+            while let Some( (pred_id,align_move) ) = &nodes[current].predecessor {
+                moves.push(align_move.clone());
+                current = *pred_id;
+            }
+            moves.reverse();
+            return Some( MultiTraceAlignment::new(cost,moves) );
+        }
+        for (new_kind,align_move) in get_next_moves(&context,&param,&node_kind) {
+            let new_cost = cost + align_move.get_cost();
+            let new_consumed = get_consumed(&new_kind);
+            let key = (new_kind.interaction.clone(),new_consumed.clone());
+            match best_costs.get(&key) {
+                Some( known_cost ) if *known_cost <= new_cost => {},
+                _ => {
+                    best_costs.insert(key,new_cost);
+                    let new_id = nodes.len();
+                    nodes.push( AlignmentSearchNode{kind:new_kind,predecessor:Some((node_id,align_move))} );
+                    queue.push( Reverse((new_cost,Reverse(new_consumed.iter().sum()),new_id)) );
+                }
+            }
+        }
+    }
+    return None;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::execution::trace::trace::TraceActionKind;
+    use crate::util::test_fixtures::{action, context_with, message};
+    use super::*;
+
+    #[test]
+    fn cost_counts_deleted_and_inserted_actions() {
+        let gen_ctx = context_with(&["l1","l2"],&["m1","m2"]);
+        // seq(l1 -- m1 -> l2, l2 -- m2 -> l1)
+        let int = Interaction::Seq(Box::new(message(0,0,1)),Box::new(message(1,1,0)));
+        // ***
+        let accepted : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0),action(0,TraceActionKind::Reception,1)],
+                                         vec![action(1,TraceActionKind::Reception,0),action(1,TraceActionKind::Emission,1)]];
+        let alignment = align_multi_trace(gen_ctx.clone(),CoLocalizations::get_discrete_partition(2),accepted,int.clone(),None).unwrap();
+        assert_eq!(alignment.cost,0);
+        // ***
+        // "l2?m1" is received twice and "l2!m2.l1?m2" is missing
+        let deviating : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0)],
+                                          vec![action(1,TraceActionKind::Reception,0),action(1,TraceActionKind::Reception,0)]];
+        let alignment = align_multi_trace(gen_ctx.clone(),CoLocalizations::get_discrete_partition(2),deviating.clone(),int.clone(),None).unwrap();
+        assert_eq!(alignment.cost,3);
+        assert_eq!(alignment.moves.iter().filter(|m| m.kind == AlignmentMoveKind::Deletion).count(),1);
+        assert!(align_multi_trace(gen_ctx,CoLocalizations::get_discrete_partition(2),deviating,int,Some(2)).is_none());
+    }
+
+}
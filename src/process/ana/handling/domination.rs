@@ -41,7 +41,7 @@ pub fn get_head_actions_ids_maps(algo_uses_lifeline_removal_steps : bool,
         // iter head actions to look for a match
         'iter_head : for (x,(coloc_id,head,is_last)) in head_actions.iter().enumerate() {
             // if there is a match keeps track of frt_elt and the follow_up interaction
-            if frt_elt.target_actions == **head {
+            if TraceAction::multi_action_matches(&frt_elt.target_actions,head) {
                 let exe_result = execute_interaction(interaction,
                                                      &frt_elt.position,
                                                      &frt_elt.target_lf_ids,
@@ -85,7 +85,7 @@ pub fn get_domination_domain(
         let mut left_then_right = hashset!{};
         for left_follow_up in head_action_id_to_follow_ups.get(&left).unwrap() {
             for after_left in global_frontier(left_follow_up,true) {
-                if after_left.target_actions == **right_actions {
+                if TraceAction::multi_action_matches(&after_left.target_actions,right_actions) {
                     let exe_result = execute_interaction(left_follow_up,
                                                          &after_left.position,
                                                          &after_left.target_lf_ids,
@@ -103,7 +103,7 @@ pub fn get_domination_domain(
         let mut right_then_left = hashset!{};
         for right_follow_up in head_action_id_to_follow_ups.get(&right).unwrap() {
             for after_right in global_frontier(right_follow_up,true) {
-                if after_right.target_actions == **left_actions {
+                if TraceAction::multi_action_matches(&after_right.target_actions,left_actions) {
                     let exe_result = execute_interaction(right_follow_up,
                                                          &after_right.position,
                                                          &after_right.target_lf_ids,
@@ -152,7 +152,7 @@ pub fn get_domination_maps(
         let mut left_then_right = hashset!{};
         for left_follow_up in head_action_id_to_follow_ups.get(&left).unwrap() {
             for after_left in global_frontier(left_follow_up,true) {
-                if after_left.target_actions == **right_actions {
+                if TraceAction::multi_action_matches(&after_left.target_actions,right_actions) {
                     let exe_result = execute_interaction(left_follow_up,
                                                          &after_left.position,
                                                          &after_left.target_lf_ids,
@@ -170,7 +170,7 @@ pub fn get_domination_maps(
         let mut right_then_left = hashset!{};
         for right_follow_up in head_action_id_to_follow_ups.get(&right).unwrap() {
             for after_right in global_frontier(right_follow_up,true) {
-                if after_right.target_actions == **left_actions {
+                if TraceAction::multi_action_matches(&after_right.target_actions,left_actions) {
                     let exe_result = execute_interaction(right_follow_up,
                                                          &after_right.position,
                                                          &after_right.target_lf_ids,
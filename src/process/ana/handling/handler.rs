@@ -20,6 +20,7 @@ use graph_process_manager_core::delegate::node::GenericNode;
 use graph_process_manager_core::handler::handler::AbstractProcessHandler;
 use graph_process_manager_core::queued_steps::step::GenericStep;
 use crate::core::execution::semantics::execute::execute_interaction;
+use crate::core::execution::semantics::negative::{is_assertion_reached, is_position_within_negative_fragment};
 use crate::core::execution::trace::multitrace::Trace;
 use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
 use crate::core::language::involve::involves::InvolvesLifelines;
@@ -53,7 +54,7 @@ impl AbstractProcessHandler<AnalysisConfig> for AnalysisProcessHandler {
                 // ***
                 let new_flags = parent_state.kind.flags.update_on_hide(&context.gen_ctx,coloc_ids_to_hide);
                 // ***
-                AnalysisNodeKind::new(new_interaction,new_flags,parent_state.kind.ana_loop_depth)
+                AnalysisNodeKind::new(new_interaction,new_flags,parent_state.kind.ana_loop_depth,parent_state.kind.forbidden)
             },
             AnalysisStepKind::Execute( ref frt_elt, ref consu_set, ref sim_map ) => {
                 let exe_result = execute_interaction(&parent_state.kind.interaction,
@@ -69,7 +70,8 @@ impl AbstractProcessHandler<AnalysisConfig> for AnalysisProcessHandler {
                                                                             &exe_result.interaction);
                 // ***
                 let new_ana_loop_depth = parent_state.kind.ana_loop_depth + frt_elt.max_loop_depth;
-                AnalysisNodeKind::new(exe_result.interaction,new_flags,new_ana_loop_depth)
+                let new_forbidden = parent_state.kind.forbidden || is_position_within_negative_fragment(&parent_state.kind.interaction,&frt_elt.position);
+                AnalysisNodeKind::new(exe_result.interaction,new_flags,new_ana_loop_depth,new_forbidden)
             }
         }
     }
@@ -98,7 +100,7 @@ impl AbstractProcessHandler<AnalysisConfig> for AnalysisProcessHandler {
 
         if !parent_node_kind.flags.is_multi_trace_empty(&context.multi_trace) {
             match &param.ana_kind {
-                AnalysisKind::Accept => {
+                AnalysisKind::Accept | AnalysisKind::Align(_) => {
                     param.get_action_matches_in_analysis(
                         param.partial_order_reduction,
                         false,
@@ -166,10 +168,13 @@ impl AbstractProcessHandler<AnalysisConfig> for AnalysisProcessHandler {
     fn get_local_verdict_when_no_child(context: &AnalysisContext,
                                        param : &AnalysisParameterization,
                                        node_kind: &AnalysisNodeKind) -> AnalysisLocalVerdict {
+        if node_kind.forbidden {
+            return get_local_verdict_within_negative_fragment(context,param,node_kind);
+        }
         if node_kind.flags.is_multi_trace_empty(&context.multi_trace) {
             if node_kind.interaction.express_empty() {
                 match param.ana_kind {
-                    AnalysisKind::Accept => {
+                    AnalysisKind::Accept | AnalysisKind::Align(_) => {
                         return AnalysisLocalVerdict::Cov;
                     },
                     AnalysisKind::Prefix => {
@@ -202,7 +207,7 @@ impl AbstractProcessHandler<AnalysisConfig> for AnalysisProcessHandler {
                 }
             } else { /* multi-trace empty but interaction does not express empty */
                 match param.ana_kind {
-                    AnalysisKind::Accept => {
+                    AnalysisKind::Accept | AnalysisKind::Align(_) => {
                         return AnalysisLocalVerdict::Out(false);
                     },
                     AnalysisKind::Prefix => {
@@ -236,17 +241,25 @@ impl AbstractProcessHandler<AnalysisConfig> for AnalysisProcessHandler {
             }
         } else { /* multi-trace not emptied */
             match param.ana_kind {
-                AnalysisKind::Accept => {
+                AnalysisKind::Accept | AnalysisKind::Align(_) => {
+                    if is_assertion_reached(&node_kind.interaction) {
+                        return AnalysisLocalVerdict::ForbiddenMatched;
+                    }
                     return AnalysisLocalVerdict::Out(false);
                 },
                 AnalysisKind::Prefix => {
                     if node_kind.flags.is_any_component_empty(&context.multi_trace) {
                         return AnalysisLocalVerdict::Inconc(InconcReason::LackObs);
+                    } else if is_assertion_reached(&node_kind.interaction) {
+                        return AnalysisLocalVerdict::ForbiddenMatched;
                     } else {
                         return AnalysisLocalVerdict::Out(false);
                     }
                 },
                 AnalysisKind::Eliminate => {
+                    if is_assertion_reached(&node_kind.interaction) {
+                        return AnalysisLocalVerdict::ForbiddenMatched;
+                    }
                     return AnalysisLocalVerdict::Out(false);
                 },
                 AnalysisKind::Simulate(_) => {
@@ -313,3 +326,33 @@ impl AbstractProcessHandler<AnalysisConfig> for AnalysisProcessHandler {
     }
 }
 
+
+// once an action from a negative fragment has been executed
+// the multi-trace is forbidden if it matches the remainder of the negative scenario
+// otherwise this path cannot lead to an accepted multi-trace
+fn get_local_verdict_within_negative_fragment(context: &AnalysisContext,
+                                              param : &AnalysisParameterization,
+                                              node_kind: &AnalysisNodeKind) -> AnalysisLocalVerdict {
+    let is_matched = node_kind.flags.is_multi_trace_empty(&context.multi_trace)
+        && node_kind.interaction.express_empty()
+        && !node_kind.flags.is_any_component_hidden();
+    match param.ana_kind {
+        AnalysisKind::Simulate(_) => {
+            match (is_matched, node_kind.flags.is_simulated()) {
+                (true, WasMultiTraceConsumedWithSimulation::No) => {
+                    return AnalysisLocalVerdict::ForbiddenMatched;
+                },
+                _ => {
+                    return AnalysisLocalVerdict::OutSim(false);
+                }
+            }
+        },
+        _ => {
+            if is_matched {
+                return AnalysisLocalVerdict::ForbiddenMatched;
+            } else {
+                return AnalysisLocalVerdict::Out(false);
+            }
+        }
+    }
+}
@@ -171,7 +171,7 @@ pub fn perform_local_analysis(gen_ctx : &GeneralContext,
                                                                                                         loggers,
                                                                                                         Some(AnalysisGlobalVerdict::WeakPass),
                                                                                                         true);
-    let init_node = AnalysisNodeKind::new(local_interaction,local_flags,0);
+    let init_node = AnalysisNodeKind::new(local_interaction,local_flags,0,false);
     let (_,verd) = local_analysis_manager.start_process(init_node);
     return verd;
 }
\ No newline at end of file
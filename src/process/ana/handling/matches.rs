@@ -84,7 +84,7 @@ impl AnalysisParameterization {
             // ***
             let mut match_on_canal : Vec<usize> = vec!{}; // ids of the canals on which there is a match
             let mut ok_canals : HashSet<usize> = hashset!{}; // canals in which we already do something match or simu
-            let mut act_left_to_match : HashSet<&TraceAction> = frt_elt.target_actions.iter().collect();
+            let mut act_left_to_match : Vec<&TraceAction> = frt_elt.target_actions.iter().collect();
             for (canal_id, canal_flag) in flags.canals.iter().enumerate() {
                 let canal_trace = context.multi_trace.get(canal_id).unwrap();
                 match canal_trace.get(canal_flag.consumed) {
@@ -92,19 +92,27 @@ impl AnalysisParameterization {
                     Some( got_multiact ) => {
                         let mut intersect_with_front_elt = false;
                         let mut entirely_included_in_front_elt = true;
+                        let mut matched_idxs : Vec<usize> = vec![];
                         for got_act in got_multiact {
-                            if act_left_to_match.contains(got_act) {
-                                intersect_with_front_elt = true;
-                            } else {
-                                entirely_included_in_front_elt = false;
+                            let found = act_left_to_match.iter().enumerate()
+                                .position(|(idx,act)| !matched_idxs.contains(&idx) && act.matches(got_act));
+                            match found {
+                                Some( idx ) => {
+                                    intersect_with_front_elt = true;
+                                    matched_idxs.push(idx);
+                                },
+                                None => {
+                                    entirely_included_in_front_elt = false;
+                                }
                             }
                         }
                         // ***
                         if intersect_with_front_elt && entirely_included_in_front_elt {
                             match_on_canal.push(canal_id );
                             ok_canals.insert(canal_id);
-                            for got_act in got_multiact {
-                                act_left_to_match.remove(got_act);
+                            matched_idxs.sort();
+                            for idx in matched_idxs.into_iter().rev() {
+                                act_left_to_match.remove(idx);
                             }
                         }
                     }
@@ -213,7 +221,7 @@ impl AnalysisParameterization {
         let mono_coloc_interaction = interaction.eliminate_lifelines(&lfs_to_remove);
         let mut match_count = 0;
         for frt_elt in global_frontier(&mono_coloc_interaction, false) {
-            if frt_elt.target_actions == *head_multi_action {
+            if TraceAction::multi_action_matches(&frt_elt.target_actions,head_multi_action) {
                 match_count += 1;
                 if match_count >= 2 {
                     return false;
@@ -255,7 +263,7 @@ impl AnalysisParameterization {
                 // iter head actions to look for a match
                 'iter_head : for (coloc_id,head) in head_actions.iter() {
                     // if there is a match keeps track of frt_elt
-                    if frt_elt.target_actions == **head {
+                    if TraceAction::multi_action_matches(&frt_elt.target_actions,head) {
                         coloc_ids_to_frontier_elts.entry(*coloc_id)
                             .or_insert_with(Vec::new)
                             .push(frt_elt);
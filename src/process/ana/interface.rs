@@ -0,0 +1,224 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::{multi_trace_length, multi_trace_without_messages, MultiTrace};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::eliminate_ms::eliminable::MessageEliminable;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::process::ana::conf::AnalysisConfig;
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::filter::elim::AnalysisFilterEliminationKind;
+use crate::process::ana::filter::filter::AnalysisFilterCriterion;
+use crate::process::ana::node::flags::MultiTraceAnalysisFlags;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::priorities::AnalysisPriorities;
+use crate::process::ana::step::AnalysisStepKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::parallel::manager::ParallelProcessManager;
+
+
+pub fn hide_unobserved_messages(interaction : Interaction,
+                                multi_trace : MultiTrace,
+                                unobserved_messages : &BTreeSet<usize>) -> (Interaction,MultiTrace) {
+    if unobserved_messages.is_empty() {
+        return (interaction,multi_trace);
+    }
+    return (interaction.eliminate_messages(unobserved_messages),
+            multi_trace_without_messages(&multi_trace,unobserved_messages));
+}
+
+// unobserved messages must have been hidden from the interaction and multi-trace beforehand
+// the manager is returned so that its loggers can be inspected
+pub fn analyze_multi_trace(gen_ctx : GeneralContext,
+                           co_localizations : CoLocalizations,
+                           multi_trace : MultiTrace,
+                           interaction : Interaction,
+                           ana_opts : HibouAnalyzeOptions) -> (ParallelProcessManager<AnalysisConfig>,u32,AnalysisGlobalVerdict) {
+    let multi_trace_length = multi_trace_length(&multi_trace);
+    let ana_ctx = AnalysisContext::new(gen_ctx,co_localizations,multi_trace,multi_trace_length);
+    let delegate : GenericProcessDelegate<AnalysisStepKind,AnalysisNodeKind,AnalysisPriorities> = GenericProcessDelegate::new(ana_opts.strategy,ana_opts.priorities);
+    // ***
+    let init_flags = match ana_opts.ana_param.ana_kind.get_sim_config() {
+        None => {
+            MultiTraceAnalysisFlags::new_init(ana_ctx.co_localizations.num_colocs(),
+                                              0,
+                                              0)
+        },
+        Some( sim_config ) => {
+            MultiTraceAnalysisFlags::new_init(ana_ctx.co_localizations.num_colocs(),
+                                              sim_config.get_reset_rem_loop(multi_trace_length,&interaction),
+                                              sim_config.get_reset_rem_act(multi_trace_length,&interaction))
+        }
+    };
+    // ***
+    let filters : Vec<Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>> = ana_opts.filters.into_iter()
+        .map(|filter| Box::new(filter) as Box<dyn AbstractFilter<AnalysisFilterCriterion,AnalysisFilterEliminationKind>>)
+        .collect();
+    let mut analysis_manager : ParallelProcessManager<AnalysisConfig> = ParallelProcessManager::new(ana_ctx,
+                                                                                                    ana_opts.ana_param,
+                                                                                                    delegate,
+                                                                                                    filters,
+                                                                                                    ana_opts.loggers,
+                                                                                                    ana_opts.goal,
+                                                                                                    ana_opts.use_memoization,
+                                                                                                    ana_opts.threads,
+                                                                                                    ana_opts.limits);
+    let init_node = AnalysisNodeKind::new(interaction,init_flags,0,false);
+    let (node_count,verdict) = analysis_manager.start_process(init_node);
+    return (analysis_manager,node_count,verdict);
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::execution::trace::trace::TraceActionKind;
+    use crate::core::language::syntax::interaction::LoopKind;
+    use crate::process::ana::filter::filter::AnalysisFilter;
+    use crate::util::test_fixtures::{action, actions, context_with, emission, message};
+    use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+    use super::*;
+
+    fn analyze_on_single_lifeline(interaction : Interaction, multi_trace : MultiTrace) -> AnalysisGlobalVerdict {
+        let mut ana_opts = HibouAnalyzeOptions::default();
+        ana_opts.goal = None;
+        let (_,_,verdict) = analyze_multi_trace(context_with(&["a"],&["m1","m2","m3"]),
+                                                CoLocalizations::get_discrete_partition(1),
+                                                multi_trace,
+                                                interaction,
+                                                ana_opts);
+        return verdict;
+    }
+
+    #[test]
+    fn same_outcome_whatever_the_number_of_threads() {
+        let gen_ctx = context_with(&["l1","l2","l3","l4"],&["m1","m2","m3"]);
+        // loopP(alt(l1 -- m1 -> l2, alt(l3 -- m2 -> l4, l1 -- m3 -> l4)))
+        let int = Interaction::Loop(LoopKind::PInterleaving,
+                                    Box::new(Interaction::Alt(Box::new(message(0,0,1)),
+                                                              Box::new(Interaction::Alt(Box::new(message(2,1,3)),
+                                                                                        Box::new(message(0,2,3)))))));
+        let multi_trace : MultiTrace = vec![actions(0,TraceActionKind::Emission,vec![0,2]),
+                                            actions(1,TraceActionKind::Reception,vec![0]),
+                                            actions(2,TraceActionKind::Emission,vec![1]),
+                                            actions(3,TraceActionKind::Reception,vec![2,1])];
+        let mut outcomes = vec![];
+        for threads in [1,2,4] {
+            let mut ana_opts = HibouAnalyzeOptions::default();
+            ana_opts.goal = None;
+            ana_opts.threads = threads;
+            let (_,node_count,verdict) = analyze_multi_trace(gen_ctx.clone(),
+                                                             CoLocalizations::get_discrete_partition(4),
+                                                             multi_trace.clone(),
+                                                             int.clone(),
+                                                             ana_opts);
+            outcomes.push((node_count,verdict));
+        }
+        assert_eq!(outcomes[0].1, AnalysisGlobalVerdict::Pass);
+        assert!(outcomes.iter().all(|outcome| *outcome == outcomes[0]));
+    }
+
+    #[test]
+    fn same_node_count_as_sequential_whatever_the_strategy() {
+        let gen_ctx = context_with(&["l1","l2"],&["m1","m2"]);
+        // seq(loopP(alt(l1 -- m1 -> l2, l1 -- m2 -> l2)), l1 -- m1 -> l2)
+        let int = Interaction::Seq(Box::new(Interaction::Loop(LoopKind::PInterleaving,
+                                                              Box::new(Interaction::Alt(Box::new(message(0,0,1)),
+                                                                                        Box::new(message(0,1,1)))))),
+                                   Box::new(message(0,0,1)));
+        let multi_trace : MultiTrace = vec![actions(0,TraceActionKind::Emission,vec![0,1,0]),
+                                            actions(1,TraceActionKind::Reception,vec![0,1,0])];
+        let strategies = || vec![QueueSearchStrategy::BFS,QueueSearchStrategy::DFS,QueueSearchStrategy::HCS];
+        for strategy_id in 0..3 {
+            for max_node_number in [None,Some(5)] {
+                for goal in [None,Some(AnalysisGlobalVerdict::WeakPass)] {
+                    let mut outcomes = vec![];
+                    for threads in [1,2,4] {
+                        let mut ana_opts = HibouAnalyzeOptions::default();
+                        ana_opts.strategy = strategies().remove(strategy_id);
+                        ana_opts.filters = max_node_number.iter().map(|max| AnalysisFilter::MaxNodeNumber(*max)).collect();
+                        ana_opts.goal = goal.clone();
+                        ana_opts.threads = threads;
+                        let (_,node_count,verdict) = analyze_multi_trace(gen_ctx.clone(),
+                                                                         CoLocalizations::get_discrete_partition(2),
+                                                                         multi_trace.clone(),
+                                                                         int.clone(),
+                                                                         ana_opts);
+                        outcomes.push((node_count,verdict));
+                    }
+                    assert!(outcomes.iter().all(|outcome| *outcome == outcomes[0]),
+                            "{} with max node number {:?} and goal {:?} : {:?}", strategies().remove(strategy_id), max_node_number, goal, outcomes);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "non-conform interaction")]
+    fn panics_of_worker_threads_are_propagated() {
+        // strict(l1 -- m1 -> l2, and(l1 -- m1 -> l2, l1 -- m1 -> l2))
+        // the frontier of "and" is not defined, which panics once the first message has been executed by a worker
+        let int = Interaction::Strict(Box::new(message(0,0,1)),
+                                      Box::new(Interaction::And(Box::new(message(0,0,1)),
+                                                                Box::new(message(0,0,1)))));
+        let mut ana_opts = HibouAnalyzeOptions::default();
+        ana_opts.threads = 2;
+        analyze_multi_trace(context_with(&["l1","l2"],&["m1"]),
+                            CoLocalizations::get_discrete_partition(2),
+                            vec![actions(0,TraceActionKind::Emission,vec![0,0]),
+                                 actions(1,TraceActionKind::Reception,vec![0,0])],
+                            int,
+                            ana_opts);
+    }
+
+    #[test]
+    fn matching_a_negative_fragment_is_forbidden() {
+        // seq(a -- m1 ->|, neg(a -- m2 ->|))
+        let int = Interaction::Seq(Box::new(emission(0,0)),
+                                   Box::new(Interaction::Neg(Box::new(emission(0,1)))));
+        let verdict = analyze_on_single_lifeline(int.clone(),vec![actions(0,TraceActionKind::Emission,vec![0,1])]);
+        assert_eq!(verdict, AnalysisGlobalVerdict::ForbiddenMatched);
+        // the negative fragment is optional
+        let verdict = analyze_on_single_lifeline(int,vec![vec![action(0,TraceActionKind::Emission,0)]]);
+        assert_eq!(verdict, AnalysisGlobalVerdict::Pass);
+    }
+
+    #[test]
+    fn deviating_from_an_assertion_is_forbidden() {
+        // assert(a -- m1 ->|)
+        let int = Interaction::Assert(Box::new(emission(0,0)));
+        let verdict = analyze_on_single_lifeline(int,vec![vec![action(0,TraceActionKind::Emission,1)]]);
+        assert_eq!(verdict, AnalysisGlobalVerdict::ForbiddenMatched);
+    }
+
+    #[test]
+    fn assertions_of_untaken_branches_do_not_forbid() {
+        // alt(assert(a -- m1 ->|), a -- m2 ->|) against "a!m3"
+        let int = Interaction::Alt(Box::new(Interaction::Assert(Box::new(emission(0,0)))),
+                                   Box::new(emission(0,1)));
+        let verdict = analyze_on_single_lifeline(int,vec![vec![action(0,TraceActionKind::Emission,2)]]);
+        assert_eq!(verdict, AnalysisGlobalVerdict::Fail);
+    }
+}
@@ -0,0 +1,154 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use crate::core::execution::semantics::frontier::{FrontierElement, global_frontier};
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::language::syntax::interaction::Interaction;
+use crate::process::ana::node::node::AnalysisNodeKind;
+
+
+pub struct CanalFailureDiagnosis {
+    pub canal_id : usize,
+    // number of actions of this canal consumed by the deepest node
+    pub consumed : usize,
+    pub length : usize,
+    // the first multi-action which was never consumed, if any
+    pub head : Option<BTreeSet<TraceAction>>,
+    // whether or not the head could have been executed from the deepest node
+    pub head_expected : bool,
+    // frontier elements which would have matched the head if not for the values of their arguments
+    pub value_mismatches : Vec<FrontierElement>
+}
+
+pub struct AnalysisFailureDiagnosis {
+    pub canals : Vec<CanalFailureDiagnosis>,
+    // interaction reached at the deepest node, i.e. after having consumed the most actions
+    pub interaction : Interaction,
+    // whether or not the exploration from the deepest node was cut short by a local analysis
+    pub pruned_by_local_analysis : bool,
+    // what this interaction would have accepted instead
+    pub expected : Vec<FrontierElement>
+}
+
+impl AnalysisFailureDiagnosis {
+
+    // when unobserved messages are hidden, the analyzed multi-trace is not that of the ".htf" file
+    // positions of heads and lengths are then translated into positions in the original multi-trace
+    pub fn relocate_in_original_multi_trace(&mut self,
+                                            original : &MultiTrace,
+                                            unobserved_messages : &BTreeSet<usize>) {
+        if unobserved_messages.is_empty() {
+            return;
+        }
+        for canal in self.canals.iter_mut() {
+            let trace = original.get(canal.canal_id).unwrap();
+            // original indices of the multi-actions which are not entirely hidden
+            let observed : Vec<usize> = trace.iter().enumerate()
+                .filter(|(_,multi_action)| multi_action.iter().any(|action| !unobserved_messages.contains(&action.ms_id)))
+                .map(|(idx,_)| idx)
+                .collect();
+            canal.length = trace.len();
+            match observed.get(canal.consumed) {
+                None => {
+                    canal.consumed = trace.len();
+                },
+                Some( original_idx ) => {
+                    // hidden actions which precede the head are counted as consumed
+                    canal.consumed = *original_idx;
+                }
+            }
+        }
+    }
+
+}
+
+fn action_signature(action : &TraceAction) -> (usize,TraceActionKind,usize) {
+    return (action.lf_id,action.act_kind,action.ms_id);
+}
+
+// whether or not the actions are the same if we ignore arguments
+fn matches_without_arguments(patterns : &BTreeSet<TraceAction>, concrete : &BTreeSet<TraceAction>) -> bool {
+    let mut got_patterns : Vec<_> = patterns.iter().map(action_signature).collect();
+    let mut got_concrete : Vec<_> = concrete.iter().map(action_signature).collect();
+    got_patterns.sort();
+    got_concrete.sort();
+    return got_patterns == got_concrete;
+}
+
+// heads of canals and expected actions are all taken from the same node
+// so that the diagnosis describes a single branch of the analysis
+pub fn diagnose_analysis_failure(multi_trace : &MultiTrace,
+                                 deepest_node : &AnalysisNodeKind,
+                                 pruned_by_local_analysis : bool) -> AnalysisFailureDiagnosis {
+    let expected = global_frontier(&deepest_node.interaction,true);
+    let mut canals = vec![];
+    for (canal_id,canal_flags) in deepest_node.flags.canals.iter().enumerate() {
+        let consumed = &canal_flags.consumed;
+        let trace = multi_trace.get(canal_id).unwrap();
+        let head = trace.get(*consumed).cloned();
+        let head_expected = match &head {
+            None => {
+                false
+            },
+            Some( got_head ) => {
+                expected.iter().any(|frt_elt| TraceAction::multi_action_matches(&frt_elt.target_actions,got_head))
+            }
+        };
+        let value_mismatches = match &head {
+            None => {
+                vec![]
+            },
+            Some( got_head ) => {
+                expected.iter()
+                    .filter(|frt_elt| matches_without_arguments(&frt_elt.target_actions,got_head)
+                        && !TraceAction::multi_action_matches(&frt_elt.target_actions,got_head))
+                    .cloned()
+                    .collect()
+            }
+        };
+        canals.push( CanalFailureDiagnosis{canal_id,consumed:*consumed,length:trace.len(),head,head_expected,value_mismatches} );
+    }
+    return AnalysisFailureDiagnosis{canals,interaction:deepest_node.interaction.clone(),pruned_by_local_analysis,expected};
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::util::test_fixtures::action;
+    use super::*;
+
+    #[test]
+    fn heads_are_relocated_after_the_hidden_actions_which_precede_them() {
+        // "a!m2" (message 1) is unobserved
+        let original : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,1),
+                                              action(0,TraceActionKind::Emission,0),
+                                              action(0,TraceActionKind::Emission,1),
+                                              action(0,TraceActionKind::Emission,0)]];
+        let canal = CanalFailureDiagnosis{canal_id:0,consumed:1,length:2,head:Some(action(0,TraceActionKind::Emission,0)),
+                                          head_expected:false,value_mismatches:vec![]};
+        let mut diagnosis = AnalysisFailureDiagnosis{canals:vec![canal],interaction:Interaction::Empty,
+                                                     pruned_by_local_analysis:false,expected:vec![]};
+        diagnosis.relocate_in_original_multi_trace(&original,&btreeset!{1});
+        assert_eq!(diagnosis.canals[0].consumed, 3);
+        assert_eq!(diagnosis.canals[0].length, 4);
+    }
+}
@@ -0,0 +1,190 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::any::Any;
+use std::collections::HashSet;
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::process::ana::conf::{AnalysisConfig, AnalysisStaticLocalVerdictAnalysisProof};
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::filter::elim::AnalysisFilterEliminationKind;
+use crate::process::ana::filter::filter::AnalysisFilterCriterion;
+use crate::process::ana::loggers::diag::diagnosis::{AnalysisFailureDiagnosis, diagnose_analysis_failure};
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::param::param::AnalysisParameterization;
+use crate::process::ana::priorities::AnalysisPriorities;
+use crate::process::ana::step::AnalysisStepKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::ana::verdict::local::AnalysisLocalVerdict;
+
+
+// keeps track of how far the analysis went so as to explain a failure afterwards
+#[derive(Default)]
+pub struct AnalysisDiagnosisLogger {
+    // total number of actions consumed by the deepest nodes
+    pub deepest_consumed : usize,
+    // distinct nodes which consumed the most actions overall, with their identifiers
+    pub deepest_nodes : Vec<(u32,AnalysisNodeKind)>,
+    pub pruned_nodes : HashSet<u32>
+}
+
+impl AnalysisDiagnosisLogger {
+
+    pub fn new() -> AnalysisDiagnosisLogger {
+        return AnalysisDiagnosisLogger{deepest_consumed:0,deepest_nodes:vec![],pruned_nodes:HashSet::new()};
+    }
+
+    // one diagnosis per deepest node, in the order in which they were reached
+    pub fn get_diagnoses(&self, multi_trace : &MultiTrace) -> Vec<AnalysisFailureDiagnosis> {
+        return self.deepest_nodes.iter()
+            .map(|(node_id,node)| diagnose_analysis_failure(multi_trace,node,self.pruned_nodes.contains(node_id)))
+            .collect();
+    }
+
+}
+
+impl AbstractProcessLogger<AnalysisConfig> for AnalysisDiagnosisLogger {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.deepest_consumed = 0;
+        self.deepest_nodes = vec![];
+        self.pruned_nodes = HashSet::new();
+    }
+
+    fn log_parameterization(&mut self,
+                            _strategy: &QueueSearchStrategy,
+                            _priorities: &GenericProcessPriorities<AnalysisPriorities>,
+                            _filters: &[Box<dyn AbstractFilter<AnalysisFilterCriterion, AnalysisFilterEliminationKind>>],
+                            _goal: &Option<AnalysisGlobalVerdict>,
+                            _use_memoization: bool,
+                            _parameterization: &AnalysisParameterization) {}
+
+    fn log_filtered(&mut self,
+                    _context: &AnalysisContext,
+                    _parent_node_id: u32,
+                    _new_node_id: u32,
+                    _elim_kind: &AnalysisFilterEliminationKind) {}
+
+    fn log_new_node(&mut self,
+                    _context: &AnalysisContext,
+                    _param: &AnalysisParameterization,
+                    new_node_id: u32,
+                    new_node: &AnalysisNodeKind) {
+        let total_consumed = new_node.flags.canals.iter().fold(0,|sum,canal_flags| sum + canal_flags.consumed);
+        if self.deepest_nodes.is_empty() || total_consumed > self.deepest_consumed {
+            self.deepest_consumed = total_consumed;
+            self.deepest_nodes = vec![(new_node_id,new_node.clone())];
+        } else if total_consumed == self.deepest_consumed {
+            // nodes which only differ by e.g. their loop depth would yield the same diagnosis
+            let is_new = !self.deepest_nodes.iter().any(|(_,node)| node.interaction == new_node.interaction
+                && node.flags.canals.iter().zip(new_node.flags.canals.iter()).all(|(f1,f2)| f1.consumed == f2.consumed));
+            if is_new {
+                self.deepest_nodes.push( (new_node_id,new_node.clone()) );
+            }
+        }
+    }
+
+    fn log_new_step(&mut self,
+                    _context: &AnalysisContext,
+                    _param: &AnalysisParameterization,
+                    _origin_node_id: u32,
+                    _target_node_id: u32,
+                    _step: &AnalysisStepKind,
+                    _target_node: &AnalysisNodeKind,
+                    _target_depth: u32) {}
+
+    fn log_verdict_on_no_child(&mut self,
+                               _context: &AnalysisContext,
+                               _param: &AnalysisParameterization,
+                               _parent_node_id: u32,
+                               _verdict: &AnalysisLocalVerdict) {}
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      _context: &AnalysisContext,
+                                      _param: &AnalysisParameterization,
+                                      parent_node_id: u32,
+                                      _verdict: &AnalysisLocalVerdict,
+                                      _proof: &AnalysisStaticLocalVerdictAnalysisProof) {
+        self.pruned_nodes.insert(parent_node_id);
+    }
+
+    fn log_terminate(&mut self,
+                     _global_verdict: &AnalysisGlobalVerdict) {}
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        _context: &AnalysisContext,
+                                        _node_id: u32) {}
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               _context: &AnalysisContext,
+                                               _parent_node_id: u32) {}
+
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::colocalizations::CoLocalizations;
+    use crate::core::execution::trace::trace::TraceActionKind;
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+    use crate::process::ana::interface::analyze_multi_trace;
+    use crate::util::test_fixtures::{action, context_with, emission};
+    use super::*;
+
+    #[test]
+    fn each_deepest_node_is_diagnosed_on_its_own() {
+        // lifelines "a" (0) and "b" (1) on distinct canals
+        // alt(seq(a -- m1 ->|, b -- m2 ->|), seq(b -- m3 ->|, a -- m4 ->|))
+        let int = Interaction::Alt(Box::new(Interaction::Seq(Box::new(emission(0,0)),Box::new(emission(1,1)))),
+                                   Box::new(Interaction::Seq(Box::new(emission(1,2)),Box::new(emission(0,3)))));
+        let multi_trace : MultiTrace = vec![vec![action(0,TraceActionKind::Emission,0),action(0,TraceActionKind::Emission,4)],
+                                            vec![action(1,TraceActionKind::Emission,2),action(1,TraceActionKind::Emission,4)]];
+        let mut ana_opts = HibouAnalyzeOptions::default();
+        ana_opts.goal = None;
+        ana_opts.loggers = vec![Box::new(AnalysisDiagnosisLogger::new())];
+        let (manager,_,verdict) = analyze_multi_trace(context_with(&["a","b"],&["m1","m2","m3","m4","m5"]),
+                                                      CoLocalizations::get_discrete_partition(2),
+                                                      multi_trace.clone(),
+                                                      int,
+                                                      ana_opts);
+        assert_eq!(verdict, AnalysisGlobalVerdict::Fail);
+        let diag_logger = manager.get_logger(0).unwrap().as_any().downcast_ref::<AnalysisDiagnosisLogger>().unwrap();
+        let diagnoses = diag_logger.get_diagnoses(&multi_trace);
+        assert_eq!(diagnoses.len(), 2);
+        for diagnosis in &diagnoses {
+            // the action consumed in one branch is the unexpected head of the other canal
+            let consumed : Vec<usize> = diagnosis.canals.iter().map(|canal| canal.consumed).collect();
+            assert!(consumed == vec![1,0] || consumed == vec![0,1]);
+            let unconsumed = diagnosis.canals.iter().find(|canal| canal.consumed == 0).unwrap();
+            assert!(unconsumed.head.is_some() && !unconsumed.head_expected);
+            assert_eq!(diagnosis.expected.len(), 1);
+        }
+        assert!(diagnoses[0].canals[0].consumed != diagnoses[1].canals[0].consumed);
+    }
+}
@@ -68,6 +68,9 @@ impl GraphVizProcessDrawer<AnalysisConfig> for InteractionProcessDrawer {
             },
             AnalysisLocalVerdict::OutSim(_) => {
                 GraphvizColor::crimson
+            },
+            AnalysisLocalVerdict::ForbiddenMatched => {
+                GraphvizColor::darkorange3
             }
         }
     }
@@ -15,5 +15,7 @@ limitations under the License.
 */
 
 
+pub mod diag;
 pub mod glog;
-pub mod slog;
\ No newline at end of file
+pub mod slog;
+pub mod witness;
\ No newline at end of file
@@ -0,0 +1,207 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::any::Any;
+use std::collections::{BTreeSet, HashMap};
+
+use graph_process_manager_core::delegate::priorities::GenericProcessPriorities;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::process::ana::conf::{AnalysisConfig, AnalysisStaticLocalVerdictAnalysisProof};
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::filter::elim::AnalysisFilterEliminationKind;
+use crate::process::ana::filter::filter::AnalysisFilterCriterion;
+use crate::process::ana::loggers::witness::witness::{AnalysisWitness, AnalysisWitnessStep, WitnessStepKind};
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::param::param::AnalysisParameterization;
+use crate::process::ana::priorities::AnalysisPriorities;
+use crate::process::ana::step::AnalysisStepKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::ana::verdict::local::AnalysisLocalVerdict;
+
+
+// keeps track of how each node was first reached so as to rebuild the path towards the first covering node
+#[derive(Default)]
+pub struct AnalysisWitnessLogger {
+    pub initial_node_id : Option<u32>,
+    // for each node, the node and step from which it was first reached and its interaction
+    pub nodes : HashMap<u32,(Option<(u32,WitnessStepKind)>,Interaction)>,
+    pub covering_node_id : Option<u32>
+}
+
+impl AnalysisWitnessLogger {
+
+    pub fn new() -> AnalysisWitnessLogger {
+        return AnalysisWitnessLogger{initial_node_id:None,nodes:HashMap::new(),covering_node_id:None};
+    }
+
+    pub fn get_witness(&self) -> Option<AnalysisWitness> {
+        let mut current_id = self.covering_node_id?;
+        let mut steps = vec![];
+        loop {
+            let (parent,interaction) = self.nodes.get(&current_id).unwrap();
+            match parent {
+                None => {
+                    steps.reverse();
+                    return Some( AnalysisWitness{initial_interaction:interaction.clone(),steps} );
+                },
+                Some( (parent_id,step_kind) ) => {
+                    let kind = match step_kind {
+                        WitnessStepKind::EliminateNoLongerObserved(coloc_ids) => {
+                            WitnessStepKind::EliminateNoLongerObserved(coloc_ids.clone())
+                        },
+                        WitnessStepKind::Execute(position,actions,simulated) => {
+                            WitnessStepKind::Execute(position.clone(),actions.clone(),*simulated)
+                        }
+                    };
+                    steps.push( AnalysisWitnessStep{kind,interaction:interaction.clone()} );
+                    current_id = *parent_id;
+                }
+            }
+        }
+    }
+
+    fn notify_local_verdict(&mut self, node_id : u32, verdict : &AnalysisLocalVerdict) {
+        if let AnalysisLocalVerdict::Cov = verdict {
+            if self.covering_node_id.is_none() {
+                self.covering_node_id = Some(node_id);
+            }
+        }
+    }
+
+}
+
+// actions on canals which are not consumed come from simulation
+fn get_executed_actions(context : &AnalysisContext,
+                        step : &AnalysisStepKind,
+                        target_node : &AnalysisNodeKind) -> WitnessStepKind {
+    match step {
+        AnalysisStepKind::EliminateNoLongerObserved(coloc_ids) => {
+            return WitnessStepKind::EliminateNoLongerObserved(coloc_ids.iter().cloned().collect());
+        },
+        AnalysisStepKind::Execute(frt_elt,consu_set,_) => {
+            let mut actions : BTreeSet<TraceAction> = BTreeSet::new();
+            for coloc_id in consu_set {
+                let consumed = target_node.flags.canals.get(*coloc_id).unwrap().consumed;
+                let trace = context.multi_trace.get(*coloc_id).unwrap();
+                actions.extend( trace.get(consumed - 1).unwrap().iter().cloned() );
+            }
+            let mut simulated = false;
+            for action in &frt_elt.target_actions {
+                match context.co_localizations.get_lf_coloc_id(action.lf_id) {
+                    Some( coloc_id ) if consu_set.contains(&coloc_id) => {},
+                    _ => {
+                        simulated = true;
+                        actions.insert(action.clone());
+                    }
+                }
+            }
+            return WitnessStepKind::Execute(frt_elt.position.clone(),actions,simulated);
+        }
+    }
+}
+
+impl AbstractProcessLogger<AnalysisConfig> for AnalysisWitnessLogger {
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn log_initialize(&mut self) {
+        self.initial_node_id = None;
+        self.nodes = HashMap::new();
+        self.covering_node_id = None;
+    }
+
+    fn log_parameterization(&mut self,
+                            _strategy: &QueueSearchStrategy,
+                            _priorities: &GenericProcessPriorities<AnalysisPriorities>,
+                            _filters: &[Box<dyn AbstractFilter<AnalysisFilterCriterion, AnalysisFilterEliminationKind>>],
+                            _goal: &Option<AnalysisGlobalVerdict>,
+                            _use_memoization: bool,
+                            _parameterization: &AnalysisParameterization) {}
+
+    fn log_filtered(&mut self,
+                    _context: &AnalysisContext,
+                    _parent_node_id: u32,
+                    _new_node_id: u32,
+                    _elim_kind: &AnalysisFilterEliminationKind) {}
+
+    fn log_new_node(&mut self,
+                    _context: &AnalysisContext,
+                    _param: &AnalysisParameterization,
+                    new_node_id: u32,
+                    new_node: &AnalysisNodeKind) {
+        if self.initial_node_id.is_none() {
+            self.initial_node_id = Some(new_node_id);
+        }
+        self.nodes.entry(new_node_id).or_insert((None,new_node.interaction.clone()));
+    }
+
+    fn log_new_step(&mut self,
+                    context: &AnalysisContext,
+                    _param: &AnalysisParameterization,
+                    origin_node_id: u32,
+                    target_node_id: u32,
+                    step: &AnalysisStepKind,
+                    target_node: &AnalysisNodeKind,
+                    _target_depth: u32) {
+        // only the first step towards a node is kept, it comes from an older node so that no cycle can occur
+        if Some(target_node_id) == self.initial_node_id {
+            return;
+        }
+        if let Some((parent,_)) = self.nodes.get_mut(&target_node_id) {
+            if parent.is_none() {
+                *parent = Some( (origin_node_id,get_executed_actions(context,step,target_node)) );
+            }
+        }
+    }
+
+    fn log_verdict_on_no_child(&mut self,
+                               _context: &AnalysisContext,
+                               _param: &AnalysisParameterization,
+                               parent_node_id: u32,
+                               verdict: &AnalysisLocalVerdict) {
+        self.notify_local_verdict(parent_node_id,verdict);
+    }
+
+    fn log_verdict_on_static_analysis(&mut self,
+                                      _context: &AnalysisContext,
+                                      _param: &AnalysisParameterization,
+                                      parent_node_id: u32,
+                                      verdict: &AnalysisLocalVerdict,
+                                      _proof: &AnalysisStaticLocalVerdictAnalysisProof) {
+        self.notify_local_verdict(parent_node_id,verdict);
+    }
+
+    fn log_terminate(&mut self,
+                     _global_verdict: &AnalysisGlobalVerdict) {}
+
+    fn log_notify_terminal_node_reached(&mut self,
+                                        _context: &AnalysisContext,
+                                        _node_id: u32) {}
+
+    fn log_notify_last_child_of_node_processed(&mut self,
+                                               _context: &AnalysisContext,
+                                               _parent_node_id: u32) {}
+
+}
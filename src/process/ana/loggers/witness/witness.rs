@@ -0,0 +1,190 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::language::position::position::Position;
+use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction, EmissionTargetRef, ReceptionAction};
+use crate::core::language::syntax::interaction::Interaction;
+
+
+pub enum WitnessStepKind {
+    // ids of the co-localizations which are no longer observed
+    EliminateNoLongerObserved(BTreeSet<usize>),
+    // position of the executed frontier element, executed actions with concrete values
+    // and whether or not some of these actions were simulated instead of being read from the multi-trace
+    Execute(Position,BTreeSet<TraceAction>,bool)
+}
+
+pub struct AnalysisWitnessStep {
+    pub kind : WitnessStepKind,
+    // interaction reached after the step
+    pub interaction : Interaction
+}
+
+// path from the initial node to the first node which covers the multi-trace
+pub struct AnalysisWitness {
+    pub initial_interaction : Interaction,
+    pub steps : Vec<AnalysisWitnessStep>
+}
+
+fn single_action_as_interaction(action : &TraceAction) -> Interaction {
+    match action.act_kind {
+        TraceActionKind::Emission => {
+            return Interaction::Emission(EmissionAction::new(action.lf_id,
+                                                             action.ms_id,
+                                                             CommunicationSynchronicity::Asynchronous,
+                                                             vec![],
+                                                             action.args.clone()));
+        },
+        TraceActionKind::Reception => {
+            return Interaction::Reception(ReceptionAction::new(None,
+                                                               action.ms_id,
+                                                               CommunicationSynchronicity::Asynchronous,
+                                                               vec![action.lf_id],
+                                                               action.args.clone()));
+        }
+    }
+}
+
+// an emission and its receptions occurring at once form a synchronous message
+fn multi_action_as_interaction(actions : &BTreeSet<TraceAction>) -> Interaction {
+    let emissions : Vec<&TraceAction> = actions.iter().filter(|a| a.act_kind == TraceActionKind::Emission).collect();
+    if let [emission] = emissions.as_slice() {
+        let receptions : Vec<&TraceAction> = actions.iter().filter(|a| a.act_kind == TraceActionKind::Reception).collect();
+        if !receptions.is_empty() && receptions.iter().all(|r| r.ms_id == emission.ms_id && r.args == emission.args) {
+            let targets = receptions.iter().map(|r| EmissionTargetRef::Lifeline(r.lf_id)).collect();
+            return Interaction::Emission(EmissionAction::new(emission.lf_id,
+                                                             emission.ms_id,
+                                                             CommunicationSynchronicity::Synchronous,
+                                                             targets,
+                                                             emission.args.clone()));
+        }
+    }
+    let mut sub_ints : Vec<Interaction> = actions.iter().map(single_action_as_interaction).collect();
+    let mut got = sub_ints.pop().unwrap();
+    while let Some(sub_int) = sub_ints.pop() {
+        got = Interaction::Par(Box::new(sub_int),Box::new(got));
+    }
+    return got;
+}
+
+// an emission immediately followed by a matching reception on a single lifeline forms an asynchronous message
+fn as_asynchronous_message(first : &BTreeSet<TraceAction>, second : &BTreeSet<TraceAction>) -> Option<Interaction> {
+    match (first.iter().next(),second.iter().next()) {
+        (Some(emission),Some(reception)) if first.len() == 1 && second.len() == 1 => {
+            if emission.act_kind == TraceActionKind::Emission
+                && reception.act_kind == TraceActionKind::Reception
+                && emission.ms_id == reception.ms_id
+                && emission.args == reception.args {
+                return Some( Interaction::Emission(EmissionAction::new(emission.lf_id,
+                                                                       emission.ms_id,
+                                                                       CommunicationSynchronicity::Asynchronous,
+                                                                       vec![EmissionTargetRef::Lifeline(reception.lf_id)],
+                                                                       emission.args.clone())) );
+            }
+            return None;
+        },
+        _ => {
+            return None;
+        }
+    }
+}
+
+impl AnalysisWitness {
+
+    pub fn get_executed_actions(&self) -> Vec<&BTreeSet<TraceAction>> {
+        let mut executed = vec![];
+        for step in &self.steps {
+            if let WitnessStepKind::Execute(_,actions,_) = &step.kind {
+                executed.push(actions);
+            }
+        }
+        return executed;
+    }
+
+    // the scenario has no alternative and no loop, its actions are sequenced in the order in which they were executed
+    pub fn get_scenario(&self) -> Interaction {
+        let executed = self.get_executed_actions();
+        let mut sub_ints : Vec<Interaction> = vec![];
+        let mut idx = 0;
+        while idx < executed.len() {
+            let as_message = match executed.get(idx+1) {
+                None => {
+                    None
+                },
+                Some( next ) => {
+                    as_asynchronous_message(executed[idx],next)
+                }
+            };
+            match as_message {
+                Some( message ) => {
+                    sub_ints.push(message);
+                    idx += 2;
+                },
+                None => {
+                    sub_ints.push(multi_action_as_interaction(executed[idx]));
+                    idx += 1;
+                }
+            }
+        }
+        let mut got = Interaction::Empty;
+        while let Some(sub_int) = sub_ints.pop() {
+            got = match got {
+                Interaction::Empty => {
+                    sub_int
+                },
+                _ => {
+                    Interaction::Seq(Box::new(sub_int),Box::new(got))
+                }
+            };
+        }
+        return got;
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn execute(actions : BTreeSet<TraceAction>) -> AnalysisWitnessStep {
+        return AnalysisWitnessStep{kind:WitnessStepKind::Execute(Position::Epsilon(None),actions,false),
+                                   interaction:Interaction::Empty};
+    }
+
+    #[test]
+    fn emission_immediately_received_becomes_a_message() {
+        let emission = TraceAction::new(0,TraceActionKind::Emission,0,vec![]);
+        let reception = TraceAction::new(1,TraceActionKind::Reception,0,vec![]);
+        let other = TraceAction::new(1,TraceActionKind::Emission,1,vec![]);
+        let witness = AnalysisWitness{initial_interaction:Interaction::Empty,
+                                      steps:vec![execute(btreeset!{emission.clone()}),
+                                                 execute(btreeset!{reception}),
+                                                 execute(btreeset!{other.clone()}),
+                                                 execute(btreeset!{emission.clone()})]};
+        let message = Interaction::Emission(EmissionAction::new(0,0,CommunicationSynchronicity::Asynchronous,
+                                                                vec![EmissionTargetRef::Lifeline(1)],vec![]));
+        let expected = Interaction::Seq(Box::new(message),
+                                        Box::new(Interaction::Seq(Box::new(single_action_as_interaction(&other)),
+                                                                  Box::new(single_action_as_interaction(&emission)))));
+        assert_eq!(witness.get_scenario(), expected);
+    }
+}
@@ -25,3 +25,6 @@ pub mod node;
 pub mod priorities;
 pub mod step;
 pub mod param;
+pub mod interface;
+pub mod online;
+pub mod align;
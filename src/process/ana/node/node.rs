@@ -27,21 +27,23 @@ use crate::process::ana::node::flags::{MultiTraceAnalysisFlags, TraceAnalysisFla
 pub struct AnalysisNodeKind {
     pub interaction : Interaction,
     pub flags : MultiTraceAnalysisFlags,
-    pub ana_loop_depth : u32
+    pub ana_loop_depth : u32,
+    pub forbidden : bool // whether or not an action from a negative fragment has been executed
 }
 
 impl AnalysisNodeKind {
     pub fn new(interaction : Interaction,
                flags : MultiTraceAnalysisFlags,
-               ana_loop_depth : u32) -> AnalysisNodeKind {
-        return AnalysisNodeKind{interaction,flags,ana_loop_depth}
+               ana_loop_depth : u32,
+               forbidden : bool) -> AnalysisNodeKind {
+        return AnalysisNodeKind{interaction,flags,ana_loop_depth,forbidden}
     }
 }
 
 
 impl AbstractNodeKind for AnalysisNodeKind {
     fn is_included_for_memoization(&self, memoized_node: &Self) -> bool {
-        if self.interaction == memoized_node.interaction {
+        if self.interaction == memoized_node.interaction && self.forbidden == memoized_node.forbidden {
             if self.flags.rem_loop_in_sim > memoized_node.flags.rem_loop_in_sim || self.flags.rem_act_in_sim > memoized_node.flags.rem_act_in_sim {
                 return false;
             }
@@ -0,0 +1,210 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::{BTreeSet, HashSet};
+use graph_process_manager_core::delegate::node::GenericNode;
+use graph_process_manager_core::handler::handler::AbstractProcessHandler;
+use graph_process_manager_core::queued_steps::step::GenericStep;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::from_model::from_model::InteractionInterpretableAsTraceAction;
+use crate::core::execution::trace::trace::TraceAction;
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::interaction::Interaction;
+use crate::process::ana::context::AnalysisContext;
+use crate::process::ana::handling::handler::AnalysisProcessHandler;
+use crate::process::ana::node::flags::MultiTraceAnalysisFlags;
+use crate::process::ana::node::node::AnalysisNodeKind;
+use crate::process::ana::online::verdict::OnlineVerdict;
+use crate::process::ana::param::anakind::AnalysisKind;
+use crate::process::ana::param::param::AnalysisParameterization;
+use crate::process::ana::step::AnalysisStepKind;
+
+
+/**
+ * Incremental multi-trace analysis.
+ * Actions are received one at a time on their canal
+ * and the set of reachable analysis nodes is updated accordingly
+ * instead of requiring the whole multi-trace up front.
+ **/
+pub struct OnlineMonitor {
+    context : AnalysisContext,
+    param : AnalysisParameterization,
+    reached : HashSet<AnalysisNodeKind>,
+    verdict : OnlineVerdict
+}
+
+impl OnlineMonitor {
+
+    pub fn new(gen_ctx : GeneralContext,
+               co_localizations : CoLocalizations,
+               interaction : Interaction) -> OnlineMonitor {
+        let canals_num = co_localizations.locs_lf_ids.len();
+        let multi_trace = (0..canals_num).map(|_| vec![]).collect();
+        let context = AnalysisContext::new(gen_ctx,co_localizations,multi_trace,0);
+        // the multi-trace received so far is a prefix of the observed behavior
+        // hence executions are only ever tried on actions which have already arrived
+        let param = AnalysisParameterization::new(AnalysisKind::Accept,None,false);
+        let init_flags = MultiTraceAnalysisFlags::new_init(canals_num,0,0);
+        let init_node = AnalysisNodeKind::new(interaction,init_flags,0,false);
+        let mut monitor = OnlineMonitor{context,param,reached:hashset!{init_node},verdict:OnlineVerdict::Pending};
+        monitor.verdict = monitor.compute_verdict();
+        return monitor;
+    }
+
+    pub fn get_verdict(&self) -> OnlineVerdict {
+        return self.verdict;
+    }
+
+    pub fn get_live_nodes_number(&self) -> usize {
+        return self.reached.len();
+    }
+
+    pub fn get_co_localizations(&self) -> &CoLocalizations {
+        return &self.context.co_localizations;
+    }
+
+    pub fn receive(&mut self,
+                   canal_id : usize,
+                   multi_action : BTreeSet<TraceAction>) -> OnlineVerdict {
+        if self.verdict.is_definitive() {
+            return self.verdict;
+        }
+        let previous_length = self.context.multi_trace.get(canal_id).unwrap().len();
+        self.context.multi_trace.get_mut(canal_id).unwrap().push(multi_action);
+        self.context.init_multitrace_length += 1;
+        // ***
+        // only nodes which had consumed the whole canal can now go further
+        // and, for those, only via executions which consume the new action
+        let mut to_process : Vec<(AnalysisNodeKind,bool)> = self.reached.iter()
+            .filter(|node| node.flags.canals.get(canal_id).unwrap().consumed == previous_length)
+            .map(|node| (node.clone(),true))
+            .collect();
+        while let Some( (node_kind,is_old) ) = to_process.pop() {
+            let parent = GenericNode::new(node_kind,hashset!{},0);
+            let steps = AnalysisProcessHandler::collect_next_steps(&self.context,&self.param,&parent.kind);
+            for (idx,step_kind) in steps.into_iter().enumerate() {
+                if is_old {
+                    match &step_kind {
+                        AnalysisStepKind::Execute( _, consu_set, _ ) => {
+                            if !consu_set.contains(&canal_id) {
+                                continue;
+                            }
+                        },
+                        _ => {
+                            continue;
+                        }
+                    }
+                }
+                let step = GenericStep::new(0,(idx as u32) + 1,step_kind);
+                let mut new_node = AnalysisProcessHandler::process_new_step(&self.context,&self.param,&parent,&step,0,0);
+                // there are no loop depth filters when monitoring
+                new_node.ana_loop_depth = 0;
+                if self.is_alive(&new_node) && self.reached.insert(new_node.clone()) {
+                    to_process.push( (new_node,false) );
+                }
+            }
+        }
+        // ***
+        // all the steps which are possible from the remaining nodes have now been taken
+        // so a node which waits on every canal cannot be extended by the actions to come
+        let context = &self.context;
+        self.reached.retain(|node| OnlineMonitor::is_node_alive(context,node)
+            && OnlineMonitor::may_consume_actions_to_come(context,node));
+        self.verdict = self.compute_verdict();
+        return self.verdict;
+    }
+
+    fn is_alive(&self, node : &AnalysisNodeKind) -> bool {
+        return OnlineMonitor::is_node_alive(&self.context,node);
+    }
+
+    // a node is dead if it can never be part of an accepting path whatever the actions to come
+    // i.e. if an action from a negative fragment has been executed
+    // or if an action which has arrived but is not yet consumed cannot be matched by any action which remains in the interaction
+    fn is_node_alive(context : &AnalysisContext, node : &AnalysisNodeKind) -> bool {
+        if node.forbidden {
+            return false;
+        }
+        let remaining_actions = node.interaction.get_all_trace_actions();
+        for (canal_id,canal_flags) in node.flags.canals.iter().enumerate() {
+            let trace = context.multi_trace.get(canal_id).unwrap();
+            for concrete in trace[canal_flags.consumed..].iter().flatten() {
+                if !remaining_actions.iter().any(|pattern| pattern.matches(concrete)) {
+                    return false;
+                }
+            }
+        }
+        return true;
+    }
+
+    fn may_consume_actions_to_come(context : &AnalysisContext, node : &AnalysisNodeKind) -> bool {
+        return node.flags.canals.iter().enumerate()
+            .any(|(canal_id,canal_flags)| context.multi_trace.get(canal_id).unwrap().len() == canal_flags.consumed);
+    }
+
+    fn compute_verdict(&self) -> OnlineVerdict {
+        if self.reached.is_empty() {
+            return OnlineVerdict::Fail;
+        }
+        let mut verdict = OnlineVerdict::Pending;
+        for node in &self.reached {
+            if node.flags.is_multi_trace_empty(&self.context.multi_trace) {
+                if node.interaction.express_empty() {
+                    return OnlineVerdict::Pass;
+                } else {
+                    verdict = OnlineVerdict::WeakPass;
+                }
+            }
+        }
+        return verdict;
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::execution::trace::trace::TraceActionKind;
+    use crate::util::test_fixtures::{action, context_with, message};
+    use super::*;
+
+    fn new_monitor() -> OnlineMonitor {
+        let gen_ctx = context_with(&["l1","l2"],&["m1","m2"]);
+        // seq(l1 -- m1 -> l2, l2 -- m2 -> l1)
+        let int = Interaction::Seq(Box::new(message(0,0,1)),Box::new(message(1,1,0)));
+        return OnlineMonitor::new(gen_ctx,CoLocalizations::get_discrete_partition(2),int);
+    }
+
+    #[test]
+    fn verdict_is_updated_on_each_arrival() {
+        let mut monitor = new_monitor();
+        // actions may arrive on distinct canals in any order
+        assert_eq!(monitor.receive(1,action(1,TraceActionKind::Reception,0)),OnlineVerdict::Pending);
+        assert_eq!(monitor.receive(0,action(0,TraceActionKind::Emission,0)),OnlineVerdict::WeakPass);
+        assert_eq!(monitor.receive(0,action(0,TraceActionKind::Reception,1)),OnlineVerdict::Pending);
+        assert_eq!(monitor.receive(1,action(1,TraceActionKind::Emission,1)),OnlineVerdict::Pass);
+        // ***
+        let mut monitor = new_monitor();
+        assert_eq!(monitor.receive(0,action(0,TraceActionKind::Reception,1)),OnlineVerdict::Pending);
+        // l1 can no longer emit m1 before having received m2
+        assert_eq!(monitor.receive(0,action(0,TraceActionKind::Emission,0)),OnlineVerdict::Pending);
+        assert_eq!(monitor.receive(1,action(1,TraceActionKind::Reception,0)),OnlineVerdict::Fail);
+        assert_eq!(monitor.receive(1,action(1,TraceActionKind::Emission,1)),OnlineVerdict::Fail);
+    }
+
+}
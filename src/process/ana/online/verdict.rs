@@ -0,0 +1,63 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fmt;
+
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Copy, Hash)]
+pub enum OnlineVerdict {
+    Fail,
+    Pending,
+    WeakPass,
+    Pass
+}
+
+impl OnlineVerdict {
+
+    // once every possible continuation has been ruled out no further action can change the verdict
+    pub fn is_definitive(&self) -> bool {
+        return *self == OnlineVerdict::Fail;
+    }
+
+    // verdict to report once no more action can arrive
+    pub fn at_end_of_stream(&self) -> OnlineVerdict {
+        match self {
+            OnlineVerdict::Pending => OnlineVerdict::Fail,
+            _ => *self
+        }
+    }
+
+}
+
+impl fmt::Display for OnlineVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OnlineVerdict::Pass => {
+                write!(f,"Pass")
+            },
+            OnlineVerdict::WeakPass => {
+                write!(f,"WeakPass")
+            },
+            OnlineVerdict::Pending => {
+                write!(f,"Pending")
+            },
+            OnlineVerdict::Fail => {
+                write!(f,"Fail")
+            }
+        }
+    }
+}
@@ -151,7 +151,8 @@ pub enum AnalysisKind {
     Accept,
     Prefix,
     Eliminate,
-    Simulate(SimulationConfiguration)
+    Simulate(SimulationConfiguration),
+    Align(Option<u32>) // searches for a minimal-cost repair of the multi-trace, with an optional maximum cost
 }
 
 impl AnalysisKind {
@@ -238,6 +239,16 @@ impl fmt::Display for AnalysisKind {
             },
             AnalysisKind::Simulate(sim_config) => {
                 write!(f,"simulate[{:}]", sim_config)
+            },
+            AnalysisKind::Align(max_cost) => {
+                match max_cost {
+                    None => {
+                        write!(f,"align")
+                    },
+                    Some( got_max_cost ) => {
+                        write!(f,"align[max_cost={:}]", got_max_cost)
+                    }
+                }
             }
         }
     }
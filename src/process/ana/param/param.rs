@@ -43,6 +43,7 @@ impl fmt::Display for LocalAnalysisLifelineSelectionPolicy {
 }
 
 
+#[derive(Clone)]
 pub struct LocalAnalysisParameterization {
     pub on_lifeline_policy : LocalAnalysisLifelineSelectionPolicy,
     pub max_look_ahead_depth : Option<u32>,
@@ -71,6 +72,7 @@ impl LocalAnalysisParameterization {
 }
 
 
+#[derive(Clone)]
 pub struct AnalysisParameterization {
     pub ana_kind : AnalysisKind,
     pub locana : Option<LocalAnalysisParameterization>,
@@ -23,6 +23,7 @@ use crate::core::execution::trace::trace::TraceAction;
 use crate::process::ana::step::AnalysisStepKind;
 
 
+#[derive(Clone)]
 pub struct AnalysisPriorities {
     pub emission : i32,
     pub reception : i32,
@@ -26,7 +26,7 @@ pub enum SimulationStepKind {
 }
 
 
-
+#[derive(Clone)]
 pub enum AnalysisStepKind {
     EliminateNoLongerObserved(HashSet<usize>), // all the ids of all the co-localizations to eliminate
     Execute(FrontierElement, // frontier element to execute
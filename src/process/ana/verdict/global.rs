@@ -20,9 +20,11 @@ use graph_process_manager_core::manager::verdict::AbstractGlobalVerdict;
 
 use crate::process::ana::verdict::inconc::InconcReason;
 use crate::process::ana::verdict::local::AnalysisLocalVerdict;
+use crate::process::parallel::limits::{ProcessResourceLimit, ResourceLimitedGlobalVerdict};
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
 pub enum AnalysisGlobalVerdict{
+    ForbiddenMatched,
     Fail,
     WeakFail,
     Inconc(InconcReason),
@@ -30,6 +32,37 @@ pub enum AnalysisGlobalVerdict{
     Pass
 }
 
+impl AnalysisGlobalVerdict {
+
+    // inconclusive verdicts are all equivalent whatever their reason
+    fn get_rank(&self) -> u32 {
+        match self {
+            AnalysisGlobalVerdict::ForbiddenMatched => 0,
+            AnalysisGlobalVerdict::Fail => 1,
+            AnalysisGlobalVerdict::WeakFail => 2,
+            AnalysisGlobalVerdict::Inconc(_) => 3,
+            AnalysisGlobalVerdict::WeakPass => 4,
+            AnalysisGlobalVerdict::Pass => 5
+        }
+    }
+
+    pub fn is_below(&self, threshold : &AnalysisGlobalVerdict) -> bool {
+        return self.get_rank() < threshold.get_rank();
+    }
+
+    pub fn get_kind_string(&self) -> &'static str {
+        match self {
+            AnalysisGlobalVerdict::ForbiddenMatched => "ForbiddenMatched",
+            AnalysisGlobalVerdict::Fail => "Fail",
+            AnalysisGlobalVerdict::WeakFail => "WeakFail",
+            AnalysisGlobalVerdict::Inconc(_) => "Inconc",
+            AnalysisGlobalVerdict::WeakPass => "WeakPass",
+            AnalysisGlobalVerdict::Pass => "Pass"
+        }
+    }
+
+}
+
 impl fmt::Display for AnalysisGlobalVerdict {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -47,6 +80,9 @@ impl fmt::Display for AnalysisGlobalVerdict {
             },
             AnalysisGlobalVerdict::Fail => {
                 write!(f,"Fail")
+            },
+            AnalysisGlobalVerdict::ForbiddenMatched => {
+                write!(f,"ForbiddenMatched")
             }
         }
     }
@@ -92,6 +128,9 @@ impl AbstractGlobalVerdict<AnalysisLocalVerdict> for AnalysisGlobalVerdict {
                     AnalysisLocalVerdict::Slice => {
                         AnalysisGlobalVerdict::WeakPass
                     },
+                    AnalysisLocalVerdict::ForbiddenMatched => {
+                        AnalysisGlobalVerdict::ForbiddenMatched
+                    },
                     _ => {
                         self
                     }
@@ -115,6 +154,9 @@ impl AbstractGlobalVerdict<AnalysisLocalVerdict> for AnalysisGlobalVerdict {
                     AnalysisLocalVerdict::Inconc(reason) => {
                         AnalysisGlobalVerdict::Inconc(reason.clone())
                     },
+                    AnalysisLocalVerdict::ForbiddenMatched => {
+                        AnalysisGlobalVerdict::ForbiddenMatched
+                    },
                     _ => {
                         self
                     }
@@ -142,6 +184,29 @@ impl AbstractGlobalVerdict<AnalysisLocalVerdict> for AnalysisGlobalVerdict {
                     AnalysisLocalVerdict::OutSim(_) => {
                         AnalysisGlobalVerdict::WeakFail
                     },
+                    AnalysisLocalVerdict::ForbiddenMatched => {
+                        AnalysisGlobalVerdict::ForbiddenMatched
+                    },
+                    _ => {
+                        self
+                    }
+                }
+            },
+            AnalysisGlobalVerdict::ForbiddenMatched => {
+                match local_verdict {
+                    AnalysisLocalVerdict::Cov => {
+                        AnalysisGlobalVerdict::Pass
+                    },
+                    // ***
+                    AnalysisLocalVerdict::TooShort => {
+                        AnalysisGlobalVerdict::WeakPass
+                    },
+                    AnalysisLocalVerdict::MultiPref => {
+                        AnalysisGlobalVerdict::WeakPass
+                    },
+                    AnalysisLocalVerdict::Slice => {
+                        AnalysisGlobalVerdict::WeakPass
+                    },
                     _ => {
                         self
                     }
@@ -170,4 +235,17 @@ impl AbstractGlobalVerdict<AnalysisLocalVerdict> for AnalysisGlobalVerdict {
     }
 }
 
+impl ResourceLimitedGlobalVerdict for AnalysisGlobalVerdict {
 
+    // a Pass cannot be changed by processing more nodes
+    fn update_knowing_resource_limit_was_exceeded(self, limit: &ProcessResourceLimit) -> Self {
+        match self {
+            AnalysisGlobalVerdict::Pass => {
+                self
+            },
+            _ => {
+                AnalysisGlobalVerdict::Inconc(InconcReason::ResourceLimit(limit.clone(),Box::new(self)))
+            }
+        }
+    }
+}
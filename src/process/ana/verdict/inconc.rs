@@ -1,10 +1,15 @@
 use std::fmt;
 
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::parallel::limits::ProcessResourceLimit;
+
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
 pub enum InconcReason {
     LackObs,
     UsingLifelineRemovalWithCoLocalizations,
-    FilteredNodes
+    FilteredNodes,
+    // the limit which was exceeded and the verdict which had been reached when the analysis was stopped
+    ResourceLimit(ProcessResourceLimit,Box<AnalysisGlobalVerdict>)
 }
 
 impl InconcReason {
@@ -19,6 +24,9 @@ impl InconcReason {
             },
             InconcReason::FilteredNodes => {
                 return "due to having set a filter which forcefully limited exploration of the graph : Fails may be false negative".to_string();
+            },
+            InconcReason::ResourceLimit(limit,reached) => {
+                return format!("due to the analysis having been stopped after exceeding {} : the verdict reached until then was {}", limit, reached);
             }
         }
     }
@@ -37,6 +45,9 @@ impl fmt::Display for InconcReason {
             },
             InconcReason::FilteredNodes => {
                 write!(f,"FilteredNodes")
+            },
+            InconcReason::ResourceLimit(limit,reached) => {
+                write!(f,"ResourceLimit({} ; reached {})", limit, reached)
             }
         }
     }
@@ -8,7 +8,8 @@ pub enum AnalysisLocalVerdict{
     Slice,
     Inconc(InconcReason),
     Out(bool),   // bool for if it's known via local analysis
-    OutSim(bool) // bool for if it's known via local analysis}
+    OutSim(bool), // bool for if it's known via local analysis}
+    ForbiddenMatched // the multi-trace matches a negative fragment or deviates from an assertion
 }
 
 impl fmt::Display for AnalysisLocalVerdict {
@@ -43,6 +44,9 @@ impl fmt::Display for AnalysisLocalVerdict {
                 } else {
                     write!(f,"OutSim")
                 }
+            },
+            AnalysisLocalVerdict::ForbiddenMatched => {
+                write!(f,"ForbiddenMatched")
             }
         }
     }
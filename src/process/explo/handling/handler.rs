@@ -21,6 +21,7 @@ use graph_process_manager_core::queued_steps::step::GenericStep;
 
 use crate::core::execution::semantics::execute::execute_interaction;
 use crate::core::execution::semantics::frontier::global_frontier;
+use crate::core::execution::semantics::negative::is_position_within_negative_fragment;
 use crate::process::explo::conf::{ExplorationConfig, ExplorationStaticLocalVerdictAnalysisProof};
 use crate::process::explo::context::{ExplorationContext, ExplorationParameterization};
 use crate::process::explo::filter::filter::ExplorationFilterCriterion;
@@ -70,7 +71,10 @@ impl AbstractProcessHandler<ExplorationConfig> for ExplorationProcessHandler {
                           parent_node_kind: &ExplorationNodeKind)
                 -> Vec<ExplorationStepKind> {
         
-        let mut glob_front = global_frontier(&parent_node_kind.interaction,true);
+        // behaviors of negative fragments are forbidden hence not explored
+        let mut glob_front : Vec<_> = global_frontier(&parent_node_kind.interaction,true).into_iter()
+            .filter(|frt_elt| !is_position_within_negative_fragment(&parent_node_kind.interaction,&frt_elt.position))
+            .collect();
         // reverse so that when one pops from right to left the actions appear from the top to the bottom
         glob_front.reverse();
         // ***
@@ -110,4 +114,28 @@ impl AbstractProcessHandler<ExplorationConfig> for ExplorationProcessHandler {
             }
         }
     }
-}
\ No newline at end of file
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use crate::core::language::syntax::interaction::Interaction;
+    use crate::util::test_fixtures::{context_with, emission};
+    use super::*;
+
+    #[test]
+    fn negative_fragments_are_not_explored() {
+        let context = ExplorationContext::new(context_with(&["a","b"],&["m1","m2"]));
+        // par(a -- m1 ->|, neg(b -- m2 ->|))
+        let int = Interaction::Par(Box::new(emission(0,0)),
+                                   Box::new(Interaction::Neg(Box::new(emission(1,1)))));
+        let steps = ExplorationProcessHandler::collect_next_steps(&context,&ExplorationParameterization{},&ExplorationNodeKind::new(int,0));
+        assert_eq!(steps.len(), 1);
+        match &steps[0] {
+            ExplorationStepKind::Execute( frt_elt ) => {
+                assert_eq!(frt_elt.target_lf_ids, btreeset!{0});
+            }
+        }
+    }
+}
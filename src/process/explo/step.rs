@@ -16,6 +16,7 @@ limitations under the License.
 
 use crate::core::execution::semantics::frontier::FrontierElement;
 
+#[derive(Clone)]
 pub enum ExplorationStepKind {
     Execute(FrontierElement)
 }
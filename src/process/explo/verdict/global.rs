@@ -19,6 +19,7 @@ use std::fmt;
 use graph_process_manager_core::manager::verdict::AbstractGlobalVerdict;
 
 use crate::process::explo::verdict::local::ExplorationLocalVerdict;
+use crate::process::parallel::limits::{ProcessResourceLimit, ResourceLimitedGlobalVerdict};
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
 pub enum ExplorationGlobalVerdict{
@@ -80,4 +81,11 @@ impl AbstractGlobalVerdict<ExplorationLocalVerdict> for ExplorationGlobalVerdict
 
 }
 
+impl ResourceLimitedGlobalVerdict for ExplorationGlobalVerdict {
 
+    fn update_knowing_resource_limit_was_exceeded(self,
+                                                  _limit: &ProcessResourceLimit) -> Self {
+        self
+    }
+
+}
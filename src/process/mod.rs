@@ -18,6 +18,7 @@ limitations under the License.
 pub mod explo;
 pub mod ana;
 pub mod canon;
+pub mod parallel;
 
 
 
@@ -0,0 +1,45 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use graph_process_manager_core::manager::config::AbstractProcessConfiguration;
+
+use crate::process::parallel::limits::ResourceLimitedGlobalVerdict;
+
+
+// what a process configuration must ensure so that new steps can be processed by worker threads
+// while the queue, filters, loggers and global verdict remain handled by a single thread
+// steps are cloned so that worker threads may process them while they are still queued
+pub trait ParallelProcessConfiguration : 'static + AbstractProcessConfiguration<
+    Context : Sync,
+    Parameterization : Sync,
+    NodeKind : Send + Sync,
+    StepKind : Send + Clone,
+    FilterCriterion : Send,
+    LocalVerdict : Send,
+    StaticLocalVerdictAnalysisProof : Send,
+    GlobalVerdict : ResourceLimitedGlobalVerdict> {}
+
+impl<Conf> ParallelProcessConfiguration for Conf where Conf : 'static + AbstractProcessConfiguration<
+    Context : Sync,
+    Parameterization : Sync,
+    NodeKind : Send + Sync,
+    StepKind : Send + Clone,
+    FilterCriterion : Send,
+    LocalVerdict : Send,
+    StaticLocalVerdictAnalysisProof : Send,
+    GlobalVerdict : ResourceLimitedGlobalVerdict> {}
@@ -0,0 +1,125 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::sync::RwLock;
+
+use graph_process_manager_core::delegate::node::GenericNode;
+use graph_process_manager_core::handler::handler::AbstractProcessHandler;
+use graph_process_manager_core::manager::config::{AbstractNodeKind, AbstractProcessConfiguration};
+use graph_process_manager_core::queued_steps::step::GenericStep;
+
+use crate::process::parallel::conf::ParallelProcessConfiguration;
+
+
+// memoized nodes are only ever appended so that a lookup can be resumed from where it stopped
+pub type SharedMemo<Conf> = RwLock<Vec<(<Conf as AbstractProcessConfiguration>::NodeKind,u32)>>;
+
+pub fn lookup_memo<Conf : AbstractProcessConfiguration>(memo : &[(Conf::NodeKind,u32)],
+                                                        from : usize,
+                                                        to_look_up : &Conf::NodeKind) -> Option<u32> {
+    for (memoized_node, memoized_node_id) in &memo[from..] {
+        if to_look_up.is_included_for_memoization(memoized_node) {
+            return Some(*memoized_node_id);
+        }
+    }
+    return None;
+}
+
+
+pub enum NodeExpansion<Conf : AbstractProcessConfiguration> {
+    // the node has no child and is given a local verdict
+    Terminal(Conf::LocalVerdict),
+    // the node (as updated by the static analysis), its next steps,
+    // the verdict of the static analysis if any and whether or not its children are to be processed
+    Steps(Conf::NodeKind,
+          Vec<Conf::StepKind>,
+          Option<(Conf::LocalVerdict,Conf::StaticLocalVerdictAnalysisProof)>,
+          bool)
+}
+
+pub fn expand_node<Conf : AbstractProcessConfiguration>(context : &Conf::Context,
+                                                        param : &Conf::Parameterization,
+                                                        node_kind : Conf::NodeKind) -> NodeExpansion<Conf> {
+    let next_steps = Conf::ProcessHandler::collect_next_steps(context,param,&node_kind);
+    if next_steps.is_empty() {
+        let local_verdict = Conf::ProcessHandler::get_local_verdict_when_no_child(context,param,&node_kind);
+        return NodeExpansion::Terminal(local_verdict);
+    }
+    let mut node_kind = node_kind;
+    match Conf::ProcessHandler::get_local_verdict_from_static_analysis(context,param,&mut node_kind) {
+        None => {
+            return NodeExpansion::Steps(node_kind,next_steps,None,true);
+        },
+        Some((static_verdict,proof_data)) => {
+            let pursue = Conf::ProcessHandler::pursue_process_after_static_verdict(context,param,&static_verdict);
+            return NodeExpansion::Steps(node_kind,next_steps,Some((static_verdict,proof_data)),pursue);
+        }
+    }
+}
+
+
+pub struct StepJob<Conf : AbstractProcessConfiguration> {
+    pub parent_node : GenericNode<Conf::NodeKind>,
+    pub step : GenericStep<Conf::StepKind>
+}
+
+pub struct StepExpansion<Conf : AbstractProcessConfiguration> {
+    pub new_node : Conf::NodeKind,
+    // id of a memoized node including the new node, if one was found by the worker
+    pub memoized_as : Option<u32>,
+    // number of memoized nodes the new node has been compared to
+    pub memo_checked : usize,
+    // not computed if the new node was found to be memoized
+    pub expansion : Option<NodeExpansion<Conf>>
+}
+
+// everything that can be computed about a step without knowing the outcome of the steps processed before it
+// as steps may be processed before being dequeued, the node id and the node counter are not known yet
+// (the handlers of this crate do not use them to process a step)
+pub fn expand_step<Conf : ParallelProcessConfiguration>(context : &Conf::Context,
+                                                        param : &Conf::Parameterization,
+                                                        memo : Option<&SharedMemo<Conf>>,
+                                                        job : StepJob<Conf>) -> StepExpansion<Conf> {
+    let new_node = Conf::ProcessHandler::process_new_step(context,
+                                                          param,
+                                                          &job.parent_node,
+                                                          &job.step,
+                                                          0,
+                                                          0);
+    let (memoized_as,memo_checked) = match memo {
+        None => {
+            (None,0)
+        },
+        Some( memo ) => {
+            let memoized = memo.read().unwrap();
+            (lookup_memo::<Conf>(&memoized,0,&new_node),memoized.len())
+        }
+    };
+    let expansion = match memoized_as {
+        None => {
+            Some(expand_node::<Conf>(context,param,new_node.clone()))
+        },
+        Some(_) => {
+            None
+        }
+    };
+    return StepExpansion{new_node,
+        memoized_as,
+        memo_checked,
+        expansion};
+}
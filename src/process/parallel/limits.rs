@@ -0,0 +1,102 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::fmt;
+use std::time::Duration;
+
+
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone, Hash)]
+pub enum ProcessResourceLimit {
+    MaxTime(Duration),
+    MaxNodesInMemory(u32)
+}
+
+impl fmt::Display for ProcessResourceLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProcessResourceLimit::MaxTime(max_time) => {
+                write!(f,"max_time={:?}",max_time)
+            },
+            ProcessResourceLimit::MaxNodesInMemory(max_nodes) => {
+                write!(f,"max_nodes_in_memory={}",max_nodes)
+            }
+        }
+    }
+}
+
+
+// budgets beyond which a process is stopped before its queue is empty
+#[derive(Clone)]
+pub struct ProcessResourceLimits {
+    pub max_time : Option<Duration>,
+    // nodes kept in memory are those which have children left to process and those which are memoized
+    pub max_nodes_in_memory : Option<u32>
+}
+
+impl ProcessResourceLimits {
+
+    pub fn new(max_time: Option<Duration>, max_nodes_in_memory: Option<u32>) -> Self {
+        ProcessResourceLimits { max_time, max_nodes_in_memory }
+    }
+
+    pub fn unlimited() -> Self {
+        ProcessResourceLimits::new(None,None)
+    }
+
+    pub fn get_exceeded_limit(&self,
+                              elapsed : &Duration,
+                              nodes_in_memory : u32) -> Option<ProcessResourceLimit> {
+        if let Some(max_time) = &self.max_time {
+            if elapsed > max_time {
+                return Some(ProcessResourceLimit::MaxTime(*max_time));
+            }
+        }
+        if let Some(max_nodes) = &self.max_nodes_in_memory {
+            if nodes_in_memory > *max_nodes {
+                return Some(ProcessResourceLimit::MaxNodesInMemory(*max_nodes));
+            }
+        }
+        return None;
+    }
+
+}
+
+
+// as update_knowing_nodes_were_filtered_out for processes stopped because of a resource limit
+pub trait ResourceLimitedGlobalVerdict : Sized {
+
+    fn update_knowing_resource_limit_was_exceeded(self, limit : &ProcessResourceLimit) -> Self;
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exceeded_limits() {
+        let limits = ProcessResourceLimits::new(Some(Duration::from_secs(1)),Some(10));
+        assert_eq!(limits.get_exceeded_limit(&Duration::from_millis(500),10), None);
+        assert_eq!(limits.get_exceeded_limit(&Duration::from_millis(1500),10),
+                   Some(ProcessResourceLimit::MaxTime(Duration::from_secs(1))));
+        assert_eq!(limits.get_exceeded_limit(&Duration::from_millis(500),11),
+                   Some(ProcessResourceLimit::MaxNodesInMemory(10)));
+        assert_eq!(ProcessResourceLimits::unlimited().get_exceeded_limit(&Duration::MAX,u32::MAX), None);
+    }
+}
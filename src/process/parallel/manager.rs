@@ -0,0 +1,585 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Mutex, RwLock};
+use std::thread;
+use std::time::Instant;
+
+use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
+use graph_process_manager_core::delegate::node::GenericNode;
+use graph_process_manager_core::handler::filter::AbstractFilter;
+use graph_process_manager_core::manager::logger::AbstractProcessLogger;
+use graph_process_manager_core::handler::handler::AbstractProcessHandler;
+use graph_process_manager_core::manager::verdict::AbstractGlobalVerdict;
+use graph_process_manager_core::queued_steps::queue::strategy::QueueSearchStrategy;
+use graph_process_manager_core::queued_steps::step::GenericStep;
+
+use crate::process::parallel::conf::ParallelProcessConfiguration;
+use crate::process::parallel::expansion::{expand_node, expand_step, lookup_memo, NodeExpansion, SharedMemo, StepExpansion, StepJob};
+use crate::process::parallel::limits::{ProcessResourceLimit, ProcessResourceLimits, ResourceLimitedGlobalVerdict};
+
+
+/**
+ * Processes the same graph as the GenericProcessManager of graph_process_manager_core
+ * except that, when a dequeued step has to be processed, up to 'threads'-1 other queued steps
+ * are processed along with it by worker threads, ahead of being dequeued.
+ * Steps are still dequeued, filtered and integrated one at a time, in the same order
+ * as with the GenericProcessManager, so that node ids, node counts, loggers and the global verdict
+ * do not depend on the number of threads nor on thread scheduling.
+ * The process is also stopped, with its global verdict updated accordingly, once a resource limit is exceeded.
+ **/
+pub struct ParallelProcessManager<Conf : ParallelProcessConfiguration> {
+    shared : SharedProcessData<Conf>,
+    coordinator : ProcessCoordinator<Conf>,
+    threads : usize
+}
+
+// read by the worker threads
+struct SharedProcessData<Conf : ParallelProcessConfiguration> {
+    context : Conf::Context,
+    param : Conf::Parameterization,
+    memoized : Option<SharedMemo<Conf>>
+}
+
+// only accessed by the thread driving the process
+struct ProcessCoordinator<Conf : ParallelProcessConfiguration> {
+    delegate : GenericProcessDelegate<Conf::StepKind,Conf::NodeKind,Conf::Priorities>,
+    filters : Vec<Box<dyn AbstractFilter<Conf::FilterCriterion,Conf::FilterEliminationKind>>>,
+    loggers : Vec<Box< dyn AbstractProcessLogger<Conf>>>,
+    goal : Option<Conf::GlobalVerdict>,
+    limits : ProcessResourceLimits,
+    // ***
+    has_filtered_nodes : bool,
+    node_has_processed_child : HashSet<u32>,
+    // nodes remembered by the delegate until all their children are processed
+    remembered_nodes : u32,
+    exceeded_limit : Option<ProcessResourceLimit>,
+    // ***
+    // number of steps processed together when a dequeued step has to be processed
+    batch_size : usize,
+    // queued steps which have not been processed ahead of being dequeued
+    pending_steps : HashMap<(u32,u32),GenericStep<Conf::StepKind>>,
+    // in the order in which they were queued, possibly including steps which are no longer pending
+    pending_order : VecDeque<(u32,u32)>,
+    // outcomes of queued steps processed ahead of being dequeued
+    computed_steps : HashMap<(u32,u32),StepExpansion<Conf>>
+}
+
+impl<Conf : ParallelProcessConfiguration> ParallelProcessManager<Conf> {
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(context : Conf::Context,
+               param : Conf::Parameterization,
+               delegate : GenericProcessDelegate<Conf::StepKind,Conf::NodeKind,Conf::Priorities>,
+               filters : Vec<Box<dyn AbstractFilter<Conf::FilterCriterion,Conf::FilterEliminationKind>>>,
+               loggers : Vec<Box< dyn AbstractProcessLogger<Conf>>>,
+               goal : Option<Conf::GlobalVerdict>,
+               is_memoized : bool,
+               threads : usize,
+               limits : ProcessResourceLimits) -> ParallelProcessManager<Conf> {
+        let memoized = if is_memoized {
+            Some(RwLock::new(vec![]))
+        } else {
+            None
+        };
+        let shared = SharedProcessData{context,param,memoized};
+        let coordinator = ProcessCoordinator{delegate,
+            filters,
+            loggers,
+            goal,
+            limits,
+            has_filtered_nodes:false,
+            node_has_processed_child:HashSet::new(),
+            remembered_nodes:0,
+            exceeded_limit:None,
+            batch_size:1,
+            pending_steps:HashMap::new(),
+            pending_order:VecDeque::new(),
+            computed_steps:HashMap::new()};
+        return ParallelProcessManager{shared,coordinator,threads};
+    }
+
+    pub fn get_logger(&self, logger_id : usize) -> Option<&dyn AbstractProcessLogger<Conf>> {
+        return self.coordinator.loggers.get(logger_id).map(|logger| logger.as_ref());
+    }
+
+    pub fn get_exceeded_limit(&self) -> Option<&ProcessResourceLimit> {
+        return self.coordinator.exceeded_limit.as_ref();
+    }
+
+    pub fn start_process(&mut self,
+                         init_node_kind : Conf::NodeKind) -> (u32,Conf::GlobalVerdict) {
+        let shared = &self.shared;
+        let coordinator = &mut self.coordinator;
+        if self.threads <= 1 {
+            let mut process_in_place = |jobs : Vec<StepJob<Conf>>| -> Vec<StepExpansion<Conf>> {
+                jobs.into_iter()
+                    .map(|job| expand_step(&shared.context,&shared.param,shared.memoized.as_ref(),job))
+                    .collect()
+            };
+            return coordinator.run(shared,init_node_kind,1,&mut process_in_place);
+        }
+        // ***
+        let threads = self.threads;
+        let (job_sender,job_receiver) = mpsc::channel::<(usize,StepJob<Conf>)>();
+        let (result_sender,result_receiver) = mpsc::channel::<(usize,thread::Result<StepExpansion<Conf>>)>();
+        let job_receiver = Mutex::new(job_receiver);
+        thread::scope(|scope| {
+            for _ in 0..threads {
+                let job_receiver = &job_receiver;
+                let result_sender = result_sender.clone();
+                scope.spawn(move || {
+                    loop {
+                        // the lock is released before the job is processed
+                        let received = job_receiver.lock().unwrap().recv();
+                        match received {
+                            Err(_) => {
+                                return;
+                            },
+                            Ok( (job_index,job) ) => {
+                                // a panic is sent back so that the thread driving the process does not wait for the job indefinitely
+                                let expansion = panic::catch_unwind(AssertUnwindSafe(|| {
+                                    expand_step(&shared.context,&shared.param,shared.memoized.as_ref(),job)
+                                }));
+                                if result_sender.send((job_index,expansion)).is_err() {
+                                    return;
+                                }
+                            }
+                        }
+                    }
+                });
+            }
+            drop(result_sender);
+            // ***
+            let mut process_in_workers = |jobs : Vec<StepJob<Conf>>| -> Vec<StepExpansion<Conf>> {
+                let num_jobs = jobs.len();
+                for (job_index,job) in jobs.into_iter().enumerate() {
+                    job_sender.send((job_index,job)).unwrap();
+                }
+                let mut expansions : Vec<(usize,StepExpansion<Conf>)> = Vec::with_capacity(num_jobs);
+                while expansions.len() < num_jobs {
+                    match result_receiver.recv() {
+                        Err(_) => {
+                            panic!("worker threads stopped before processing all the steps of a batch");
+                        },
+                        Ok( (_,Err(panic_payload)) ) => {
+                            // the job sender is dropped while unwinding so that the other workers stop
+                            panic::resume_unwind(panic_payload);
+                        },
+                        Ok( (job_index,Ok(expansion)) ) => {
+                            expansions.push((job_index,expansion));
+                        }
+                    }
+                }
+                expansions.sort_by_key(|(job_index,_)| *job_index);
+                expansions.into_iter().map(|(_,expansion)| expansion).collect()
+            };
+            let got = coordinator.run(shared,init_node_kind,threads,&mut process_in_workers);
+            // workers stop once there are no more jobs to receive
+            drop(job_sender);
+            got
+        })
+    }
+
+}
+
+impl<Conf : ParallelProcessConfiguration> ProcessCoordinator<Conf> {
+
+    fn run(&mut self,
+           shared : &SharedProcessData<Conf>,
+           init_node_kind : Conf::NodeKind,
+           batch_size : usize,
+           process_batch : &mut dyn FnMut(Vec<StepJob<Conf>>) -> Vec<StepExpansion<Conf>>) -> (u32,Conf::GlobalVerdict) {
+
+        let start_time = Instant::now();
+        self.batch_size = batch_size;
+        let mut next_node_id : u32 = 1;
+        let mut node_counter : u32 = 0;
+
+        self.loggers_initialize();
+        self.loggers_parameterization(shared);
+        self.loggers_new_node(shared,next_node_id,&init_node_kind);
+
+        let mut global_verdict = Conf::GlobalVerdict::get_baseline_verdict();
+
+        if let Some(memo) = &shared.memoized {
+            memo.write().unwrap().push((init_node_kind.clone(),next_node_id));
+        }
+
+        let init_expansion = expand_node::<Conf>(&shared.context,&shared.param,init_node_kind);
+        let pursue_process = match self.integrate_node_expansion(shared,next_node_id,0,init_expansion) {
+            None => {
+                true
+            },
+            Some(local_verdict) => {
+                global_verdict = global_verdict.update_with_local_verdict(&local_verdict);
+                !global_verdict.is_goal_reached(&self.goal)
+            }
+        } && !self.check_limits(shared,&start_time);
+        next_node_id += 1;
+        node_counter += 1;
+
+        if pursue_process {
+            while let Some(step) = self.delegate.extract_from_queue() {
+                let new_node_id = next_node_id;
+                next_node_id += 1;
+                if let Some(local_verdict) = self.process_step(shared,step,new_node_id,&mut node_counter,process_batch) {
+                    global_verdict = global_verdict.update_with_local_verdict(&local_verdict);
+                    if global_verdict.is_goal_reached(&self.goal) {
+                        break;
+                    }
+                }
+                if self.check_limits(shared,&start_time) {
+                    break;
+                }
+            }
+        }
+
+        // ***
+        global_verdict = global_verdict.update_knowing_nodes_were_filtered_out(self.has_filtered_nodes);
+        if let Some(limit) = &self.exceeded_limit {
+            global_verdict = global_verdict.update_knowing_resource_limit_was_exceeded(limit);
+        }
+
+        self.loggers_terminate(&global_verdict);
+
+        // ***
+        (node_counter,global_verdict)
+    }
+
+    // returns true if the process must be stopped
+    fn check_limits(&mut self,
+                    shared : &SharedProcessData<Conf>,
+                    start_time : &Instant) -> bool {
+        let memoized_nodes = match &shared.memoized {
+            None => {
+                0
+            },
+            Some( memo ) => {
+                memo.read().unwrap().len() as u32
+            }
+        };
+        self.exceeded_limit = self.limits.get_exceeded_limit(&start_time.elapsed(),
+                                                             self.remembered_nodes + memoized_nodes);
+        return self.exceeded_limit.is_some();
+    }
+
+    // the step is filtered against the node counter as it is when the step is dequeued
+    fn process_step(&mut self,
+                    shared : &SharedProcessData<Conf>,
+                    step : GenericStep<Conf::StepKind>,
+                    new_node_id : u32,
+                    node_counter : &mut u32,
+                    process_batch : &mut dyn FnMut(Vec<StepJob<Conf>>) -> Vec<StepExpansion<Conf>>) -> Option<Conf::LocalVerdict> {
+        let step_key = (step.parent_id,step.id_as_child);
+        self.pending_steps.remove(&step_key);
+        let computed = self.computed_steps.remove(&step_key);
+        let mut local_verdict = None;
+        // ***
+        let criterion = Conf::ProcessHandler::get_criterion(&shared.context,
+                                                            &shared.param,
+                                                            self.delegate.peek_memorized_state(step.parent_id),
+                                                            &step,
+                                                            new_node_id,
+                                                            *node_counter);
+        let child_depth = self.delegate.peek_memorized_state(step.parent_id).depth + 1;
+        match self.apply_filters(child_depth,*node_counter,&criterion) {
+            Some(filter_elimination) => {
+                self.loggers_filtered(shared,step.parent_id,new_node_id,&filter_elimination);
+            },
+            None => {
+                let step_expansion = match computed {
+                    Some( got_expansion ) => {
+                        got_expansion
+                    },
+                    None => {
+                        self.process_step_with_pending_ones(&step,process_batch)
+                    }
+                };
+                self.node_has_processed_child.insert(step.parent_id);
+                // nodes memoized since the worker's lookup are checked here
+                let memoized_as = match (step_expansion.memoized_as,&shared.memoized) {
+                    (Some(memorized_node_id),_) => {
+                        Some(memorized_node_id)
+                    },
+                    (None,None) => {
+                        None
+                    },
+                    (None,Some(memo)) => {
+                        let mut memoized = memo.write().unwrap();
+                        let found = lookup_memo::<Conf>(&memoized,step_expansion.memo_checked,&step_expansion.new_node);
+                        if found.is_none() {
+                            memoized.push((step_expansion.new_node.clone(),new_node_id));
+                        }
+                        found
+                    }
+                };
+                // ***
+                match memoized_as {
+                    None => {
+                        *node_counter += 1;
+                        self.loggers_new_node(shared,new_node_id,&step_expansion.new_node);
+                        self.loggers_new_step(shared,
+                                              step.parent_id,
+                                              new_node_id,
+                                              &step.kind,
+                                              &step_expansion.new_node,
+                                              child_depth);
+                        // the worker expands any node it did not find memoized
+                        let expansion = step_expansion.expansion.unwrap();
+                        local_verdict = self.integrate_node_expansion(shared,new_node_id,child_depth,expansion);
+                    },
+                    Some(memorized_node_id) => {
+                        self.loggers_new_step(shared,
+                                              step.parent_id,
+                                              memorized_node_id,
+                                              &step.kind,
+                                              &step_expansion.new_node,
+                                              child_depth);
+                    }
+                }
+            }
+        }
+        // ***
+        let mut parent_node = self.delegate.pop_memorized_state(step.parent_id);
+        parent_node.remaining_ids_to_process.remove(&step.id_as_child);
+        // ***
+        if parent_node.remaining_ids_to_process.is_empty() {
+            let parent_had_at_least_one_processed_child = self.node_has_processed_child.remove(&step.parent_id);
+            if !parent_had_at_least_one_processed_child {
+                // for the HCS queue to know the node id'ed by parent_id is terminal
+                self.delegate.queue_set_last_reached_has_no_child();
+                self.loggers_notify_terminal_node_reached(shared,step.parent_id);
+            }
+            self.loggers_notify_last_child_of_node_processed(shared,step.parent_id);
+            self.remembered_nodes -= 1;
+        } else {
+            self.delegate.remember_state(step.parent_id,parent_node);
+        }
+        // ***
+        return local_verdict;
+    }
+
+    // processes the step along with up to 'batch_size'-1 pending steps, whose outcomes are kept for when they are dequeued
+    // steps queued last are dequeued first except with a breadth first search
+    fn process_step_with_pending_ones(&mut self,
+                                      step : &GenericStep<Conf::StepKind>,
+                                      process_batch : &mut dyn FnMut(Vec<StepJob<Conf>>) -> Vec<StepExpansion<Conf>>) -> StepExpansion<Conf> {
+        let mut jobs_keys : Vec<(u32,u32)> = vec![];
+        let mut jobs : Vec<StepJob<Conf>> = vec![self.new_step_job(GenericStep::new(step.parent_id,step.id_as_child,step.kind.clone()))];
+        while jobs.len() < self.batch_size {
+            let next_key = match self.delegate.get_strategy() {
+                QueueSearchStrategy::BFS => {
+                    self.pending_order.pop_front()
+                },
+                _ => {
+                    self.pending_order.pop_back()
+                }
+            };
+            match next_key {
+                None => {
+                    break;
+                },
+                Some( key ) => {
+                    if let Some(pending_step) = self.pending_steps.remove(&key) {
+                        jobs.push(self.new_step_job(pending_step));
+                        jobs_keys.push(key);
+                    }
+                }
+            }
+        }
+        // ***
+        let mut expansions = process_batch(jobs).into_iter();
+        let step_expansion = expansions.next().unwrap();
+        for (key,expansion) in jobs_keys.into_iter().zip(expansions) {
+            self.computed_steps.insert(key,expansion);
+        }
+        return step_expansion;
+    }
+
+    fn new_step_job(&self, step : GenericStep<Conf::StepKind>) -> StepJob<Conf> {
+        let parent = self.delegate.peek_memorized_state(step.parent_id);
+        let parent_node = GenericNode::new(parent.kind.clone(),
+                                           parent.remaining_ids_to_process.clone(),
+                                           parent.depth);
+        return StepJob{parent_node,step};
+    }
+
+    fn integrate_node_expansion(&mut self,
+                                shared : &SharedProcessData<Conf>,
+                                node_id : u32,
+                                depth : u32,
+                                expansion : NodeExpansion<Conf>) -> Option<Conf::LocalVerdict> {
+        match expansion {
+            NodeExpansion::Terminal(local_verdict) => {
+                // for the HCS queue to know the node id'ed by node_id is terminal
+                self.delegate.queue_set_last_reached_has_no_child();
+                self.loggers_notify_terminal_node_reached(shared,node_id);
+                self.loggers_verdict(shared,node_id,&local_verdict,None);
+                return Some(local_verdict);
+            },
+            NodeExpansion::Steps(node_kind,next_steps,static_verdict,pursue_process) => {
+                let local_verdict = match static_verdict {
+                    None => {
+                        None
+                    },
+                    Some((static_verdict,proof_data)) => {
+                        self.loggers_verdict(shared,node_id,&static_verdict,Some(proof_data));
+                        Some(static_verdict)
+                    }
+                };
+                if pursue_process {
+                    let to_enqueue : Vec<GenericStep<Conf::StepKind>> = next_steps.into_iter()
+                        .enumerate()
+                        .map(|(idx,step_kind)| GenericStep::new(node_id,(idx as u32) + 1,step_kind))
+                        .collect();
+                    let remaining_ids_to_process : HashSet<u32> = (1..(to_enqueue.len() as u32 + 1)).collect();
+                    self.delegate.remember_state(node_id,GenericNode::new(node_kind,remaining_ids_to_process,depth));
+                    self.remembered_nodes += 1;
+                    if self.batch_size > 1 {
+                        for step in &to_enqueue {
+                            let key = (step.parent_id,step.id_as_child);
+                            self.pending_steps.insert(key,GenericStep::new(step.parent_id,step.id_as_child,step.kind.clone()));
+                            self.pending_order.push_back(key);
+                        }
+                    }
+                    self.delegate.enqueue_new_steps(node_id,to_enqueue);
+                } else {
+                    // for the HCS queue to know the node id'ed by node_id is terminal
+                    self.delegate.queue_set_last_reached_has_no_child();
+                    self.loggers_notify_terminal_node_reached(shared,node_id);
+                }
+                return local_verdict;
+            }
+        }
+    }
+
+    fn apply_filters(&self,
+                     depth : u32,
+                     node_counter : u32,
+                     criterion : &Conf::FilterCriterion) -> Option<Conf::FilterEliminationKind> {
+        for filter in &self.filters {
+            if let Some(elim_kind) = filter.apply_filter(depth,node_counter,criterion) {
+                return Some(elim_kind);
+            }
+        }
+        return None;
+    }
+
+    fn loggers_initialize(&mut self) {
+        for logger in self.loggers.iter_mut() {
+            logger.log_initialize();
+        }
+    }
+
+    fn loggers_parameterization(&mut self, shared : &SharedProcessData<Conf>) {
+        let strategy = self.delegate.get_strategy();
+        let priorities = self.delegate.get_priorities();
+        let use_memoization = shared.memoized.is_some();
+        for logger in self.loggers.iter_mut() {
+            logger.log_parameterization(strategy,
+                                        priorities,
+                                        &self.filters,
+                                        &self.goal,
+                                        use_memoization,
+                                        &shared.param);
+        }
+    }
+
+    fn loggers_filtered(&mut self,
+                        shared : &SharedProcessData<Conf>,
+                        parent_node_id : u32,
+                        new_node_id : u32,
+                        elim_kind : &Conf::FilterEliminationKind) {
+        self.has_filtered_nodes = true;
+        for logger in self.loggers.iter_mut() {
+            logger.log_filtered(&shared.context,parent_node_id,new_node_id,elim_kind);
+        }
+    }
+
+    fn loggers_new_node(&mut self,
+                        shared : &SharedProcessData<Conf>,
+                        new_node_id : u32,
+                        new_node : &Conf::NodeKind) {
+        for logger in self.loggers.iter_mut() {
+            logger.log_new_node(&shared.context,&shared.param,new_node_id,new_node);
+        }
+    }
+
+    fn loggers_new_step(&mut self,
+                        shared : &SharedProcessData<Conf>,
+                        origin_node_id : u32,
+                        target_node_id : u32,
+                        new_step : &Conf::StepKind,
+                        target_node : &Conf::NodeKind,
+                        target_depth : u32) {
+        for logger in self.loggers.iter_mut() {
+            logger.log_new_step(&shared.context,
+                                &shared.param,
+                                origin_node_id,
+                                target_node_id,
+                                new_step,
+                                target_node,
+                                target_depth);
+        }
+    }
+
+    fn loggers_verdict(&mut self,
+                       shared : &SharedProcessData<Conf>,
+                       node_id : u32,
+                       local_verdict : &Conf::LocalVerdict,
+                       proof : Option<Conf::StaticLocalVerdictAnalysisProof>) {
+        match proof {
+            None => {
+                for logger in self.loggers.iter_mut() {
+                    logger.log_verdict_on_no_child(&shared.context,&shared.param,node_id,local_verdict);
+                }
+            },
+            Some(data) => {
+                for logger in self.loggers.iter_mut() {
+                    logger.log_verdict_on_static_analysis(&shared.context,&shared.param,node_id,local_verdict,&data);
+                }
+            }
+        }
+    }
+
+    fn loggers_notify_terminal_node_reached(&mut self,
+                                            shared : &SharedProcessData<Conf>,
+                                            node_id : u32) {
+        for logger in self.loggers.iter_mut() {
+            logger.log_notify_terminal_node_reached(&shared.context,node_id);
+        }
+    }
+
+    fn loggers_notify_last_child_of_node_processed(&mut self,
+                                                   shared : &SharedProcessData<Conf>,
+                                                   node_id : u32) {
+        for logger in self.loggers.iter_mut() {
+            logger.log_notify_last_child_of_node_processed(&shared.context,node_id);
+        }
+    }
+
+    fn loggers_terminate(&mut self, global_verdict : &Conf::GlobalVerdict) {
+        for logger in self.loggers.iter_mut() {
+            logger.log_terminate(global_verdict);
+        }
+    }
+
+}
@@ -0,0 +1,91 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+// removes chunks of elements as long as the test still fails, refining the chunks until single elements
+// the result is 1-minimal : removing any one of its elements makes the test pass
+pub fn ddmin<T : Clone>(elements : Vec<T>,
+                        still_fails : &mut dyn FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut current = elements;
+    let mut granularity : usize = 2;
+    while current.len() >= 2 {
+        let chunks = split_in_chunks(current.len(), granularity);
+        let mut reduced = false;
+        // tries to keep a single chunk
+        for (start,end) in &chunks {
+            if still_fails(&current[*start..*end]) {
+                current = current[*start..*end].to_vec();
+                granularity = 2;
+                reduced = true;
+                break;
+            }
+        }
+        // tries to remove a single chunk
+        if !reduced && granularity > 2 {
+            for (start,end) in &chunks {
+                let complement : Vec<T> = current[..*start].iter().chain(current[*end..].iter()).cloned().collect();
+                if still_fails(&complement) {
+                    current = complement;
+                    granularity = (granularity - 1).max(2);
+                    reduced = true;
+                    break;
+                }
+            }
+        }
+        if !reduced {
+            if granularity >= current.len() {
+                break;
+            }
+            granularity = (granularity * 2).min(current.len());
+        }
+    }
+    if current.len() == 1 && still_fails(&[]) {
+        current = vec![];
+    }
+    return current;
+}
+
+// the sizes of the chunks differ by at most one
+fn split_in_chunks(length : usize, granularity : usize) -> Vec<(usize,usize)> {
+    let mut chunks = vec![];
+    let mut start = 0;
+    for chunk_idx in 0..granularity {
+        let end = (length * (chunk_idx + 1)) / granularity;
+        if end > start {
+            chunks.push((start,end));
+        }
+        start = end;
+    }
+    return chunks;
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::ddmin;
+
+    #[test]
+    fn ddmin_finds_the_two_culprits() {
+        let elements : Vec<u32> = (0..20).collect();
+        let mut num_tests = 0;
+        let got = ddmin(elements, &mut |subset : &[u32]| {
+            num_tests += 1;
+            subset.contains(&3) && subset.contains(&17)
+        });
+        assert_eq!(got, vec![3,17]);
+        assert!(num_tests < 60);
+    }
+}
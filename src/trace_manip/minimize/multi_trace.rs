@@ -0,0 +1,54 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::HashMap;
+
+use crate::core::execution::trace::multitrace::MultiTrace;
+use crate::trace_manip::minimize::ddmin::ddmin;
+use crate::trace_manip::mutate::remove_actions::remove_all_actions_except;
+
+
+pub struct MultiTraceMinimization {
+    pub minimized : MultiTrace,
+    // number of distinct sub-multi-traces which were tested
+    pub num_tests : u32
+}
+
+// multi-actions are ordered canal by canal so that removed chunks are blocks of consecutive multi-actions
+pub fn minimize_failing_multi_trace(multi_trace : &MultiTrace,
+                                    still_fails : &mut dyn FnMut(&MultiTrace) -> bool) -> MultiTraceMinimization {
+    let mut places : Vec<(usize,usize)> = vec![];
+    for (coloc_id,trace) in multi_trace.iter().enumerate() {
+        for act_idx in 0..trace.len() {
+            places.push((coloc_id,act_idx));
+        }
+    }
+    // ***
+    let mut tested : HashMap<Vec<(usize,usize)>,bool> = HashMap::new();
+    let kept = ddmin(places, &mut |kept_places : &[(usize,usize)]| {
+        if let Some(got) = tested.get(kept_places) {
+            return *got;
+        }
+        let got = still_fails(&remove_all_actions_except(multi_trace,kept_places));
+        tested.insert(kept_places.to_vec(),got);
+        got
+    });
+    // ***
+    let minimized = remove_all_actions_except(multi_trace,&kept);
+    return MultiTraceMinimization{minimized,num_tests:tested.len() as u32};
+}
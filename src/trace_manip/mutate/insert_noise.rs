@@ -78,9 +78,9 @@ fn generate_random_action_on_coloc(rng : &mut ThreadRng,
         ms_id = *msgs_as_vec.get(got_id).unwrap();
     }
     if rng.gen::<bool>() {
-        return TraceAction::new(lf_id,TraceActionKind::Emission,ms_id);
+        return TraceAction::new(lf_id,TraceActionKind::Emission,ms_id,vec![]);
     } else {
-        return TraceAction::new(lf_id,TraceActionKind::Reception,ms_id);
+        return TraceAction::new(lf_id,TraceActionKind::Reception,ms_id,vec![]);
     }
 }
 
@@ -78,6 +78,19 @@ fn mutate_by_removing_actions(gen_ctx : &GeneralContext,
 
 
 
+// only keeps the multi-actions at the given (canal id, index in canal) places
+pub fn remove_all_actions_except(multi_trace : &MultiTrace,
+                                 kept : &[(usize,usize)]) -> MultiTrace {
+    let mut reduced_mt : MultiTrace = vec![vec![];multi_trace.len()];
+    for (coloc_id,act_idx) in kept {
+        let actions = multi_trace.get(*coloc_id).unwrap().get(*act_idx).unwrap();
+        reduced_mt.get_mut(*coloc_id).unwrap().push(actions.clone());
+    }
+    reduced_mt
+}
+
+
+
 fn mutate_vector_by_removal<T>(rng : &mut ThreadRng, vector : &mut Vec<T>) {
     let vec_length = vector.len();
     if vec_length > 0 {
@@ -0,0 +1,100 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::output::alignment_report::alignment_search_result_as_text;
+use crate::io::output::to_json::alignment::multi_trace_alignment_as_json;
+use crate::process::ana::align::search::align_multi_trace;
+
+
+pub fn cli_align(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let htf_file_path = matches.value_of("htf").unwrap();
+                    match parse_htf_file(&gen_ctx,htf_file_path) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( (co_localizations,multi_trace) ) => {
+                            let max_cost : Option<u32> = match matches.value_of("max_cost") {
+                                None => {
+                                    None
+                                },
+                                Some( got_str ) => {
+                                    match got_str.parse::<u32>() {
+                                        Err(_) => {
+                                            return (vec![format!("max cost must be a non-negative integer, got '{}'", got_str)],1);
+                                        },
+                                        Ok( got ) => {
+                                            Some( got )
+                                        }
+                                    }
+                                }
+                            };
+                            // ***
+                            let now = Instant::now();
+                            let alignment = align_multi_trace(gen_ctx.clone(),co_localizations.clone(),multi_trace,int,max_cost);
+                            let elapsed_time = now.elapsed();
+                            // ***
+                            if matches.value_of("format") == Some("json") {
+                                match &alignment {
+                                    None => {
+                                        println!("null");
+                                        return (vec![],1);
+                                    },
+                                    Some( got_alignment ) => {
+                                        println!("{}", multi_trace_alignment_as_json(&gen_ctx,&co_localizations,got_alignment));
+                                        return (vec![],0);
+                                    }
+                                }
+                            }
+                            let mut ret_print = vec![];
+                            ret_print.push( "ALIGNING MULTI-TRACE".to_string());
+                            ret_print.push( format!("from file '{}'",htf_file_path) );
+                            ret_print.push( "W.R.T. INTERACTION".to_string());
+                            ret_print.push( format!("from file '{}'",hif_file_path) );
+                            ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+                            ret_print.push( "".to_string());
+                            ret_print.append( &mut alignment_search_result_as_text(&gen_ctx,&co_localizations,&alignment,max_cost) );
+                            if alignment.is_some() {
+                                return (ret_print,0);
+                            } else {
+                                return (ret_print,1);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
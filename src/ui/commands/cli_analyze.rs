@@ -17,8 +17,6 @@ limitations under the License.
 use std::time::Instant;
 
 use clap::ArgMatches;
-use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
-use graph_process_manager_core::manager::manager::GenericProcessManager;
 
 use crate::core::execution::trace::multitrace::multi_trace_length;
 use crate::io::input::hcf::ana::interface::parse_hcf_file_for_ana;
@@ -26,12 +24,20 @@ use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
 use crate::io::input::hsf::interface::parse_hsf_file;
 use crate::io::input::hif::interface::parse_hif_file;
 use crate::io::input::htf::interface::parse_htf_file;
-use crate::process::ana::conf::AnalysisConfig;
-use crate::process::ana::context::AnalysisContext;
-use crate::process::ana::node::flags::MultiTraceAnalysisFlags;
-use crate::process::ana::node::node::AnalysisNodeKind;
-use crate::process::ana::priorities::AnalysisPriorities;
-use crate::process::ana::step::AnalysisStepKind;
+use crate::io::output::draw_interactions::interface::{draw_interaction, InteractionGraphicalRepresentation};
+use crate::io::output::alignment_report::alignment_search_result_as_text;
+use crate::io::output::failure_report::analysis_failure_diagnoses_as_text;
+use crate::io::output::to_json::alignment::multi_trace_alignment_as_json;
+use crate::io::output::to_json::diagnosis::analysis_failure_diagnoses_as_json;
+use crate::io::output::to_json::verdict::analysis_result_as_json;
+use crate::io::output::to_json::witness::analysis_witness_as_json;
+use crate::io::output::witness_report::analysis_witness_as_text;
+use crate::process::ana::align::search::align_multi_trace;
+use crate::process::ana::interface::{analyze_multi_trace, hide_unobserved_messages};
+use crate::process::ana::loggers::diag::logger::AnalysisDiagnosisLogger;
+use crate::process::ana::loggers::witness::logger::AnalysisWitnessLogger;
+use crate::process::ana::param::anakind::AnalysisKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
 
 
 pub fn cli_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
@@ -68,6 +74,10 @@ pub fn cli_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
                                 ana_opts = HibouAnalyzeOptions::default();
                             }
                             // ***
+                            // the diagnosis refers to the actions of the ".htf" file, including hidden ones
+                            let original_multi_trace = multi_trace.clone();
+                            let (int,multi_trace) = hide_unobserved_messages(int,multi_trace,&ana_opts.unobserved_messages);
+                            // ***
                             let multi_trace_length = multi_trace_length(&multi_trace);
                             let mut ret_print = vec![];
                             ret_print.push( "ANALYZING TRACE".to_string());
@@ -75,44 +85,113 @@ pub fn cli_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
                             ret_print.push( format!("of length '{:?}'", multi_trace_length) );
                             ret_print.push( "W.R.T. INTERACTION".to_string());
                             ret_print.push( format!("from file '{}'",hsf_file_path) );
+                            if !ana_opts.unobserved_messages.is_empty() {
+                                let hidden : Vec<String> = ana_opts.unobserved_messages.iter()
+                                    .map(|ms_id| gen_ctx.get_ms_name(*ms_id).unwrap())
+                                    .collect();
+                                ret_print.push( format!("hiding unobserved messages : [{}]", hidden.join(",")) );
+                            }
                             if ana_opts.ana_param.partial_order_reduction {
                                 ret_print.push( "WARNING : uses experimental Partial Order Reduction that returns FALSE NEGATIVES when using synchronous operator".to_string() );
                             }
                             ret_print.push( "".to_string());
                             // ***
-                            let ana_ctx = AnalysisContext::new(gen_ctx,co_localizations,multi_trace,multi_trace_length);
-                            let delegate : GenericProcessDelegate<AnalysisStepKind,AnalysisNodeKind,AnalysisPriorities> = GenericProcessDelegate::new(ana_opts.strategy,ana_opts.priorities);
-
-                            let init_flags : MultiTraceAnalysisFlags;
-                            match ana_opts.ana_param.ana_kind.get_sim_config() {
-                                None => {
-                                    init_flags = MultiTraceAnalysisFlags::new_init(ana_ctx.co_localizations.num_colocs(),
-                                                                                   0,
-                                                                                   0);
-                                },
-                                Some( sim_config ) => {
-                                    init_flags = MultiTraceAnalysisFlags::new_init(ana_ctx.co_localizations.num_colocs(),
-                                                                                   sim_config.get_reset_rem_loop(multi_trace_length,&int),
-                                                                                   sim_config.get_reset_rem_act(multi_trace_length,&int));
+                            // alignments are searched for instead of computing a verdict
+                            if let AnalysisKind::Align(max_cost) = ana_opts.ana_param.ana_kind {
+                                let now = Instant::now();
+                                let alignment = align_multi_trace(gen_ctx.clone(),co_localizations.clone(),multi_trace,int,max_cost);
+                                let elapsed_time = now.elapsed();
+                                if matches.value_of("format") == Some("json") {
+                                    match &alignment {
+                                        None => {
+                                            println!("null");
+                                        },
+                                        Some( got_alignment ) => {
+                                            println!("{}", multi_trace_alignment_as_json(&gen_ctx,&co_localizations,got_alignment));
+                                        }
+                                    }
+                                    return (vec![],0);
                                 }
+                                ret_print.push( format!("analysis   : {}", ana_opts.ana_param.ana_kind) );
+                                ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+                                ret_print.push( "".to_string());
+                                ret_print.append( &mut alignment_search_result_as_text(&gen_ctx,&co_localizations,&alignment,max_cost) );
+                                return (ret_print,0);
+                            }
+                            // kept to explain a failure once the analysis context has been consumed
+                            let diag_data = (gen_ctx.clone(),co_localizations.clone(),multi_trace.clone(),ana_opts.unobserved_messages.clone());
+                            let mut ana_opts = ana_opts;
+                            let diag_logger_id = ana_opts.loggers.len();
+                            ana_opts.loggers.push( Box::new(AnalysisDiagnosisLogger::new()) );
+                            let witness_logger_id = ana_opts.loggers.len();
+                            if matches.is_present("witness") {
+                                ana_opts.loggers.push( Box::new(AnalysisWitnessLogger::new()) );
                             }
-                            let mut analysis_manager : GenericProcessManager<AnalysisConfig> = GenericProcessManager::new(ana_ctx,
-                                                                                                                          ana_opts.ana_param,
-                                                                                                                                delegate,
-                                                                                                                          ana_opts.filters,
-                                                                                                                          ana_opts.loggers,
-                                                                                                                          ana_opts.goal,
-                                                                                                                          ana_opts.use_memoization);
-
-
-                            let init_node = AnalysisNodeKind::new(int,init_flags,0);
                             // ***
                             let now = Instant::now();
-                            let (node_count,verdict) = analysis_manager.start_process(init_node);
+                            let (analysis_manager,node_count,verdict) = analyze_multi_trace(gen_ctx,co_localizations,multi_trace,int,ana_opts);
                             let elapsed_time = now.elapsed();
+                            // ***
+                            let diagnoses = match verdict {
+                                AnalysisGlobalVerdict::Fail | AnalysisGlobalVerdict::WeakFail => {
+                                    let raw_logger = analysis_manager.get_logger(diag_logger_id).unwrap();
+                                    let diag_logger : &AnalysisDiagnosisLogger = raw_logger.as_any().downcast_ref::<AnalysisDiagnosisLogger>().unwrap();
+                                    let mut got_diagnoses = diag_logger.get_diagnoses(&diag_data.2);
+                                    for got_diagnosis in got_diagnoses.iter_mut() {
+                                        got_diagnosis.relocate_in_original_multi_trace(&original_multi_trace,&diag_data.3);
+                                    }
+                                    got_diagnoses
+                                },
+                                _ => {
+                                    vec![]
+                                }
+                            };
+                            let witness = match matches.value_of("witness") {
+                                None => {
+                                    None
+                                },
+                                Some( witness_name ) => {
+                                    let raw_logger = analysis_manager.get_logger(witness_logger_id).unwrap();
+                                    let witness_logger : &AnalysisWitnessLogger = raw_logger.as_any().downcast_ref::<AnalysisWitnessLogger>().unwrap();
+                                    let got_witness = witness_logger.get_witness();
+                                    if let Some(ref some_witness) = got_witness {
+                                        draw_interaction(&diag_data.0, &some_witness.get_scenario(), &InteractionGraphicalRepresentation::AsSequenceDiagram,
+                                                         &"temp".to_string(), &"".to_string(), &witness_name.to_string());
+                                    }
+                                    Some( (witness_name,got_witness) )
+                                }
+                            };
+                            // ***
+                            if matches.value_of("format") == Some("json") {
+                                let mut result = analysis_result_as_json(&verdict,node_count,&elapsed_time);
+                                if !diagnoses.is_empty() {
+                                    result["diagnoses"] = analysis_failure_diagnoses_as_json(&diag_data.0,&diag_data.1,&diagnoses);
+                                }
+                                if let Some( (_,Some(got_witness)) ) = &witness {
+                                    result["witness"] = analysis_witness_as_json(&diag_data.0,&diag_data.1,got_witness);
+                                }
+                                println!("{}", result);
+                                return (vec![],0);
+                            }
                             ret_print.push( format!("verdict    : '{}'", verdict.to_string() ) );
                             ret_print.push( format!("node count : {:?}", node_count ) );
                             ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
+                            if !diagnoses.is_empty() {
+                                ret_print.push( "".to_string());
+                                ret_print.append( &mut analysis_failure_diagnoses_as_text(&diag_data.0,&diag_data.1,htf_file_path,&diagnoses) );
+                            }
+                            match &witness {
+                                None => {},
+                                Some( (_,None) ) => {
+                                    ret_print.push( "".to_string());
+                                    ret_print.push( "no acceptance witness : no node covering the multi-trace was reached".to_string());
+                                },
+                                Some( (witness_name,Some(got_witness)) ) => {
+                                    ret_print.push( "".to_string());
+                                    ret_print.append( &mut analysis_witness_as_text(&diag_data.0,&diag_data.1,got_witness) );
+                                    ret_print.push( format!("scenario drawn on file : {}.png", witness_name) );
+                                }
+                            }
                             return (ret_print,0);
                         }
                     }
@@ -0,0 +1,222 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use clap::ArgMatches;
+
+use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::input::hcf::ana::interface::get_analysis_options_without_loggers;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::output::batch_report::{batch_analysis_as_csv, batch_analysis_as_json, BatchAnalysisRecord};
+use crate::process::ana::interface::{analyze_multi_trace, hide_unobserved_messages};
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::process::ana::verdict::inconc::InconcReason;
+
+
+// either all the trace files of a folder or those listed in a manifest file, one per line
+// relative paths in a manifest are resolved from the folder of the manifest
+pub fn get_trace_files(traces_path : &str) -> Result<Vec<String>,String> {
+    let path_object = Path::new(traces_path);
+    if path_object.is_dir() {
+        let entries = fs::read_dir(path_object).map_err(|e| e.to_string())?;
+        let mut files : Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some(HIBOU_TRACE_FILE_EXTENSION))
+            .map(|path| path.to_str().unwrap().to_string())
+            .collect();
+        files.sort();
+        return Ok(files);
+    }
+    let manifest = fs::read_to_string(path_object).map_err(|e| e.to_string())?;
+    let manifest_folder = path_object.parent().unwrap_or_else(|| Path::new(""));
+    let mut files = vec![];
+    for line in manifest.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let listed = Path::new(line);
+        let file : PathBuf = if listed.is_absolute() {
+            listed.to_path_buf()
+        } else {
+            manifest_folder.join(listed)
+        };
+        files.push( file.to_str().unwrap().to_string() );
+    }
+    return Ok(files);
+}
+
+// the reason given for an inconclusive threshold is irrelevant
+fn parse_threshold(threshold : &str) -> AnalysisGlobalVerdict {
+    match threshold {
+        "Pass" => {
+            return AnalysisGlobalVerdict::Pass;
+        },
+        "WeakPass" => {
+            return AnalysisGlobalVerdict::WeakPass;
+        },
+        "Inconc" => {
+            return AnalysisGlobalVerdict::Inconc(InconcReason::LackObs);
+        },
+        "WeakFail" => {
+            return AnalysisGlobalVerdict::WeakFail;
+        },
+        "Fail" => {
+            return AnalysisGlobalVerdict::Fail;
+        },
+        _ => {
+            panic!("unknown verdict threshold : {:}", threshold);
+        }
+    }
+}
+
+pub fn cli_batch_analyze(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let hcf_file_path = matches.value_of("hcf");
+                    let ana_opts = match get_analysis_options_without_loggers(&gen_ctx,hcf_file_path) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( got_opts ) => {
+                            got_opts
+                        }
+                    };
+                    let traces_path = matches.value_of("traces").unwrap();
+                    let trace_files = match get_trace_files(traces_path) {
+                        Err(e) => {
+                            return (vec![format!("cannot list traces from '{}' : {}", traces_path, e)],1);
+                        },
+                        Ok( got_files ) => {
+                            got_files
+                        }
+                    };
+                    let threshold = parse_threshold(matches.value_of("threshold").unwrap_or("WeakPass"));
+                    // ***
+                    let mut records : Vec<BatchAnalysisRecord> = vec![];
+                    for htf_file_path in trace_files {
+                        match parse_htf_file(&gen_ctx,&htf_file_path) {
+                            Err(e) => {
+                                records.push( BatchAnalysisRecord::new(htf_file_path, Err(e.to_string())) );
+                            },
+                            Ok( (co_localizations,multi_trace) ) => {
+                                let (hidden_int,multi_trace) = hide_unobserved_messages(int.clone(),multi_trace,&ana_opts.unobserved_messages);
+                                let now = Instant::now();
+                                let (_,node_count,verdict) = analyze_multi_trace(gen_ctx.clone(),co_localizations,multi_trace,hidden_int,ana_opts.clone_without_loggers());
+                                let elapsed_time = now.elapsed();
+                                records.push( BatchAnalysisRecord::new(htf_file_path, Ok((verdict,node_count,elapsed_time))) );
+                            }
+                        }
+                    }
+                    // ***
+                    let num_below = records.iter().filter(|r| r.is_below(&threshold)).count();
+                    let ret_code = if num_below > 0 {
+                        1
+                    } else {
+                        0
+                    };
+                    let report = if matches.value_of("format") == Some("json") {
+                        batch_analysis_as_json(&records).to_string()
+                    } else {
+                        batch_analysis_as_csv(&records)
+                    };
+                    match matches.value_of("output") {
+                        None => {
+                            println!("{}", report);
+                            return (vec![],ret_code);
+                        },
+                        Some( output_file_path ) => {
+                            if let Err(e) = fs::write(output_file_path, format!("{}\n", report)) {
+                                return (vec![format!("cannot write report on '{}' : {}", output_file_path, e)],1);
+                            }
+                            let mut counts : BTreeMap<&str,u32> = BTreeMap::new();
+                            for record in &records {
+                                let kind = match &record.outcome {
+                                    Err(_) => "Error",
+                                    Ok( (verdict,_,_) ) => verdict.get_kind_string()
+                                };
+                                *counts.entry(kind).or_insert(0) += 1;
+                            }
+                            let mut ret_print = vec![];
+                            ret_print.push( "BATCH ANALYSIS".to_string());
+                            ret_print.push( format!("of {} traces from '{}'", records.len(), traces_path) );
+                            ret_print.push( "W.R.T. INTERACTION".to_string());
+                            ret_print.push( format!("from file '{}'",hif_file_path) );
+                            ret_print.push( "".to_string());
+                            for (kind,count) in counts {
+                                ret_print.push( format!("{} : {}", kind, count) );
+                            }
+                            ret_print.push( format!("below '{}' : {}", threshold.get_kind_string(), num_below) );
+                            ret_print.push( format!("report written on file '{}'", output_file_path) );
+                            return (ret_print,ret_code);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_files_are_listed_from_a_folder_or_a_manifest() {
+        let dir = std::env::temp_dir().join(format!("hibou_batch_traces_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for file_name in ["b.htf","a.htf","notes.txt"] {
+            fs::write(dir.join(file_name),"").unwrap();
+        }
+        fs::write(dir.join("manifest.txt"),"# traces to analyze\n\nb.htf\n/elsewhere/c.htf\n").unwrap();
+        let from_folder = get_trace_files(dir.to_str().unwrap());
+        let from_manifest = get_trace_files(dir.join("manifest.txt").to_str().unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+        let in_dir = |file_name : &str| dir.join(file_name).to_str().unwrap().to_string();
+        assert_eq!(from_folder.unwrap(), vec![in_dir("a.htf"),in_dir("b.htf")]);
+        assert_eq!(from_manifest.unwrap(), vec![in_dir("b.htf"),"/elsewhere/c.htf".to_string()]);
+    }
+
+    #[test]
+    fn verdicts_below_the_threshold() {
+        let threshold = parse_threshold("WeakPass");
+        assert!(!AnalysisGlobalVerdict::Pass.is_below(&threshold));
+        assert!(!AnalysisGlobalVerdict::WeakPass.is_below(&threshold));
+        assert!(AnalysisGlobalVerdict::Inconc(InconcReason::UsingLifelineRemovalWithCoLocalizations).is_below(&threshold));
+        assert!(AnalysisGlobalVerdict::ForbiddenMatched.is_below(&parse_threshold("Fail")));
+    }
+}
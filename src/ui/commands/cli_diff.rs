@@ -0,0 +1,91 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::core::language::diff::tree_diff::diff_interactions;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::diff_report::interaction_edits_as_text;
+use crate::io::output::draw_interactions::interface::draw_interaction_diff;
+use crate::nfa_translation::canonize::canonize_interaction;
+use crate::process::canon::param::default::DefaultCanonizationProcess;
+
+
+pub fn cli_diff(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif1_file_path = matches.value_of("hif1").unwrap();
+            let hif2_file_path = matches.value_of("hif2").unwrap();
+            match (parse_hif_file(&gen_ctx,hif1_file_path),parse_hif_file(&gen_ctx,hif2_file_path)) {
+                (Err(e),_) | (_,Err(e)) => {
+                    return (vec![e.to_string()],1);
+                },
+                (Ok( int1 ),Ok( int2 )) => {
+                    let (int1,int2) = if matches.is_present("canonize") {
+                        (canonize_interaction(&gen_ctx,&int1,DefaultCanonizationProcess::FivePhases),
+                         canonize_interaction(&gen_ctx,&int2,DefaultCanonizationProcess::FivePhases))
+                    } else {
+                        (int1,int2)
+                    };
+                    let (distance,edits) = diff_interactions(&int1,&int2);
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "".to_string());
+                    ret_print.push( "DIFF of INTERACTIONS".to_string());
+                    ret_print.push( format!("from file '{}'",hif1_file_path) );
+                    ret_print.push( format!("to file '{}'",hif2_file_path) );
+                    if matches.is_present("canonize") {
+                        ret_print.push( "after canonization".to_string() );
+                    }
+                    ret_print.push( "".to_string());
+                    if edits.is_empty() {
+                        ret_print.push( "the interactions are identical".to_string() );
+                    } else {
+                        ret_print.push( format!("edit distance : {}", distance) );
+                        ret_print.extend( interaction_edits_as_text(&gen_ctx,&edits) );
+                    }
+                    // ***
+                    if matches.is_present("draw") || matches.is_present("output") {
+                        let output_file_name = match matches.value_of("output") {
+                            Some( extracted ) => extracted.to_string(),
+                            None => {
+                                let file_name1 = Path::new(hif1_file_path).file_stem().unwrap().to_str().unwrap();
+                                let file_name2 = Path::new(hif2_file_path).file_stem().unwrap().to_str().unwrap();
+                                format!("{}_{}_diff", file_name1, file_name2)
+                            }
+                        };
+                        draw_interaction_diff(&gen_ctx, &int1, &int2, &edits, &"".to_string(), &output_file_name);
+                        ret_print.push( "".to_string());
+                        ret_print.push( format!("drawn on file : {}.png",output_file_name) );
+                    }
+                    if edits.is_empty() {
+                        return (ret_print,0);
+                    } else {
+                        return (ret_print,1);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -20,8 +20,8 @@ use std::path::Path;
 use clap::ArgMatches;
 
 use crate::io::input::hsf::interface::parse_hsf_file;
-use crate::io::input::hif::interface::parse_hif_file;
-use crate::io::output::draw_interactions::interface::{InteractionGraphicalRepresentation,draw_interaction};
+use crate::io::input::hif::interface::parse_hif_file_with_references;
+use crate::io::output::draw_interactions::interface::{InteractionGraphicalRepresentation,draw_interaction,draw_interaction_with_references};
 
 
 
@@ -33,11 +33,11 @@ pub fn cli_draw(matches : &ArgMatches) -> (Vec<String>,u32) {
         },
         Ok( gen_ctx ) => {
             let hif_file_path = matches.value_of("hif").unwrap();
-            match parse_hif_file(&gen_ctx,hif_file_path) {
+            match parse_hif_file_with_references(&gen_ctx,hif_file_path) {
                 Err(e) => {
                     return (vec![e.to_string()],1);
                 },
-                Ok( int) => {
+                Ok( (int,references) ) => {
                     let rep_kind : InteractionGraphicalRepresentation;
                     if matches.is_present("representation") {
                         let extracted = matches.value_of("representation").unwrap();
@@ -65,7 +65,14 @@ pub fn cli_draw(matches : &ArgMatches) -> (Vec<String>,u32) {
                         output_file_name = format!("{}_repr", file_name);
                     }
                     // ***
-                    draw_interaction(&gen_ctx, &int, &rep_kind, &"temp".to_string(), &"".to_string(), &output_file_name);
+                    match rep_kind {
+                        InteractionGraphicalRepresentation::AsSequenceDiagram if matches.is_present("collapse_refs") => {
+                            draw_interaction_with_references(&gen_ctx, &int, &references, &"".to_string(), &output_file_name);
+                        },
+                        _ => {
+                            draw_interaction(&gen_ctx, &int, &rep_kind, &"temp".to_string(), &"".to_string(), &output_file_name);
+                        }
+                    }
                     // ***
                     let mut ret_print = vec![];
                     ret_print.push( "".to_string());
@@ -0,0 +1,78 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use clap::ArgMatches;
+
+use crate::core::execution::trace::multitrace::Trace;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_hfiles::trace::multi_trace::trace_as_htf_encoding;
+use crate::nfa_translation::language_comparison::{find_shortest_distinguishing_word, get_dfas_over_common_alphabet};
+
+
+pub fn cli_equiv(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif1_file_path = matches.value_of("hif1").unwrap();
+            let hif2_file_path = matches.value_of("hif2").unwrap();
+            match (parse_hif_file(&gen_ctx,hif1_file_path),parse_hif_file(&gen_ctx,hif2_file_path)) {
+                (Err(e),_) | (_,Err(e)) => {
+                    return (vec![e.to_string()],1);
+                },
+                (Ok( int1 ),Ok( int2 )) => {
+                    let (dfa1,dfa2,alphabet,elapsed) = get_dfas_over_common_alphabet(&gen_ctx,&int1,&int2);
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "".to_string());
+                    ret_print.push( "CHECKING LANGUAGE EQUIVALENCE".to_string());
+                    ret_print.push( format!("of interaction from file '{}'",hif1_file_path) );
+                    ret_print.push( format!("and interaction from file '{}'",hif2_file_path) );
+                    ret_print.push( format!("with signature from file '{}'",hsf_file_path) );
+                    ret_print.push( format!("time to transform ints to nfas : '{:?}'", elapsed.as_secs_f64()) );
+                    ret_print.push( "".to_string());
+                    match find_shortest_distinguishing_word(&dfa1,&dfa2,false) {
+                        None => {
+                            ret_print.push( "the interactions are equivalent".to_string() );
+                            return (ret_print,0);
+                        },
+                        Some( (word,accepted_by_first) ) => {
+                            let trace : Trace = word.iter().map(|letter| alphabet.get(*letter).unwrap().clone()).collect();
+                            let (accepting,rejecting) = if accepted_by_first {
+                                (hif1_file_path,hif2_file_path)
+                            } else {
+                                (hif2_file_path,hif1_file_path)
+                            };
+                            ret_print.push( "the interactions are NOT equivalent".to_string() );
+                            ret_print.push( "shortest distinguishing trace :".to_string() );
+                            if trace.is_empty() {
+                                ret_print.push( "(empty trace)".to_string() );
+                            } else {
+                                ret_print.push( trace_as_htf_encoding(&gen_ctx,&trace) );
+                            }
+                            ret_print.push( format!("is accepted by '{}' and rejected by '{}'", accepting, rejecting) );
+                            return (ret_print,1);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -21,7 +21,6 @@ use std::time::Instant;
 
 use clap::ArgMatches;
 use graph_process_manager_core::delegate::delegate::GenericProcessDelegate;
-use graph_process_manager_core::manager::manager::GenericProcessManager;
 use crate::io::input::hcf::explo::interface::parse_hcf_file_for_explore;
 use crate::io::input::hcf::explo::options::HibouExploreOptions;
 
@@ -32,6 +31,7 @@ use crate::process::explo::context::{ExplorationContext, ExplorationParameteriza
 use crate::process::explo::node::ExplorationNodeKind;
 use crate::process::explo::priorities::ExplorationPriorities;
 use crate::process::explo::step::ExplorationStepKind;
+use crate::process::parallel::manager::ParallelProcessManager;
 
 
 pub fn cli_explore(matches : &ArgMatches) -> (Vec<String>,u32) {
@@ -71,13 +71,15 @@ pub fn cli_explore(matches : &ArgMatches) -> (Vec<String>,u32) {
                     let explo_ctx = ExplorationContext::new(gen_ctx);
                     let delegate : GenericProcessDelegate<ExplorationStepKind,ExplorationNodeKind,ExplorationPriorities> = GenericProcessDelegate::new(explo_opts.strategy,explo_opts.priorities);
 
-                    let mut exploration_manager : GenericProcessManager<ExplorationConfig> = GenericProcessManager::new(explo_ctx,
-                                                                                                                        ExplorationParameterization{},
-                                                                                                                  delegate,
-                                                                                                                        explo_opts.filters,
-                                                                                                                        explo_opts.loggers,
-                                                                                                                  None,
-                                                                                                                        explo_opts.use_memoization);
+                    let mut exploration_manager : ParallelProcessManager<ExplorationConfig> = ParallelProcessManager::new(explo_ctx,
+                                                                                                                          ExplorationParameterization{},
+                                                                                                                          delegate,
+                                                                                                                          explo_opts.filters,
+                                                                                                                          explo_opts.loggers,
+                                                                                                                          None,
+                                                                                                                          explo_opts.use_memoization,
+                                                                                                                          explo_opts.threads,
+                                                                                                                          explo_opts.limits);
 
                     // ***
                     let init_node = ExplorationNodeKind::new(int,0);
@@ -85,6 +87,9 @@ pub fn cli_explore(matches : &ArgMatches) -> (Vec<String>,u32) {
                     let now = Instant::now();
                     let (node_count,_) = exploration_manager.start_process(init_node);
                     let elapsed_time = now.elapsed();
+                    if let Some(limit) = exploration_manager.get_exceeded_limit() {
+                        ret_print.push( format!("stopped after exceeding {}", limit ) );
+                    }
                     ret_print.push( format!("node count : {:?}", node_count ) );
                     ret_print.push( format!("elapsed    : {:?}", elapsed_time.as_secs_f64() ) );
                     return (ret_print,0);
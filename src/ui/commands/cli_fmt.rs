@@ -0,0 +1,102 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs;
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::io::file_extensions::{HIBOU_INTERACTION_FILE_EXTENSION, HIBOU_SIGNATURE_FILE_EXTENSION, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::hif::format::format_hif_string;
+use crate::io::input::hsf::format::format_hsf_string;
+use crate::io::input::htf::format::format_htf_string;
+
+
+fn format_hibou_file(file_path : &str, keep_comments : bool) -> Result<(String,String),HibouParsingError> {
+    let path_object = Path::new(file_path);
+    let file_extension : &str = match path_object.extension() {
+        None => {
+            ""
+        },
+        Some( got_ext ) => {
+            got_ext.to_str().unwrap()
+        }
+    };
+    match fs::read_to_string(file_path) {
+        Err(e) => {
+            return Err( HibouParsingError::FileError(e.to_string()) );
+        },
+        Ok( unformatted ) => {
+            let formatted = if file_extension == HIBOU_INTERACTION_FILE_EXTENSION {
+                format_hif_string(&unformatted, keep_comments)
+            } else if file_extension == HIBOU_SIGNATURE_FILE_EXTENSION {
+                format_hsf_string(&unformatted, keep_comments)
+            } else if file_extension == HIBOU_TRACE_FILE_EXTENSION {
+                format_htf_string(&unformatted, keep_comments)
+            } else {
+                let expected = format!("{}', '.{}' or '.{}",
+                                       HIBOU_INTERACTION_FILE_EXTENSION,
+                                       HIBOU_SIGNATURE_FILE_EXTENSION,
+                                       HIBOU_TRACE_FILE_EXTENSION);
+                return Err( HibouParsingError::FileFormatError(file_extension.to_string(),expected) );
+            };
+            match formatted {
+                Err(e) => {
+                    return Err( e.with_file_path(file_path) );
+                },
+                Ok( got_formatted ) => {
+                    return Ok( (unformatted,got_formatted) );
+                }
+            }
+        }
+    }
+}
+
+pub fn cli_fmt(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let file_path = matches.value_of("file").unwrap();
+    let keep_comments = matches.is_present("keep_comments");
+    match format_hibou_file(file_path, keep_comments) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( (unformatted,formatted) ) => {
+            if matches.is_present("check") {
+                if unformatted == formatted {
+                    return (vec![format!("file '{}' is formatted", file_path)],0);
+                } else {
+                    return (vec![format!("file '{}' is not formatted", file_path)],1);
+                }
+            } else if matches.is_present("write") {
+                if unformatted == formatted {
+                    return (vec![format!("file '{}' left unchanged", file_path)],0);
+                }
+                match fs::write(file_path, formatted) {
+                    Err(e) => {
+                        return (vec![e.to_string()],1);
+                    },
+                    Ok(_) => {
+                        return (vec![format!("file '{}' formatted", file_path)],0);
+                    }
+                }
+            } else {
+                print!("{}", formatted);
+                return (vec![],0);
+            }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use clap::ArgMatches;
+
+use crate::core::language::lint::lint::lint_interaction;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::lint_report::lint_warnings_as_text;
+use crate::io::output::to_json::lint::lint_warnings_as_json;
+
+
+pub fn cli_lint(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let warnings = lint_interaction(&gen_ctx, &int);
+                    match matches.value_of("format") {
+                        Some("json") => {
+                            println!("{}", lint_warnings_as_json(&gen_ctx, &warnings));
+                            return (vec![],0);
+                        },
+                        _ => {
+                            let mut ret_print = vec![];
+                            ret_print.push( "".to_string());
+                            ret_print.push( "LINTING INTERACTION".to_string());
+                            ret_print.push( format!("from file '{}'",hif_file_path) );
+                            ret_print.push( format!("with signature from file '{}'",hsf_file_path) );
+                            ret_print.push( format!("found {} warning(s)", warnings.len()) );
+                            ret_print.push( "".to_string());
+                            ret_print.extend( lint_warnings_as_text(&gen_ctx, &warnings) );
+                            if !warnings.is_empty() {
+                                ret_print.push( "".to_string());
+                            }
+                            return (ret_print,0);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
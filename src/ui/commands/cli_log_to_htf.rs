@@ -0,0 +1,102 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::ArgMatches;
+
+use crate::core::execution::trace::multitrace::multi_trace_length;
+use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::logs::adapter::{adapt_log, LogLineIssue};
+use crate::io::input::logs::mapping::parse_log_mapping_file;
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+
+
+pub fn cli_log_to_htf(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let mapping_file_path = matches.value_of("mapping").unwrap();
+            match parse_log_mapping_file(mapping_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( mapping ) => {
+                    let log_file_path = matches.value_of("log").unwrap();
+                    let log_content = match fs::read_to_string(log_file_path) {
+                        Err(e) => {
+                            return (vec![format!("could not read log file '{}' : {}", log_file_path, e)],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    let adaptation = adapt_log(&gen_ctx,&mapping,&log_content);
+                    // ***
+                    let output_name : String = match matches.value_of("name") {
+                        None => {
+                            Path::new(log_file_path).file_stem().unwrap().to_str().unwrap().to_string()
+                        },
+                        Some( extracted ) => {
+                            extracted.chars().filter(|c| !c.is_whitespace()).collect()
+                        }
+                    };
+                    let file_name = format!("{:}.{:}", output_name, HIBOU_TRACE_FILE_EXTENSION);
+                    let path : PathBuf = match matches.value_of("parent_folder") {
+                        None => {
+                            [&file_name].iter().collect()
+                        },
+                        Some( parent ) => {
+                            [parent, &file_name].iter().collect()
+                        }
+                    };
+                    write_multi_trace_into_file(path.as_path(),&gen_ctx,&adaptation.co_localizations,&adaptation.multi_trace);
+                    // ***
+                    let mut ret_print = vec![];
+                    ret_print.push( "ADAPTED LOG".to_string());
+                    ret_print.push( format!("from file '{}'",log_file_path) );
+                    ret_print.push( format!("with mapping from file '{}'",mapping_file_path) );
+                    ret_print.push( format!("into file '{}'",path.display()) );
+                    ret_print.push( format!("of length '{:?}' on {} canals",
+                                            multi_trace_length(&adaptation.multi_trace),
+                                            adaptation.multi_trace.len()) );
+                    ret_print.push( format!("{} lines ignored", adaptation.ignored) );
+                    if adaptation.issues.is_empty() {
+                        return (ret_print,0);
+                    }
+                    ret_print.push( format!("{} lines could not be mapped :", adaptation.issues.len()) );
+                    for (line_num,issue) in &adaptation.issues {
+                        match issue {
+                            LogLineIssue::Unmapped => {
+                                ret_print.push( format!("line {} : no rule applies", line_num) );
+                            },
+                            LogLineIssue::Unresolved(e) => {
+                                ret_print.push( format!("line {} : {}", line_num, e) );
+                            }
+                        }
+                    }
+                    return (ret_print,1);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::path::PathBuf;
+
+use clap::ArgMatches;
+
+use crate::io::file_extensions::HIBOU_INTERACTION_FILE_EXTENSION;
+use crate::io::input::hcf::ana::options::HibouAnalyzeOptions;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::output::to_hfiles::interaction::to_hif::interaction_to_hif;
+use crate::mining::interface::mine_interaction;
+use crate::process::ana::interface::analyze_multi_trace;
+use crate::process::ana::param::anakind::AnalysisKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::ui::commands::cli_batch_analyze::get_trace_files;
+
+
+pub fn cli_mine(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let traces_path = matches.value_of("traces").unwrap();
+            let trace_files = match get_trace_files(traces_path) {
+                Err(e) => {
+                    return (vec![format!("could not list multi-traces from '{}' : {}", traces_path, e)],1);
+                },
+                Ok( got ) => {
+                    got
+                }
+            };
+            let mut corpus = vec![];
+            for htf_file_path in &trace_files {
+                match parse_htf_file(&gen_ctx,htf_file_path) {
+                    Err(e) => {
+                        return (vec![format!("could not parse '{}'", htf_file_path), e.to_string()],1);
+                    },
+                    Ok( got ) => {
+                        corpus.push(got);
+                    }
+                }
+            }
+            let mined = match mine_interaction(&corpus) {
+                Err(e) => {
+                    return (vec![e],1);
+                },
+                Ok( got ) => {
+                    got
+                }
+            };
+            // ***
+            let output_name : String = match matches.value_of("name") {
+                None => {
+                    "mined".to_string()
+                },
+                Some( extracted ) => {
+                    extracted.chars().filter(|c| !c.is_whitespace()).collect()
+                }
+            };
+            let file_name = format!("{:}.{:}", output_name, HIBOU_INTERACTION_FILE_EXTENSION);
+            let path : PathBuf = match matches.value_of("parent_folder") {
+                None => {
+                    [&file_name].iter().collect()
+                },
+                Some( parent ) => {
+                    [parent, &file_name].iter().collect()
+                }
+            };
+            interaction_to_hif(path.as_path(),&gen_ctx,&mined);
+            // ***
+            // every multi-trace of the corpus must be accepted by the mined interaction
+            let mut rejected = vec![];
+            for (htf_file_path,(co_localizations,multi_trace)) in trace_files.iter().zip(corpus) {
+                let mut ana_opts = HibouAnalyzeOptions::default();
+                ana_opts.ana_param.ana_kind = AnalysisKind::Accept;
+                ana_opts.goal = Some(AnalysisGlobalVerdict::Pass);
+                let (_,_,verdict) = analyze_multi_trace(gen_ctx.clone(),co_localizations,multi_trace,mined.clone(),ana_opts);
+                if verdict != AnalysisGlobalVerdict::Pass {
+                    rejected.push( format!("'{}' : {}", htf_file_path, verdict) );
+                }
+            }
+            // ***
+            let mut ret_print = vec![];
+            ret_print.push( "MINED INTERACTION".to_string());
+            ret_print.push( format!("from {} multi-traces in '{}'", trace_files.len(), traces_path) );
+            ret_print.push( format!("into file '{}'",path.display()) );
+            if rejected.is_empty() {
+                ret_print.push( "all the multi-traces are accepted".to_string() );
+                return (ret_print,0);
+            }
+            ret_print.push( format!("{} multi-traces are not accepted :", rejected.len()) );
+            ret_print.append(&mut rejected);
+            return (ret_print,1);
+        }
+    }
+}
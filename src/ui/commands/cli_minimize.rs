@@ -0,0 +1,129 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::path::{Path, PathBuf};
+
+use clap::ArgMatches;
+
+use crate::core::execution::trace::multitrace::multi_trace_length;
+use crate::io::file_extensions::HIBOU_TRACE_FILE_EXTENSION;
+use crate::io::input::hcf::ana::interface::get_analysis_options_without_loggers;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::process::ana::interface::{analyze_multi_trace, hide_unobserved_messages};
+use crate::process::ana::param::anakind::AnalysisKind;
+use crate::process::ana::verdict::global::AnalysisGlobalVerdict;
+use crate::trace_manip::minimize::multi_trace::minimize_failing_multi_trace;
+
+
+pub fn cli_minimize(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let htf_file_path = matches.value_of("htf").unwrap();
+                    match parse_htf_file(&gen_ctx,htf_file_path) {
+                        Err(e) => {
+                            return (vec![e.to_string()],1);
+                        },
+                        Ok( (co_localizations,multi_trace) ) => {
+                            let hcf_file_path = matches.value_of("hcf");
+                            let ana_opts = match get_analysis_options_without_loggers(&gen_ctx,hcf_file_path) {
+                                Err(e) => {
+                                    return (vec![e.to_string()],1);
+                                },
+                                Ok( got_opts ) => {
+                                    got_opts
+                                }
+                            };
+                            // ***
+                            // unobserved actions play no part in the verdict and are left out of the minimized multi-trace
+                            let (int,multi_trace) = hide_unobserved_messages(int,multi_trace,&ana_opts.unobserved_messages);
+                            // ***
+                            let (_,_,initial_verdict) = analyze_multi_trace(gen_ctx.clone(),
+                                                                            co_localizations.clone(),
+                                                                            multi_trace.clone(),
+                                                                            int.clone(),
+                                                                            ana_opts.clone_without_loggers());
+                            match initial_verdict {
+                                AnalysisGlobalVerdict::Fail | AnalysisGlobalVerdict::WeakFail => {},
+                                _ => {
+                                    return (vec![format!("nothing to minimize : the multi-trace is not rejected (verdict '{}')", initial_verdict)],1);
+                                }
+                            }
+                            // ***
+                            let minimization = minimize_failing_multi_trace(&multi_trace, &mut |candidate| {
+                                let (_,_,verdict) = analyze_multi_trace(gen_ctx.clone(),
+                                                                        co_localizations.clone(),
+                                                                        candidate.clone(),
+                                                                        int.clone(),
+                                                                        ana_opts.clone_without_loggers());
+                                verdict == initial_verdict
+                            });
+                            // ***
+                            let output_name : String = match matches.value_of("name") {
+                                None => {
+                                    let mu_name : &str = Path::new(htf_file_path).file_stem().unwrap().to_str().unwrap();
+                                    format!("{}_min",mu_name)
+                                },
+                                Some( extracted ) => {
+                                    extracted.chars().filter(|c| !c.is_whitespace()).collect()
+                                }
+                            };
+                            let file_name = format!("{:}.{:}", output_name, HIBOU_TRACE_FILE_EXTENSION);
+                            let path : PathBuf = match matches.value_of("parent_folder") {
+                                None => {
+                                    [&file_name].iter().collect()
+                                },
+                                Some( parent ) => {
+                                    [parent, &file_name].iter().collect()
+                                }
+                            };
+                            write_multi_trace_into_file(path.as_path(),&gen_ctx,&co_localizations,&minimization.minimized);
+                            // ***
+                            let mut ret_print = vec![];
+                            ret_print.push( "MINIMIZED FAILING MULTI-TRACE".to_string());
+                            if ana_opts.ana_param.ana_kind == AnalysisKind::Accept {
+                                ret_print.push( "WARNING : under 'accept' analysis, any multi-trace which is not a complete execution is rejected, so that the minimized multi-trace may be empty ; use 'prefix' analysis to minimize unexpected behaviors".to_string() );
+                            }
+                            ret_print.push( format!("from file '{}'",htf_file_path) );
+                            ret_print.push( format!("of length '{:?}'", multi_trace_length(&multi_trace)) );
+                            ret_print.push( format!("with verdict '{}'", initial_verdict) );
+                            ret_print.push( "W.R.T. INTERACTION".to_string());
+                            ret_print.push( format!("from file '{}'",hif_file_path) );
+                            ret_print.push( format!("into file '{}'",path.display()) );
+                            ret_print.push( format!("of length '{:?}'", multi_trace_length(&minimization.minimized)) );
+                            ret_print.push( format!("after {} analyses", minimization.num_tests) );
+                            return (ret_print,0);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
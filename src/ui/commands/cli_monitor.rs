@@ -0,0 +1,154 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use clap::ArgMatches;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::htf::interface::{co_localizations_from_text, tagged_multi_action_from_text};
+use crate::process::ana::online::monitor::OnlineMonitor;
+use crate::process::ana::online::verdict::OnlineVerdict;
+
+
+fn open_monitored_input(input_path : Option<&str>) -> Result<Box<dyn BufRead>,String> {
+    match input_path {
+        None => {
+            return Ok( Box::new(BufReader::new(std::io::stdin())) );
+        },
+        Some( path ) => {
+            // a local socket must be connected to while a named pipe can be opened as any file
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::FileTypeExt;
+                use std::os::unix::net::UnixStream;
+                if let Ok(metadata) = std::fs::metadata(path) {
+                    if metadata.file_type().is_socket() {
+                        return match UnixStream::connect(path) {
+                            Err(e) => Err( format!("could not connect to socket '{}' : {}", path, e) ),
+                            Ok( stream ) => Ok( Box::new(BufReader::new(stream)) )
+                        };
+                    }
+                }
+            }
+            match File::open(path) {
+                Err(e) => {
+                    return Err( format!("could not open '{}' : {}", path, e) );
+                },
+                Ok( file ) => {
+                    return Ok( Box::new(BufReader::new(file)) );
+                }
+            }
+        }
+    }
+}
+
+pub fn cli_monitor(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    let co_localizations = match matches.value_of("colocs") {
+                        None => {
+                            CoLocalizations::get_discrete_partition(gen_ctx.get_lf_num())
+                        },
+                        Some( colocs_str ) => {
+                            match co_localizations_from_text(&gen_ctx,colocs_str) {
+                                Err(e) => {
+                                    return (vec![e.to_string()],1);
+                                },
+                                Ok( got ) => {
+                                    got
+                                }
+                            }
+                        }
+                    };
+                    let reader = match open_monitored_input(matches.value_of("input")) {
+                        Err(e) => {
+                            return (vec![e],1);
+                        },
+                        Ok( got ) => {
+                            got
+                        }
+                    };
+                    // ***
+                    let mut monitor = OnlineMonitor::new(gen_ctx.clone(),co_localizations,int);
+                    let mut verdict = monitor.get_verdict();
+                    let mut received : usize = 0;
+                    println!("{} after {} actions", verdict, received);
+                    for line in reader.lines() {
+                        let line = match line {
+                            Err(e) => {
+                                return (vec![format!("could not read monitored input : {}", e)],1);
+                            },
+                            Ok( got ) => {
+                                got
+                            }
+                        };
+                        let line = line.trim();
+                        if line.is_empty() || line.starts_with('#') {
+                            continue;
+                        }
+                        match tagged_multi_action_from_text(&gen_ctx,monitor.get_co_localizations(),line) {
+                            Err(e) => {
+                                // ill-formed actions are reported but do not stop the monitoring
+                                eprintln!("ignored '{}' : {}", line, e);
+                            },
+                            Ok( (canal_id,multi_action) ) => {
+                                received += 1;
+                                let new_verdict = monitor.receive(canal_id,multi_action);
+                                if new_verdict != verdict {
+                                    verdict = new_verdict;
+                                    println!("{} after {} actions", verdict, received);
+                                }
+                                if verdict.is_definitive() {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                    // ***
+                    let final_verdict = verdict.at_end_of_stream();
+                    let mut ret_print = vec![];
+                    ret_print.push( "ONLINE MONITORING".to_string());
+                    ret_print.push( format!("w.r.t. interaction from file '{}'",hif_file_path) );
+                    ret_print.push( format!("of {} received actions", received) );
+                    ret_print.push( format!("verdict : '{}'", final_verdict) );
+                    match final_verdict {
+                        OnlineVerdict::Pass | OnlineVerdict::WeakPass => {
+                            return (ret_print,0);
+                        },
+                        _ => {
+                            return (ret_print,1);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,97 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
+use crate::core::transformation::transfokind::InteractionTransformationKind;
+use crate::core::transformation::transfophase::InteractionTransformationPhase;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::draw_interactions::interface::{draw_interaction, InteractionGraphicalRepresentation};
+use crate::io::output::to_hfiles::interaction::to_hif::interaction_to_hif;
+use crate::io::output::to_hfiles::signature::to_hsf::general_context_to_hsf;
+
+
+pub fn cli_project(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let hif_file_path = matches.value_of("hif").unwrap();
+            match parse_hif_file(&gen_ctx,hif_file_path) {
+                Err(e) => {
+                    return (vec![e.to_string()],1);
+                },
+                Ok( int ) => {
+                    // either lifelines or lifeline families (standing for all their members)
+                    let mut kept_lfs : BTreeSet<usize> = BTreeSet::new();
+                    for lf_name in matches.value_of("keep").unwrap().split(',').map(|n| n.trim()).filter(|n| !n.is_empty()) {
+                        if let Some(lf_id) = gen_ctx.get_lf_id(lf_name) {
+                            kept_lfs.insert(lf_id);
+                        } else if let Some(members) = gen_ctx.get_lf_family(lf_name) {
+                            kept_lfs.extend(members.iter().cloned());
+                        } else {
+                            let error = HibouParsingError::MissingLifelineDeclarationError(lf_name.to_string());
+                            return (vec![error.to_string()],1);
+                        }
+                    }
+                    let removed_lfs : BTreeSet<usize> = &gen_ctx.get_all_lfs_ids() - &kept_lfs;
+                    let simpl_phase = InteractionTransformationPhase::new(vec![InteractionTransformationKind::Simpl,
+                                                                               InteractionTransformationKind::FlushRight]);
+                    let projected = simpl_phase.apply_exhaustively(&int.eliminate_lifelines(&removed_lfs));
+                    // ***
+                    let output_prefix = match matches.value_of("output") {
+                        Some( extracted ) => extracted.to_string(),
+                        None => {
+                            let file_name = Path::new(hif_file_path).file_stem().unwrap().to_str().unwrap();
+                            format!("{}_proj", file_name)
+                        }
+                    };
+                    let hsf_output = format!("{}.hsf", output_prefix);
+                    let hif_output = format!("{}.hif", output_prefix);
+                    general_context_to_hsf(Path::new(&hsf_output), &gen_ctx.restricted_to_lifelines(&kept_lfs));
+                    // lifelines are referred to by their names so that the original signature can be used for printing
+                    interaction_to_hif(Path::new(&hif_output), &gen_ctx, &projected);
+                    // ***
+                    let kept_names : Vec<String> = kept_lfs.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+                    let mut ret_print = vec![];
+                    ret_print.push( "".to_string());
+                    ret_print.push( "PROJECTING INTERACTION".to_string());
+                    ret_print.push( format!("from file '{}'",hif_file_path) );
+                    ret_print.push( format!("onto lifelines : [{}]", kept_names.join(",")) );
+                    ret_print.push( format!("signature written on file : {}",hsf_output) );
+                    ret_print.push( format!("interaction written on file : {}",hif_output) );
+                    if matches.is_present("draw") {
+                        let draw_output = format!("{}_repr", output_prefix);
+                        draw_interaction(&gen_ctx, &projected, &InteractionGraphicalRepresentation::AsSequenceDiagram,
+                                         &"temp".to_string(), &"".to_string(), &draw_output);
+                        ret_print.push( format!("drawn on file : {}.png",draw_output) );
+                    }
+                    ret_print.push( "".to_string());
+                    return (ret_print,0);
+                }
+            }
+        }
+    }
+}
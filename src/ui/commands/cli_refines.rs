@@ -0,0 +1,108 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::core::colocalizations::CoLocalizations;
+use crate::core::execution::trace::multitrace::{MultiTrace, Trace};
+use crate::core::language::eliminate_lf::eliminable::LifelineEliminable;
+use crate::core::language::involve::involves::InvolvesLifelines;
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::output::to_hfiles::trace::multi_trace::multi_trace_as_htf_encoding;
+use crate::io::output::to_hfiles::trace::to_htf::write_multi_trace_into_file;
+use crate::nfa_translation::language_comparison::{find_shortest_distinguishing_word, get_dfas_over_common_alphabet};
+
+
+pub fn cli_refines(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let abstract_file_path = matches.value_of("abstract").unwrap();
+            let detailed_file_path = matches.value_of("detailed").unwrap();
+            match (parse_hif_file(&gen_ctx,abstract_file_path),parse_hif_file(&gen_ctx,detailed_file_path)) {
+                (Err(e),_) | (_,Err(e)) => {
+                    return (vec![e.to_string()],1);
+                },
+                (Ok( abstract_int ),Ok( detailed_int )) => {
+                    // by default, the internal lifelines are those which only occur in the detailed interaction
+                    let mut hidden_lfs : BTreeSet<usize> = BTreeSet::new();
+                    match matches.value_of("hide") {
+                        None => {
+                            hidden_lfs = &detailed_int.involved_lifelines() - &abstract_int.involved_lifelines();
+                        },
+                        Some( lf_names ) => {
+                            for lf_name in lf_names.split(',').map(|n| n.trim()).filter(|n| !n.is_empty()) {
+                                match gen_ctx.get_lf_id(lf_name) {
+                                    None => {
+                                        let error = HibouParsingError::MissingLifelineDeclarationError(lf_name.to_string());
+                                        return (vec![error.to_string()],1);
+                                    },
+                                    Some( lf_id ) => {
+                                        hidden_lfs.insert(lf_id);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    let abstract_int = abstract_int.eliminate_lifelines(&hidden_lfs);
+                    let detailed_int = detailed_int.eliminate_lifelines(&hidden_lfs);
+                    let (detailed_dfa,abstract_dfa,alphabet,elapsed) = get_dfas_over_common_alphabet(&gen_ctx,&detailed_int,&abstract_int);
+                    // ***
+                    let hidden_names : Vec<String> = hidden_lfs.iter().map(|lf_id| gen_ctx.get_lf_name(*lf_id).unwrap()).collect();
+                    let mut ret_print = vec![];
+                    ret_print.push( "".to_string());
+                    ret_print.push( "CHECKING REFINEMENT".to_string());
+                    ret_print.push( format!("of abstract interaction from file '{}'",abstract_file_path) );
+                    ret_print.push( format!("by detailed interaction from file '{}'",detailed_file_path) );
+                    ret_print.push( format!("with signature from file '{}'",hsf_file_path) );
+                    ret_print.push( format!("hiding lifelines : [{}]", hidden_names.join(",")) );
+                    ret_print.push( format!("time to transform ints to nfas : '{:?}'", elapsed.as_secs_f64()) );
+                    ret_print.push( "".to_string());
+                    match find_shortest_distinguishing_word(&detailed_dfa,&abstract_dfa,true) {
+                        None => {
+                            ret_print.push( "the detailed interaction refines the abstract one".to_string() );
+                            return (ret_print,0);
+                        },
+                        Some( (word,_) ) => {
+                            // the counterexample is a global trace i.e. a multi-trace with a single canal
+                            let trace : Trace = word.iter().map(|letter| alphabet.get(*letter).unwrap().clone()).collect();
+                            let visible_lfs : BTreeSet<usize> = &gen_ctx.get_all_lfs_ids() - &hidden_lfs;
+                            let co_localizations = CoLocalizations::new(vec![visible_lfs]);
+                            let multi_trace : MultiTrace = vec![trace];
+                            ret_print.push( "the detailed interaction does NOT refine the abstract one".to_string() );
+                            ret_print.push( "the following multi-trace is accepted by the detailed interaction but not by the abstract one :".to_string() );
+                            ret_print.push( multi_trace_as_htf_encoding(&gen_ctx,&co_localizations,&multi_trace) );
+                            if let Some(output_file_path) = matches.value_of("output") {
+                                write_multi_trace_into_file(Path::new(output_file_path),&gen_ctx,&co_localizations,&multi_trace);
+                                ret_print.push( format!("counterexample written in file '{}'", output_file_path) );
+                            }
+                            return (ret_print,1);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+use std::path::Path;
+
+use clap::ArgMatches;
+
+use crate::io::file_extensions::{HIBOU_INTERACTION_FILE_EXTENSION, HIBOU_TRACE_FILE_EXTENSION};
+use crate::io::input::error::HibouParsingError;
+use crate::io::input::hif::interface::parse_hif_file;
+use crate::io::input::hsf::interface::parse_hsf_file;
+use crate::io::input::htf::interface::parse_htf_file;
+use crate::io::output::to_json::context::general_context_as_json;
+use crate::io::output::to_json::interaction::interaction_as_json;
+use crate::io::output::to_json::multitrace::multi_trace_as_json;
+
+
+pub fn cli_to_json(matches : &ArgMatches) -> (Vec<String>,u32) {
+    let hsf_file_path = matches.value_of("hsf").unwrap();
+    match parse_hsf_file(hsf_file_path) {
+        Err(e) => {
+            return (vec![e.to_string()],1);
+        },
+        Ok( gen_ctx ) => {
+            let got_json;
+            match matches.value_of("file") {
+                None => {
+                    got_json = general_context_as_json(&gen_ctx);
+                },
+                Some( file_path ) => {
+                    let file_extension : &str = Path::new(file_path).extension().map(|e| e.to_str().unwrap()).unwrap_or("");
+                    if file_extension == HIBOU_INTERACTION_FILE_EXTENSION {
+                        match parse_hif_file(&gen_ctx,file_path) {
+                            Err(e) => {
+                                return (vec![e.to_string()],1);
+                            },
+                            Ok( int ) => {
+                                got_json = interaction_as_json(&gen_ctx,&int);
+                            }
+                        }
+                    } else if file_extension == HIBOU_TRACE_FILE_EXTENSION {
+                        match parse_htf_file(&gen_ctx,file_path) {
+                            Err(e) => {
+                                return (vec![e.to_string()],1);
+                            },
+                            Ok( (co_localizations,multi_trace) ) => {
+                                got_json = multi_trace_as_json(&gen_ctx,&co_localizations,&multi_trace);
+                            }
+                        }
+                    } else {
+                        let expected = format!("{}' or '.{}", HIBOU_INTERACTION_FILE_EXTENSION, HIBOU_TRACE_FILE_EXTENSION);
+                        let error = HibouParsingError::FileFormatError(file_extension.to_string(),expected);
+                        return (vec![error.to_string()],1);
+                    }
+                }
+            }
+            println!("{}", serde_json::to_string_pretty(&got_json).unwrap());
+            return (vec![],0);
+        }
+    }
+}
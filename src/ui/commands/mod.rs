@@ -30,7 +30,20 @@ pub mod cli_mutate_remove_actions;
 pub mod cli_glosem;
 pub mod cli_nfa_experiment2;
 pub mod cli_get_metrics;
+pub mod cli_lint;
+pub mod cli_fmt;
+pub mod cli_to_json;
+pub mod cli_equiv;
+pub mod cli_refines;
+pub mod cli_diff;
+pub mod cli_project;
+pub mod cli_minimize;
+pub mod cli_batch_analyze;
 pub mod cli_rng_gen_interactions;
+pub mod cli_monitor;
+pub mod cli_log_to_htf;
+pub mod cli_align;
+pub mod cli_mine;
 //pub mod cli_term_repr;
 
 
@@ -20,9 +20,12 @@ use clap::App;
 use crate::ui::commands::cli_analyze::cli_analyze;
 use crate::ui::commands::cli_canonize::cli_canonize;
 use crate::ui::commands::cli_draw::cli_draw;
+use crate::ui::commands::cli_equiv::cli_equiv;
 use crate::ui::commands::cli_explore::cli_explore;
+use crate::ui::commands::cli_fmt::cli_fmt;
 use crate::ui::commands::cli_get_metrics::cli_get_metrics;
 use crate::ui::commands::cli_glosem::cli_glosem;
+use crate::ui::commands::cli_lint::cli_lint;
 use crate::ui::commands::cli_mutate_insert_noise::cli_mutate_insert_noise;
 use crate::ui::commands::cli_mutate_remove_actions::cli_mutate_remove_actions;
 use crate::ui::commands::cli_mutate_swap_actions::cli_mutate_swap_actions;
@@ -32,8 +35,18 @@ use crate::ui::commands::cli_nfa_experiment2::cli_nfa_experiment2;
 use crate::ui::commands::cli_nfa_experiment::cli_nfa_experiment;
 use crate::ui::commands::cli_puml_ap::cli_puml_ap;
 use crate::ui::commands::cli_puml_sd::cli_puml_sd;
+use crate::ui::commands::cli_refines::cli_refines;
+use crate::ui::commands::cli_diff::cli_diff;
+use crate::ui::commands::cli_project::cli_project;
+use crate::ui::commands::cli_minimize::cli_minimize;
+use crate::ui::commands::cli_batch_analyze::cli_batch_analyze;
 use crate::ui::commands::cli_rng_gen_interactions::cli_rng_gen_interactions;
+use crate::ui::commands::cli_monitor::cli_monitor;
+use crate::ui::commands::cli_log_to_htf::cli_log_to_htf;
+use crate::ui::commands::cli_align::cli_align;
+use crate::ui::commands::cli_mine::cli_mine;
 use crate::ui::commands::cli_slice::cli_slice;
+use crate::ui::commands::cli_to_json::cli_to_json;
 use crate::ui::util::printing::print_on_hibou_cli;
 
 pub fn hibou_cli() -> i32 {
@@ -76,6 +89,58 @@ pub fn hibou_cli() -> i32 {
         let mut got = cli_get_metrics(matches);
         ret_print = got.0;
         ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("lint") {
+        let mut got = cli_lint(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("fmt") {
+        let mut got = cli_fmt(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("to_json") {
+        let mut got = cli_to_json(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("equiv") {
+        let mut got = cli_equiv(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("refines") {
+        let mut got = cli_refines(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("diff") {
+        let mut got = cli_diff(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("project") {
+        let mut got = cli_project(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("minimize") {
+        let mut got = cli_minimize(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("batch_analyze") {
+        let mut got = cli_batch_analyze(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("monitor") {
+        let mut got = cli_monitor(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("log_to_htf") {
+        let mut got = cli_log_to_htf(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("align") {
+        let mut got = cli_align(matches);
+        ret_print = got.0;
+        ret_code = got.1;
+    } else if let Some(matches) = matches.subcommand_matches("mine") {
+        let mut got = cli_mine(matches);
+        ret_print = got.0;
+        ret_code = got.1;
     } else if let Some(matches) = matches.subcommand_matches("nfa_analyze") {
         let mut got = cli_nfa_ana(matches);
         ret_print = got.0;
@@ -123,7 +188,10 @@ pub fn hibou_cli() -> i32 {
         ret_code = 0
     }
     // ***
-    print_on_hibou_cli(ret_print);
-    return 0;
+    // commands with a machine-readable output print it themselves
+    if !ret_print.is_empty() {
+        print_on_hibou_cli(ret_print);
+    }
+    return ret_code as i32;
 }
 
@@ -36,6 +36,8 @@ fn get_ascii_left() -> Vec<&'static str> {
 
 pub fn print_on_hibou_cli(ret_print : Vec<String>) {
     let ascii_left = get_ascii_left();
+    // multi-line messages (e.g. located parsing errors) are printed line by line
+    let ret_print : Vec<String> = ret_print.iter().flat_map(|s| s.split('\n')).map(|s| s.to_string()).collect();
     // ***
     println!("{}", get_ascii_border());
     if ret_print.len() >= ascii_left.len() {
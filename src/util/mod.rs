@@ -18,6 +18,8 @@ limitations under the License.
 
 pub mod slicer;
 pub mod powerset;
+#[cfg(test)]
+pub mod test_fixtures;
 
 
 
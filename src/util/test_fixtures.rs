@@ -0,0 +1,61 @@
+/*
+Copyright 2020 Erwan Mahe (github.com/erwanM974)
+
+Licensed under the Apache License, Version 2.0 (the "License");
+you may not use this file except in compliance with the License.
+You may obtain a copy of the License at
+
+    http://www.apache.org/licenses/LICENSE-2.0
+
+Unless required by applicable law or agreed to in writing, software
+distributed under the License is distributed on an "AS IS" BASIS,
+WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+See the License for the specific language governing permissions and
+limitations under the License.
+*/
+
+
+
+use std::collections::BTreeSet;
+
+use crate::core::execution::trace::trace::{TraceAction, TraceActionKind};
+use crate::core::general_context::GeneralContext;
+use crate::core::language::syntax::action::{CommunicationSynchronicity, EmissionAction, EmissionTargetRef};
+use crate::core::language::syntax::interaction::Interaction;
+
+// builders shared by the unit tests of the different modules
+
+
+// as "lf_from -- ms_id -> lf_to" in the ".hif" syntax
+pub fn message(lf_from : usize, ms_id : usize, lf_to : usize) -> Interaction {
+    return Interaction::Emission(EmissionAction::new(lf_from,ms_id,CommunicationSynchronicity::Asynchronous,
+                                                     vec![EmissionTargetRef::Lifeline(lf_to)],vec![]));
+}
+
+// as "lf_from -- ms_id ->|" in the ".hif" syntax
+pub fn emission(lf_from : usize, ms_id : usize) -> Interaction {
+    return Interaction::Emission(EmissionAction::new(lf_from,ms_id,CommunicationSynchronicity::Asynchronous,
+                                                     vec![],vec![]));
+}
+
+// a single action without arguments occurring at a given position of a trace
+pub fn action(lf_id : usize, kind : TraceActionKind, ms_id : usize) -> BTreeSet<TraceAction> {
+    return btreeset!{TraceAction::new(lf_id,kind,ms_id,vec![])};
+}
+
+// consecutive actions of the same kind on the same lifeline
+pub fn actions(lf_id : usize, kind : TraceActionKind, ms_ids : Vec<usize>) -> Vec<BTreeSet<TraceAction>> {
+    return ms_ids.into_iter().map(|ms_id| action(lf_id,kind,ms_id)).collect();
+}
+
+// lifelines and messages are numbered in the order in which they are given
+pub fn context_with(lf_names : &[&str], ms_names : &[&str]) -> GeneralContext {
+    let mut gen_ctx = GeneralContext::new();
+    for lf_name in lf_names {
+        gen_ctx.add_lf(lf_name.to_string());
+    }
+    for ms_name in ms_names {
+        gen_ctx.add_msg(ms_name.to_string());
+    }
+    return gen_ctx;
+}